@@ -6,7 +6,7 @@ fn main() {
     use sguaba::Coordinate;
     use sguaba_celestial::builder;
     use sguaba_celestial::{transforms, GcrfCoordinateExt, IcrsCoordinateExt};
-    use sguaba_celestial::{validate_epoch, Gcrf, Icrs, KeplerianElements, TleElements};
+    use sguaba_celestial::{validate_epoch, EquatorialCoords, Gcrf, Icrs, KeplerianElements, TleElements};
     use uom::si::angle::degree;
     use uom::si::f64::{Angle, Length};
     use uom::si::length::kilometer;
@@ -46,11 +46,11 @@ fn main() {
 
     // 3. RA/DEC CELESTIAL COORDINATES
     println!("3. RA/Dec Celestial Coordinates");
-    let star_pos = <Coordinate<Icrs>>::from_ra_dec(
-        Angle::new::<degree>(45.0),
-        Angle::new::<degree>(30.0),
-        Length::new::<kilometer>(1000.0),
-    );
+    let star_pos = <Coordinate<Icrs>>::from_equatorial(EquatorialCoords {
+        ra: Angle::new::<degree>(45.0),
+        dec: Angle::new::<degree>(30.0),
+        distance: Length::new::<kilometer>(1000.0),
+    });
     let (ra, dec, dist) = star_pos.to_spherical_celestial();
     println!(
         "   Star: RA={:.1}°, Dec={:.1}°, Dist={:.0} km\n",
@@ -73,8 +73,8 @@ fn main() {
 
     // 5. TLE PARSING AND PROPAGATION
     println!("5. TLE Support (ISS Two-Line Elements)");
-    let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9992";
-    let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236008";
+    let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+    let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
 
     match TleElements::from_lines(line1, line2) {
         Ok(tle) => {
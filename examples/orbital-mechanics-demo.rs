@@ -9,7 +9,7 @@
 //! - Orbit propagation
 
 use chrono::{Duration, Utc};
-use sguaba_celestial::{Icrs, IcrsCoordinateExt, KeplerianElements, TimedCoordinate, VelocityTransformExt};
+use sguaba_celestial::{EquatorialCoords, Icrs, IcrsCoordinateExt, KeplerianElements, TimedCoordinate, VelocityTransformExt};
 use sguaba_celestial::transforms;
 use sguaba::Coordinate;
 use uom::si::angle::degree;
@@ -72,7 +72,7 @@ fn main() {
     println!("  Distance:        {:.2} km", dist.get::<kilometer>());
     
     // Verify roundtrip conversion
-    let pos_from_radec = <Coordinate<Icrs>>::from_ra_dec(ra, dec, dist);
+    let pos_from_radec = <Coordinate<Icrs>>::from_equatorial(EquatorialCoords { ra, dec, distance: dist });
     let error = position.distance_from(&pos_from_radec);
     println!("\n  Roundtrip error: {:.3e} m", error.get::<meter>());
 
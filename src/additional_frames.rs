@@ -1,6 +1,8 @@
 //! Additional celestial reference frames and coordinate systems.
 
-use sguaba::CoordinateSystem;
+use sguaba::systems::Ecef;
+use sguaba::{Coordinate, CoordinateSystem};
+use uom::si::f64::{Angle, Length};
 
 use super::frames::CelestialConvention;
 
@@ -45,8 +47,10 @@ unsafe impl sguaba::systems::EquivalentTo<Gcrf> for super::frames::Icrs {}
 ///
 /// # Relationship to ICRS
 ///
-/// EME2000 differs from ICRS by a small frame bias (~80 milliarcseconds).
-/// For many applications, they can be treated as equivalent.
+/// EME2000 differs from ICRS by a small frame bias (~23 milliarcseconds).
+/// For many applications, they can be treated as equivalent; use
+/// [`transforms::eme2000_to_icrs`](crate::transforms::eme2000_to_icrs) to
+/// apply the bias explicitly.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Eme2000;
@@ -68,7 +72,7 @@ impl CoordinateSystem for Eme2000 {
 ///
 /// # Properties
 ///
-/// - **Origin**: Solar System Barycenter (or geocenter for Earth-centered variant)
+/// - **Origin**: Geocenter or Sun, chosen with [`EclipticOrigin`] when transforming
 /// - **Fundamental plane**: Mean ecliptic at J2000.0
 /// - **Obliquity**: ε₀ ≈ 23.4393° (mean obliquity of ecliptic)
 ///
@@ -85,6 +89,138 @@ impl CoordinateSystem for Ecliptic {
     type Convention = CelestialConvention;
 }
 
+/// Ecliptic and equinox of date.
+///
+/// Like [`Ecliptic`], but with the equator, equinox and ecliptic carried to
+/// the transform epoch by IAU 2006 precession, so a fixed star's longitude
+/// grows by about 50″ per year. Used for apparent positions and
+/// almanac-style longitudes; orbital work usually wants the fixed
+/// [`Ecliptic`].
+///
+/// # Properties
+///
+/// - **Origin**: Geocenter or Sun, chosen with [`EclipticOrigin`] when transforming
+/// - **Fundamental plane**: Ecliptic of date
+/// - **X axis**: Mean or true equinox of date, chosen with [`Obliquity`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EclipticOfDate;
+
+impl CoordinateSystem for EclipticOfDate {
+    type Convention = CelestialConvention;
+}
+
+/// Origin of [`Ecliptic`] coordinates produced by the ecliptic transforms.
+///
+/// The axes are the same either way; the origins differ by the Earth-Sun
+/// vector (about 1 AU), so mixing them silently causes AU-scale errors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EclipticOrigin {
+    /// Earth-centered ecliptic coordinates (same origin as ICRS here).
+    #[default]
+    Geocentric,
+    /// Sun-centered ecliptic coordinates, using the low-precision solar
+    /// ephemeris. The ~0.01 AU offset between the Sun and the solar system
+    /// barycenter is not modelled.
+    Heliocentric,
+}
+
+/// Equator and equinox used for [`EclipticOfDate`] coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Obliquity {
+    /// Mean equator and equinox of date, tilted by the IAU 2006 mean
+    /// obliquity ε.
+    #[default]
+    Mean,
+    /// True equator and equinox of date (nutation applied), tilted by the
+    /// true obliquity ε + Δε. Longitudes differ from [`Obliquity::Mean`] by
+    /// the nutation in longitude Δψ (up to ~17 arcsec); latitudes agree.
+    True,
+}
+
+/// Galactic coordinate system (IAU 1958, referred to ICRS).
+///
+/// # Coordinate Axes
+///
+/// - **X axis**: Towards the Galactic center (l = 0°, b = 0°)
+/// - **Y axis**: Towards l = 90° in the Galactic plane
+/// - **Z axis**: Towards the north Galactic pole (RA 192.85948°, Dec 27.12825°)
+///
+/// # Properties
+///
+/// - **Origin**: Same as ICRS
+/// - **Rotation**: Fixed with respect to ICRS
+///
+/// # Applications
+///
+/// - Radio astronomy survey coordinates
+/// - Galactic structure and interstellar medium studies
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Galactic;
+
+impl CoordinateSystem for Galactic {
+    type Convention = CelestialConvention;
+}
+
+/// Topocentric horizontal frame of a ground [`Observer`].
+///
+/// # Coordinate Axes
+///
+/// - **X axis**: Local east
+/// - **Y axis**: Local north
+/// - **Z axis**: Up, along the WGS84 ellipsoid normal (geodetic zenith)
+///
+/// # Properties
+///
+/// - **Origin**: The observer's position
+/// - **Rotation**: Fixed to the Earth; see
+///   [`transforms::icrs_to_topocentric_at`](crate::transforms::icrs_to_topocentric_at)
+///
+/// Altitude and azimuth follow from
+/// [`TopocentricCoordinateExt::to_alt_az`](crate::TopocentricCoordinateExt::to_alt_az).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Topocentric;
+
+impl CoordinateSystem for Topocentric {
+    type Convention = CelestialConvention;
+}
+
+/// Ground observer at a geodetic position on the WGS84 ellipsoid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observer {
+    /// Geodetic latitude
+    pub latitude: Angle,
+    /// Longitude, positive east
+    pub longitude: Angle,
+    /// Height above the ellipsoid
+    pub altitude: Length,
+}
+
+impl Observer {
+    /// Create an observer from geodetic latitude, longitude and ellipsoidal height.
+    #[must_use]
+    pub fn new(latitude: Angle, longitude: Angle, altitude: Length) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Observer position in ECEF.
+    ///
+    /// See [`geodetic_to_ecef`](crate::geodetic_to_ecef).
+    #[must_use]
+    pub fn to_ecef(&self) -> Coordinate<Ecef> {
+        crate::ext::geodetic_to_ecef(self.latitude, self.longitude, self.altitude)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +243,26 @@ mod tests {
         fn check_system<S: CoordinateSystem>() {}
         check_system::<Ecliptic>();
     }
+
+    #[test]
+    fn ecliptic_of_date_implements_coordinate_system() {
+        fn check_system<S: CoordinateSystem>() {}
+        check_system::<EclipticOfDate>();
+    }
+
+    #[test]
+    fn observer_ecef_matches_wgs84_axes() {
+        use crate::constants::{EARTH_RADIUS_EQUATORIAL, EARTH_RADIUS_POLAR};
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        let equator = Observer::new(Angle::new::<degree>(0.0), Angle::new::<degree>(90.0), Length::new::<meter>(100.0));
+        let [x, y, z] = equator.to_ecef().to_cartesian().map(|c| c.get::<meter>());
+        assert!(x.abs() < 1e-6 && z.abs() < 1e-6);
+        assert!((y - EARTH_RADIUS_EQUATORIAL - 100.0).abs() < 1e-6);
+
+        let pole = Observer::new(Angle::new::<degree>(90.0), Angle::new::<degree>(0.0), Length::new::<meter>(0.0));
+        let [_, _, z] = pole.to_ecef().to_cartesian().map(|c| c.get::<meter>());
+        assert!((z - EARTH_RADIUS_POLAR).abs() < 1e-6);
+    }
 }
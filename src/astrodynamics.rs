@@ -3,7 +3,24 @@
 //! This module provides specialized vector types for spacecraft dynamics
 //! that use appropriate unit dimensions from the `uom` crate.
 
-use sguaba::Vector;
+use chrono::{DateTime, Utc};
+use nalgebra::Vector3;
+use sguaba::{Coordinate, Vector};
+use uom::si::angle::radian;
+use uom::si::f64::{Angle, Length, Velocity};
+use uom::si::length::meter;
+use uom::si::velocity::{kilometer_per_second, meter_per_second};
+
+use crate::constants::{
+    precession_between_epochs, utc_to_julian_date, AU_METERS, EARTH_RADIUS_MEAN, J2000_JD, SUN_RADIUS,
+};
+use crate::frames::Icrs;
+use crate::errors::CelestialResult;
+use crate::orbital::{seconds_between, KeplerianElements};
+use crate::time_scales::checked_add_seconds;
+use crate::timed::{velocity_components, velocity_from_components, EphemerisState};
+use sguaba::systems::Ecef;
+use crate::transforms::icrs_to_ecef_at;
 
 /// Angular velocity vector (rad/s).
 ///
@@ -57,10 +74,431 @@ pub type AccelerationVector<S> = Vector<S, typenum::N2>;
 /// Another fundamental orbital parameter.
 pub type SpecificEnergy = uom::si::f64::Velocity; // Actually m²/s², but velocity has same dims
 
+/// Velocity from a speed in km/s.
+#[must_use]
+pub fn kmps(v: f64) -> Velocity {
+    Velocity::new::<kilometer_per_second>(v)
+}
+
+/// Velocity from a speed in m/s.
+#[must_use]
+pub fn mps(v: f64) -> Velocity {
+    Velocity::new::<meter_per_second>(v)
+}
+
+/// Speed of a velocity in km/s.
+#[must_use]
+pub fn as_kmps(v: &Velocity) -> f64 {
+    v.get::<kilometer_per_second>()
+}
+
+/// Geocentric position of the Sun in ICRS (low-precision solar ephemeris).
+///
+/// Uses the Astronomical Almanac low-precision formulae (mean longitude, mean
+/// anomaly, ecliptic longitude, obliquity), precessed from the equinox of date
+/// to J2000. Accurate to ~0.01° in direction for 1950-2050.
+#[must_use]
+pub fn sun_position_icrs(time: DateTime<Utc>) -> Coordinate<Icrs> {
+    let jd = utc_to_julian_date(time);
+    let (lambda, r, eps) = solar_ecliptic_of_date(jd);
+
+    let of_date = Vector3::new(
+        r * lambda.cos(),
+        r * eps.cos() * lambda.sin(),
+        r * eps.sin() * lambda.sin(),
+    );
+    let j2000 = precession_between_epochs(J2000_JD, jd).transform_vector(&of_date);
+
+    #[allow(deprecated)]
+    Coordinate::<Icrs>::from_cartesian(
+        Length::new::<meter>(j2000.x),
+        Length::new::<meter>(j2000.y),
+        Length::new::<meter>(j2000.z),
+    )
+}
+
+/// Apparent declination of the Sun.
+///
+/// `sin δ = sin ε · sin λ` from the same low-precision ecliptic longitude λ
+/// and obliquity of date ε as [`sun_position_icrs`], without the precession
+/// to J2000. Accurate to ~0.01°; ranges over about ±23.44° through the year
+/// and crosses zero at the equinoxes.
+#[must_use]
+pub fn solar_declination(time: DateTime<Utc>) -> Angle {
+    let (lambda, _, eps) = solar_ecliptic_of_date(utc_to_julian_date(time));
+    Angle::new::<radian>((eps.sin() * lambda.sin()).asin())
+}
+
+/// Ecliptic longitude (rad), distance (m) and obliquity of date (rad) of the
+/// Sun, from the Astronomical Almanac low-precision formulae.
+fn solar_ecliptic_of_date(jd: f64) -> (f64, f64, f64) {
+    let n = jd - J2000_JD;
+
+    // Mean longitude and mean anomaly (degrees)
+    let mean_longitude = 280.460 + 0.985_647_4 * n;
+    let g = (357.528 + 0.985_600_3 * n).to_radians();
+
+    // Ecliptic longitude, distance (AU) and obliquity of date
+    let lambda = (mean_longitude + 1.915 * g.sin() + 0.020 * (2.0 * g).sin()).to_radians();
+    let r = (1.000_14 - 0.016_71 * g.cos() - 0.000_14 * (2.0 * g).cos()) * AU_METERS;
+    let eps = (23.439 - 0.000_000_4 * n).to_radians();
+
+    (lambda, r, eps)
+}
+
+/// Geocentric position of the Moon in ICRS (low-precision lunar ephemeris).
+///
+/// Sums the largest terms of the ELP-2000/82 series as truncated by Meeus
+/// (*Astronomical Algorithms*, ch. 47) for the ecliptic position of date, then
+/// rotates to the equator and precesses to J2000 like [`sun_position_icrs`].
+/// Accurate to ~0.1° in direction and a few hundred km in distance; nutation
+/// and light time are neglected.
+#[must_use]
+pub fn moon_position_icrs(time: DateTime<Utc>) -> Coordinate<Icrs> {
+    let jd = utc_to_julian_date(time);
+    let (lambda, beta, r) = lunar_ecliptic_of_date(jd);
+    let eps = (23.439 - 0.000_000_4 * (jd - J2000_JD)).to_radians();
+
+    let ecliptic = Vector3::new(r * beta.cos() * lambda.cos(), r * beta.cos() * lambda.sin(), r * beta.sin());
+    let of_date = nalgebra::Rotation3::from_axis_angle(&Vector3::x_axis(), eps) * ecliptic;
+    let j2000 = precession_between_epochs(J2000_JD, jd).transform_vector(&of_date);
+
+    crate::icrs_from_m(j2000.x, j2000.y, j2000.z)
+}
+
+/// Ecliptic longitude (rad), latitude (rad) and distance (m) of the Moon,
+/// referred to the mean equinox of date.
+fn lunar_ecliptic_of_date(jd: f64) -> (f64, f64, f64) {
+    /// Longitude and distance terms: multiples of D, M, M′, F; Σl (1e-6 °); Σr (m).
+    const LONGITUDE_DISTANCE: [(f64, f64, f64, f64, f64, f64); 13] = [
+        (0.0, 0.0, 1.0, 0.0, 6_288_774.0, -20_905_355.0),
+        (2.0, 0.0, -1.0, 0.0, 1_274_027.0, -3_699_111.0),
+        (2.0, 0.0, 0.0, 0.0, 658_314.0, -2_955_968.0),
+        (0.0, 0.0, 2.0, 0.0, 213_618.0, -569_925.0),
+        (0.0, 1.0, 0.0, 0.0, -185_116.0, 48_888.0),
+        (0.0, 0.0, 0.0, 2.0, -114_332.0, -3_149.0),
+        (2.0, 0.0, -2.0, 0.0, 58_793.0, 246_158.0),
+        (2.0, -1.0, -1.0, 0.0, 57_066.0, -152_138.0),
+        (2.0, 0.0, 1.0, 0.0, 53_322.0, -170_733.0),
+        (2.0, -1.0, 0.0, 0.0, 45_758.0, -204_586.0),
+        (0.0, 1.0, -1.0, 0.0, -40_923.0, -129_620.0),
+        (1.0, 0.0, 0.0, 0.0, -34_720.0, 108_743.0),
+        (0.0, 1.0, 1.0, 0.0, -30_383.0, 104_755.0),
+    ];
+    /// Latitude terms: multiples of D, M, M′, F; Σb (1e-6 °).
+    const LATITUDE: [(f64, f64, f64, f64, f64); 10] = [
+        (0.0, 0.0, 0.0, 1.0, 5_128_122.0),
+        (0.0, 0.0, 1.0, 1.0, 280_602.0),
+        (0.0, 0.0, 1.0, -1.0, 277_693.0),
+        (2.0, 0.0, 0.0, -1.0, 173_237.0),
+        (2.0, 0.0, -1.0, 1.0, 55_413.0),
+        (2.0, 0.0, -1.0, -1.0, 46_271.0),
+        (2.0, 0.0, 0.0, 1.0, 32_573.0),
+        (0.0, 0.0, 2.0, 1.0, 17_198.0),
+        (2.0, 0.0, 1.0, -1.0, 9_266.0),
+        (0.0, 0.0, 2.0, -1.0, 8_822.0),
+    ];
+
+    let t = (jd - J2000_JD) / 36_525.0;
+
+    // Mean longitude, elongation, solar and lunar anomalies, argument of latitude (degrees)
+    let mean_longitude = 218.316_447_7 + 481_267.881_234_21 * t;
+    let d = (297.850_192_1 + 445_267.111_403_4 * t).to_radians();
+    let m = (357.529_109_2 + 35_999.050_290_9 * t).to_radians();
+    let m_moon = (134.963_396_4 + 477_198.867_505_5 * t).to_radians();
+    let f = (93.272_095_0 + 483_202.017_523_3 * t).to_radians();
+    // Decreasing eccentricity of Earth's orbit scales the terms in M
+    let e = 1.0 - 0.002_516 * t;
+
+    let argument = |cd: f64, cm: f64, cmm: f64, cf: f64| {
+        (cd * d + cm * m + cmm * m_moon + cf * f, e.powi(cm.abs() as i32))
+    };
+    let (mut sum_l, mut sum_r) = (0.0, 0.0);
+    for (cd, cm, cmm, cf, l, r) in LONGITUDE_DISTANCE {
+        let (angle, scale) = argument(cd, cm, cmm, cf);
+        sum_l += scale * l * angle.sin();
+        sum_r += scale * r * angle.cos();
+    }
+    let sum_b: f64 = LATITUDE
+        .iter()
+        .map(|&(cd, cm, cmm, cf, b)| {
+            let (angle, scale) = argument(cd, cm, cmm, cf);
+            scale * b * angle.sin()
+        })
+        .sum();
+
+    let lambda = (mean_longitude + sum_l * 1e-6).rem_euclid(360.0).to_radians();
+    let beta = (sum_b * 1e-6).to_radians();
+    let r = 385_000_560.0 + sum_r;
+
+    (lambda, beta, r)
+}
+
+/// Geodetic latitude and longitude of the sub-solar point (solar nadir).
+///
+/// The sub-solar point is where the Sun is at the zenith; it anchors the
+/// day/night terminator. Computed from [`sun_position_icrs`] transformed to ECEF.
+///
+/// # Returns
+///
+/// `(latitude, longitude)` with longitude in (-π, π].
+#[must_use]
+pub fn subsolar_point(time: DateTime<Utc>) -> (Angle, Angle) {
+    let sun_ecef = icrs_to_ecef_at(time).transform(sun_position_icrs(time));
+    let [x, y, z] = sun_ecef.to_cartesian().map(|c| c.get::<meter>());
+
+    // For a direction at infinity, the ellipsoid normal pointing along it has a
+    // geodetic latitude equal to the direction's declination.
+    let latitude = z.atan2(x.hypot(y));
+    let longitude = y.atan2(x);
+
+    (Angle::new::<radian>(latitude), Angle::new::<radian>(longitude))
+}
+
+/// Whether a point on or near the Earth is in sunlight.
+///
+/// Intended for imaging tasking: a target is lit when it lies on the day side
+/// of the Earth, or on the night side but outside the Earth's (cylindrical)
+/// shadow, as seen along the sub-solar direction.
+#[must_use]
+pub fn target_sunlit(target_ecef: &Coordinate<Ecef>, time: DateTime<Utc>) -> bool {
+    let (lat, lon) = subsolar_point(time);
+    let (sin_lat, cos_lat) = lat.get::<radian>().sin_cos();
+    let (sin_lon, cos_lon) = lon.get::<radian>().sin_cos();
+    let sun = Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat);
+
+    let target = Vector3::from(target_ecef.to_cartesian().map(|c| c.get::<meter>()));
+    let along = target.dot(&sun);
+
+    along >= 0.0 || (target - along * sun).norm() > EARTH_RADIUS_MEAN
+}
+
+/// Illumination of a satellite by the Sun, as blocked by the Earth.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShadowState {
+    /// The whole solar disc is visible
+    Sunlit,
+    /// The Earth covers part of the solar disc (including annular geometry)
+    Penumbra,
+    /// The Earth covers the whole solar disc
+    Umbra,
+}
+
+/// Earth-shadow state of a satellite (conical shadow model).
+///
+/// Compares the apparent discs of the Sun (radius [`SUN_RADIUS`], position
+/// from [`sun_position_icrs`]) and of a spherical Earth of radius
+/// [`EARTH_RADIUS_MEAN`] as seen from the satellite. Unlike the cylindrical
+/// model of [`target_sunlit`], this resolves the penumbra, which lasts
+/// several seconds per eclipse in LEO and minutes in GEO. Atmospheric
+/// refraction and oblateness are neglected.
+#[must_use]
+pub fn shadow_state(sat: &Coordinate<Icrs>, time: DateTime<Utc>) -> ShadowState {
+    let r = Vector3::from(sat.to_cartesian().map(|c| c.get::<meter>()));
+    let to_sun = Vector3::from(sun_position_icrs(time).to_cartesian().map(|c| c.get::<meter>())) - r;
+    let to_earth = -r;
+
+    let r_norm = r.norm();
+    if r_norm <= EARTH_RADIUS_MEAN {
+        return ShadowState::Umbra;
+    }
+
+    // Apparent radii of the Sun and the Earth and the separation of their centres
+    let sun = (SUN_RADIUS / to_sun.norm()).asin();
+    let earth = (EARTH_RADIUS_MEAN / r_norm).asin();
+    let separation = crate::rotation_helper::angle_between(&to_sun, &to_earth);
+
+    if separation >= sun + earth {
+        ShadowState::Sunlit
+    } else if separation <= earth - sun {
+        ShadowState::Umbra
+    } else {
+        ShadowState::Penumbra
+    }
+}
+
+/// Whether a satellite is eclipsed by the Earth, fully or partially.
+///
+/// True in [`ShadowState::Umbra`] and [`ShadowState::Penumbra`]; see
+/// [`shadow_state`] to tell them apart.
+#[must_use]
+pub fn in_earth_shadow(sat: &Coordinate<Icrs>, time: DateTime<Utc>) -> bool {
+    shadow_state(sat, time) != ShadowState::Sunlit
+}
+
+/// Beta angle of an orbit: the Sun's elevation above the orbit plane.
+///
+/// `β = asin(ĥ · ŝ)`, where `ĥ` is the orbit normal from the inclination and
+/// RAAN and `ŝ` the geocentric Sun direction from [`sun_position_icrs`].
+/// Positive when the Sun is on the side of the orbit normal, i.e. the orbit
+/// runs counter-clockwise as seen from the Sun; ranges over [-90°, 90°]. A
+/// circular orbit is eclipsed on every revolution while
+/// `|β| < asin(R_E / a)`, and not at all once `|β|` exceeds that.
+#[must_use]
+pub fn beta_angle(elements: &KeplerianElements, time: DateTime<Utc>) -> Angle {
+    let (sin_i, cos_i) = elements.inclination.get::<radian>().sin_cos();
+    let (sin_raan, cos_raan) = elements.raan.get::<radian>().sin_cos();
+    let normal = Vector3::new(sin_i * sin_raan, -sin_i * cos_raan, cos_i);
+
+    let sun = Vector3::from(sun_position_icrs(time).to_cartesian().map(|c| c.get::<meter>())).normalize();
+    Angle::new::<radian>(normal.dot(&sun).clamp(-1.0, 1.0).asin())
+}
+
+/// Point-mass gravitational acceleration `-μ·r/|r|³` (m/s²).
+#[must_use]
+pub fn two_body_acceleration(r: [f64; 3], mu: f64) -> [f64; 3] {
+    let r = Vector3::from(r);
+    (-mu / r.norm().powi(3) * r).into()
+}
+
+/// Perturbing acceleration from the J2 zonal harmonic (m/s²).
+///
+/// Only the perturbation is returned; add [`two_body_acceleration`] for the
+/// full field. Assumes the body's pole is along the frame's z-axis, which is
+/// accurate to the precession of the equator since J2000 for Earth in ICRS.
+///
+/// # Arguments
+///
+/// * `r` - Position relative to the body centre (m)
+/// * `mu` - Gravitational parameter (m³/s²)
+/// * `j2` - Second zonal harmonic, e.g. [`J2_EARTH`](crate::constants::J2_EARTH)
+/// * `r_body` - Reference (equatorial) radius of the harmonic (m)
+#[must_use]
+pub fn j2_acceleration(r: [f64; 3], mu: f64, j2: f64, r_body: f64) -> [f64; 3] {
+    let [x, y, z] = r;
+    let r2 = x * x + y * y + z * z;
+    let r_norm = r2.sqrt();
+    let k = -1.5 * j2 * mu * r_body * r_body / (r2 * r2 * r_norm);
+    let zz = 5.0 * z * z / r2;
+
+    [k * x * (1.0 - zz), k * y * (1.0 - zz), k * z * (3.0 - zz)]
+}
+
+/// Acceleration from a constant-magnitude thrust along `direction` (m/s²).
+///
+/// `direction` need not be normalized; a zero vector yields no thrust. The
+/// pointing law is chosen by what the force-model closure passes in: the
+/// current velocity for along-velocity (prograde) thrust, or a fixed vector
+/// for inertial-fixed pointing. Mass depletion is not modelled.
+///
+/// # Arguments
+///
+/// * `direction` - Thrust direction in the integration frame
+/// * `magnitude` - Thrust force (N)
+/// * `mass` - Spacecraft mass (kg)
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "celestial")] {
+/// use crate::astrodynamics::{constant_thrust_acceleration, two_body_acceleration};
+/// use crate::constants::MU_EARTH;
+///
+/// // Prograde 0.5 N on a 500 kg spacecraft
+/// let force_model = |_t: f64, r: [f64; 3], v: [f64; 3]| {
+///     let [gx, gy, gz] = two_body_acceleration(r, MU_EARTH);
+///     let [tx, ty, tz] = constant_thrust_acceleration(v, 0.5, 500.0);
+///     [gx + tx, gy + ty, gz + tz]
+/// };
+/// # }
+/// ```
+#[must_use]
+pub fn constant_thrust_acceleration(direction: [f64; 3], magnitude: f64, mass: f64) -> [f64; 3] {
+    let direction = Vector3::from(direction);
+    let norm = direction.norm();
+    if norm == 0.0 {
+        return [0.0; 3];
+    }
+
+    (magnitude / mass / norm * direction).into()
+}
+
+/// Propagate a state with a fixed-step fourth-order Runge-Kutta integrator.
+///
+/// The force model is supplied as a closure `acceleration(t, r, v)` taking the
+/// seconds elapsed since the initial epoch, position (m) and velocity (m/s),
+/// and returning the total acceleration (m/s²). Combine
+/// [`two_body_acceleration`], [`j2_acceleration`] and any other terms inside it.
+///
+/// # Arguments
+///
+/// * `initial` - State at the start of the arc
+/// * `end` - Epoch to integrate to
+/// * `step` - Step size (s); the final step is shortened to land on `end`
+/// * `acceleration` - Force model
+///
+/// # Returns
+///
+/// The state at every step, starting with `initial` and ending at `end`.
+///
+/// # Errors
+///
+/// Returns `CelestialError::TimeScaleConversionFailed` if a step epoch cannot
+/// be represented.
+pub fn integrate_rk4<F>(
+    initial: &EphemerisState<Icrs>,
+    end: DateTime<Utc>,
+    step: f64,
+    acceleration: F,
+) -> CelestialResult<Vec<EphemerisState<Icrs>>>
+where
+    F: Fn(f64, [f64; 3], [f64; 3]) -> [f64; 3],
+{
+    let start = initial.epoch();
+    let duration = seconds_between(start, end);
+    let mut states = vec![*initial];
+    if duration <= 0.0 || step <= 0.0 {
+        return Ok(states);
+    }
+
+    let accel = |t: f64, r: &Vector3<f64>, v: &Vector3<f64>| {
+        Vector3::from(acceleration(t, (*r).into(), (*v).into()))
+    };
+
+    let mut r = Vector3::from(initial.position().to_cartesian().map(|c| c.get::<meter>()));
+    let mut v = Vector3::from(velocity_components(initial.velocity()));
+    let mut t = 0.0;
+
+    while t < duration {
+        let h = step.min(duration - t);
+
+        let k1v = accel(t, &r, &v);
+        let k1r = v;
+        let k2v = accel(t + h / 2.0, &(r + k1r * h / 2.0), &(v + k1v * h / 2.0));
+        let k2r = v + k1v * h / 2.0;
+        let k3v = accel(t + h / 2.0, &(r + k2r * h / 2.0), &(v + k2v * h / 2.0));
+        let k3r = v + k2v * h / 2.0;
+        let k4v = accel(t + h, &(r + k3r * h), &(v + k3v * h));
+        let k4r = v + k3v * h;
+
+        r += (k1r + 2.0 * k2r + 2.0 * k3r + k4r) * h / 6.0;
+        v += (k1v + 2.0 * k2v + 2.0 * k3v + k4v) * h / 6.0;
+        t += h;
+
+        #[allow(deprecated)]
+        let position = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<meter>(r.x),
+            Length::new::<meter>(r.y),
+            Length::new::<meter>(r.z),
+        );
+        let epoch = checked_add_seconds(start, t)?;
+        states.push(EphemerisState::new(position, velocity_from_components(v.into()), epoch));
+    }
+
+    Ok(states)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Icrs;
+    use chrono::TimeZone;
+    use uom::si::angle::degree;
+
+    #[test]
+    fn velocity_unit_helpers_roundtrip() {
+        assert_eq!(as_kmps(&kmps(7.5)), 7.5);
+        assert!((as_kmps(&mps(7546.0)) - 7.546).abs() < 1e-12);
+    }
 
     #[test]
     fn type_dimensions_compile() {
@@ -69,4 +507,189 @@ mod tests {
         let _: Option<SpecificAngularMomentum<Icrs>> = None;
         let _: Option<AccelerationVector<Icrs>> = None;
     }
+
+    #[test]
+    fn subsolar_latitude_tracks_seasons() {
+        let lat_at = |m, d| subsolar_point(Utc.with_ymd_and_hms(2025, m, d, 12, 0, 0).unwrap()).0;
+
+        let march = lat_at(3, 20).get::<degree>();
+        let june = lat_at(6, 21).get::<degree>();
+        let december = lat_at(12, 21).get::<degree>();
+
+        assert!(march.abs() < 0.5);
+        assert!((june - 23.44).abs() < 0.5);
+        assert!((december + 23.44).abs() < 0.5);
+
+        for month in 1..=12 {
+            assert!(lat_at(month, 1).get::<degree>().abs() <= 23.5);
+        }
+    }
+
+    #[test]
+    fn solar_declination_spans_obliquity_and_vanishes_at_equinoxes() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let declinations: Vec<f64> = (0..365)
+            .map(|day| solar_declination(start + chrono::Duration::days(day)).get::<degree>())
+            .collect();
+        let max = declinations.iter().cloned().fold(f64::MIN, f64::max);
+        let min = declinations.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(max < 23.5 && max > 23.4);
+        assert!(min > -23.5 && min < -23.4);
+
+        for equinox in [
+            Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 9, 22, 18, 19, 0).unwrap(),
+        ] {
+            assert!(solar_declination(equinox).get::<degree>().abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn sun_position_reaches_obliquity_at_solstices() {
+        use crate::ext::IcrsCoordinateExt;
+
+        for (solstice, expected) in [
+            (Utc.with_ymd_and_hms(2025, 6, 21, 2, 42, 0).unwrap(), 23.44),
+            (Utc.with_ymd_and_hms(2025, 12, 21, 15, 3, 0).unwrap(), -23.44),
+        ] {
+            let (ra, dec, distance) = sun_position_icrs(solstice).to_spherical_celestial();
+            assert!((dec.get::<degree>() - expected).abs() < 0.02);
+            assert!((ra.get::<degree>() - if expected > 0.0 { 90.0 } else { 270.0 }).abs() < 0.5);
+            assert!((distance.get::<meter>() / AU_METERS - 1.0).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn moon_position_matches_meeus_example() {
+        use crate::ext::IcrsCoordinateExt;
+
+        // Meeus, Astronomical Algorithms, example 47.a (1992 April 12, 0h TD):
+        // λ = 133.162655°, β = -3.229126°, Δ = 368409.7 km
+        let time = Utc.with_ymd_and_hms(1992, 4, 12, 0, 0, 0).unwrap();
+        let (lambda, beta, r) = lunar_ecliptic_of_date(utc_to_julian_date(time));
+        assert!((lambda.to_degrees() - 133.162_655).abs() < 0.05);
+        assert!((beta.to_degrees() + 3.229_126).abs() < 0.05);
+        assert!((r / 1000.0 - 368_409.7).abs() < 100.0);
+
+        // Apparent δ = 13.768368° of date; precession to J2000 moves it by ~0.03°
+        let (_, dec, distance) = moon_position_icrs(time).to_spherical_celestial();
+        assert!((dec.get::<degree>() - 13.768).abs() < 0.15);
+        assert!((distance.get::<meter>() - r).abs() < 1e-3);
+    }
+
+    #[test]
+    fn shadow_state_between_and_behind_earth() {
+        let time = Utc.with_ymd_and_hms(2025, 9, 1, 6, 0, 0).unwrap();
+        let sun = Vector3::from(sun_position_icrs(time).to_cartesian().map(|c| c.get::<meter>())).normalize();
+        let across = sun.cross(&Vector3::z()).normalize();
+        let at = |v: Vector3<f64>| crate::icrs_from_m(v.x, v.y, v.z);
+        let leo = EARTH_RADIUS_MEAN + 500e3;
+
+        let between = at(leo * sun);
+        assert_eq!(shadow_state(&between, time), ShadowState::Sunlit);
+        assert!(!in_earth_shadow(&between, time));
+
+        let behind = at(-leo * sun);
+        assert_eq!(shadow_state(&behind, time), ShadowState::Umbra);
+        assert!(in_earth_shadow(&behind, time));
+
+        // Grazing the limb: the solar disc is cut by the Earth's edge
+        let limb = at(-leo * sun + EARTH_RADIUS_MEAN * across);
+        assert_eq!(shadow_state(&limb, time), ShadowState::Penumbra);
+        assert_eq!(shadow_state(&at(-leo * sun + 1.01 * EARTH_RADIUS_MEAN * across), time), ShadowState::Sunlit);
+    }
+
+    #[test]
+    fn beta_angle_is_stable_for_sun_synchronous_orbit() {
+        use crate::constants::MU_EARTH;
+        use crate::ext::IcrsCoordinateExt;
+        use uom::si::length::kilometer;
+
+        let time = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let (sun_ra, _, _) = sun_position_icrs(time).to_spherical_celestial();
+
+        // Dawn-dusk orbit: the node sits 90° east of the Sun and drifts with it
+        let sso = KeplerianElements {
+            raan: sun_ra + Angle::new::<degree>(90.0),
+            ..KeplerianElements::circular_at_altitude(
+                Length::new::<kilometer>(700.0),
+                Angle::new::<degree>(98.2),
+                Length::new::<kilometer>(6378.137),
+                MU_EARTH,
+            )
+        };
+        let next_day = KeplerianElements { raan: sso.raan + Angle::new::<degree>(360.0 / 365.2422), ..sso };
+
+        let beta = beta_angle(&sso, time).get::<degree>();
+        assert!(beta > 60.0, "dawn-dusk beta should be large, got {beta}");
+        let drift = beta_angle(&next_day, time + chrono::Duration::days(1)).get::<degree>() - beta;
+        assert!(drift.abs() < 0.5);
+
+        // Equatorial orbit at the equinox: Sun in the orbit plane, up to the
+        // ~0.14° precession of the equator of date away from ICRS's J2000 one
+        let equatorial = KeplerianElements { inclination: Angle::new::<degree>(0.0), ..sso };
+        let equinox = Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap();
+        assert!(beta_angle(&equatorial, equinox).get::<degree>().abs() < 0.2);
+    }
+
+    #[test]
+    fn subsolar_longitude_near_greenwich_at_noon_utc() {
+        // Within the equation of time (< 17 min ≈ 4.2°) of the prime meridian
+        let (_, lon) = subsolar_point(Utc.with_ymd_and_hms(2025, 4, 15, 12, 0, 0).unwrap());
+        assert!(lon.get::<degree>().abs() < 5.0);
+    }
+
+    #[test]
+    fn day_side_is_lit_and_night_side_is_not() {
+        let time = Utc.with_ymd_and_hms(2025, 9, 1, 6, 0, 0).unwrap();
+        let (lat, lon) = subsolar_point(time);
+        let (lat, lon) = (lat.get::<radian>(), lon.get::<radian>());
+
+        let surface = |sign: f64| {
+            let r = sign * EARTH_RADIUS_MEAN;
+            #[allow(deprecated)]
+            Coordinate::<Ecef>::from_cartesian(
+                Length::new::<meter>(r * lat.cos() * lon.cos()),
+                Length::new::<meter>(r * lat.cos() * lon.sin()),
+                Length::new::<meter>(r * lat.sin()),
+            )
+        };
+
+        assert!(target_sunlit(&surface(1.0), time));
+        assert!(!target_sunlit(&surface(-1.0), time));
+    }
+
+    #[test]
+    fn prograde_thrust_raises_orbit_energy() {
+        use crate::constants::MU_EARTH;
+        use crate::orbital::KeplerianElements;
+
+        let (position, velocity) = KeplerianElements::default().to_state_vectors();
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let state = EphemerisState::new(position, velocity_from_components(velocity), epoch);
+        let energy = |s: &EphemerisState<Icrs>| {
+            let r = s.position().distance_from_origin().get::<meter>();
+            let v = Vector3::from(velocity_components(s.velocity())).norm();
+            0.5 * v * v - MU_EARTH / r
+        };
+
+        let end = epoch + chrono::Duration::minutes(30);
+        let arc = |thrust: f64| {
+            let states = integrate_rk4(&state, end, 10.0, |_, r, v| {
+                let [gx, gy, gz] = two_body_acceleration(r, MU_EARTH);
+                let [tx, ty, tz] = constant_thrust_acceleration(v, thrust, 500.0);
+                [gx + tx, gy + ty, gz + tz]
+            })
+            .unwrap();
+            energy(&states[states.len() - 1])
+        };
+
+        let coast = arc(0.0);
+        let burn = arc(1.0);
+
+        // Along-velocity thrust does work F·|v|·Δt/m on the orbit
+        let v0 = Vector3::from(velocity).norm();
+        assert!((coast - energy(&state)).abs() < 1e-3);
+        assert!((burn - coast - 1.0 / 500.0 * v0 * 1800.0).abs() < 0.01 * v0 * 1800.0 / 500.0);
+    }
 }
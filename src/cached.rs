@@ -5,12 +5,17 @@
 use sguaba::math::RigidBodyTransform;
 use sguaba::CoordinateSystem;
 use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
 
+use crate::errors::CelestialResult;
+
 /// A cached transformation between coordinate systems with epoch-based invalidation.
 ///
 /// This type caches a `RigidBodyTransform` and automatically invalidates it
-/// when the epoch changes beyond a tolerance threshold.
+/// when the epoch changes beyond a tolerance threshold. By default one entry
+/// is kept; [`with_capacity`](Self::with_capacity) keeps the most recently
+/// used few, so callers alternating between several epochs do not thrash it.
 ///
 /// # Example
 ///
@@ -40,8 +45,10 @@ use std::sync::{Arc, RwLock};
 /// ```
 #[derive(Debug)]
 pub struct CachedTransform<From: CoordinateSystem, To: CoordinateSystem> {
-    cached: Arc<RwLock<Option<CacheEntry<From, To>>>>,
+    /// Entries ordered from most to least recently used
+    cached: Arc<RwLock<VecDeque<CacheEntry<From, To>>>>,
     tolerance: Duration,
+    capacity: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -57,16 +64,34 @@ impl<From: CoordinateSystem, To: CoordinateSystem> CachedTransform<From, To> {
     /// that differs from the cached epoch by more than `tolerance`.
     #[must_use]
     pub fn new(tolerance: Duration) -> Self {
+        Self::with_capacity(tolerance, 1)
+    }
+
+    /// Create a cached transform holding up to `capacity` epochs.
+    ///
+    /// Lookups return any entry within `tolerance` of the requested epoch;
+    /// when full, a miss evicts the least recently used entry. A capacity of
+    /// zero is treated as one.
+    ///
+    /// Entries live in a recency-ordered list that is scanned linearly rather
+    /// than keyed by epoch bucket. That is cheaper than hashing for the few
+    /// epochs this is meant for, and unlike buckets it still hits when the
+    /// requested and cached epochs straddle a bucket boundary. Keep capacities
+    /// small; each miss scans every entry.
+    #[must_use]
+    pub fn with_capacity(tolerance: Duration, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
         Self {
-            cached: Arc::new(RwLock::new(None)),
+            cached: Arc::new(RwLock::new(VecDeque::with_capacity(capacity))),
             tolerance,
+            capacity,
         }
     }
 
     /// Get the cached transform or compute a new one.
     ///
-    /// If the cache is empty or the epoch differs by more than the tolerance,
-    /// `compute_fn` will be called to generate a new transform.
+    /// If no cached entry lies within the tolerance of `epoch`, `compute_fn`
+    /// will be called to generate a new transform.
     ///
     /// # Arguments
     ///
@@ -79,47 +104,92 @@ impl<From: CoordinateSystem, To: CoordinateSystem> CachedTransform<From, To> {
         To: Clone,
     {
         // Try to read from cache
-        {
-            let cache_read = self.cached.read().unwrap();
-            if let Some(entry) = cache_read.as_ref() {
-                let time_diff = (epoch - entry.epoch).num_seconds().abs();
-                if time_diff <= self.tolerance.num_seconds() {
-                    return entry.transform;
-                }
-            }
+        if let Some(transform) = self.lookup(epoch) {
+            return transform;
         }
 
         // Cache miss - compute new transform
         let new_transform = compute_fn(epoch);
+        self.store(epoch, new_transform);
+        new_transform
+    }
+
+    /// Get the cached transform or compute a new one with a fallible closure.
+    ///
+    /// Like [`get_or_compute`](Self::get_or_compute), but `compute_fn` may fail
+    /// (e.g. by calling `validate_epoch`). Only successful results are cached;
+    /// on error the cache is left untouched and the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error `compute_fn` returns on a cache miss.
+    pub fn get_or_compute_fallible<F>(
+        &self,
+        epoch: DateTime<Utc>,
+        compute_fn: F,
+    ) -> CelestialResult<RigidBodyTransform<From, To>>
+    where
+        F: FnOnce(DateTime<Utc>) -> CelestialResult<RigidBodyTransform<From, To>>,
+        From: Clone,
+        To: Clone,
+    {
+        if let Some(transform) = self.lookup(epoch) {
+            return Ok(transform);
+        }
+
+        let new_transform = compute_fn(epoch)?;
+        self.store(epoch, new_transform);
+        Ok(new_transform)
+    }
 
-        // Update cache
+    /// Cached transform within tolerance of `epoch`, marked most recently used.
+    fn lookup(&self, epoch: DateTime<Utc>) -> Option<RigidBodyTransform<From, To>>
+    where
+        From: Clone,
+        To: Clone,
+    {
+        // Hits on the most recent entry, the common case, only need a shared lock
         {
-            let mut cache_write = self.cached.write().unwrap();
-            *cache_write = Some(CacheEntry {
-                transform: new_transform,
-                epoch,
-            });
+            let cache_read = self.cached.read().unwrap();
+            let index = cache_read.iter().position(|entry| self.covers(entry, epoch))?;
+            if index == 0 {
+                return Some(cache_read[0].transform);
+            }
         }
 
-        new_transform
+        // Another thread may have reordered or evicted entries in between
+        let mut cache_write = self.cached.write().unwrap();
+        let index = cache_write.iter().position(|entry| self.covers(entry, epoch))?;
+        let entry = cache_write.remove(index)?;
+        let transform = entry.transform;
+        cache_write.push_front(entry);
+        Some(transform)
+    }
+
+    /// Insert an entry, evicting the least recently used one when full.
+    fn store(&self, epoch: DateTime<Utc>, transform: RigidBodyTransform<From, To>) {
+        let mut cache_write = self.cached.write().unwrap();
+        cache_write.push_front(CacheEntry { transform, epoch });
+        cache_write.truncate(self.capacity);
     }
 
-    /// Clear the cached transform.
+    /// Whether `entry` is within tolerance of `epoch`.
+    fn covers(&self, entry: &CacheEntry<From, To>, epoch: DateTime<Utc>) -> bool {
+        let time_diff = (epoch - entry.epoch).num_seconds().abs();
+        time_diff <= self.tolerance.num_seconds()
+    }
+
+    /// Clear all cached transforms.
     pub fn invalidate(&self) {
         let mut cache_write = self.cached.write().unwrap();
-        *cache_write = None;
+        cache_write.clear();
     }
 
     /// Check if the cache contains a valid entry for the given epoch.
     #[must_use]
     pub fn is_valid_for(&self, epoch: DateTime<Utc>) -> bool {
         let cache_read = self.cached.read().unwrap();
-        if let Some(entry) = cache_read.as_ref() {
-            let time_diff = (epoch - entry.epoch).num_seconds().abs();
-            time_diff <= self.tolerance.num_seconds()
-        } else {
-            false
-        }
+        cache_read.iter().any(|entry| self.covers(entry, epoch))
     }
 }
 
@@ -128,6 +198,7 @@ impl<From: CoordinateSystem, To: CoordinateSystem> Clone for CachedTransform<Fro
         Self {
             cached: Arc::clone(&self.cached),
             tolerance: self.tolerance,
+            capacity: self.capacity,
         }
     }
 }
@@ -194,6 +265,67 @@ mod tests {
         assert_eq!(call_count.load(Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn interleaved_epochs_compute_once_with_capacity() {
+        use chrono::TimeZone;
+
+        let epoch1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let epoch2 = epoch1 + Duration::minutes(30);
+        let epoch3 = epoch1 + Duration::minutes(60);
+
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let compute = |cached: &CachedTransform<Icrs, Mci>, epoch| {
+            let call_count = Arc::clone(&call_count);
+            cached.get_or_compute(epoch, move |_| {
+                call_count.fetch_add(1, Ordering::SeqCst);
+                unsafe { RigidBodyTransform::identity() }
+            });
+        };
+
+        let lru = CachedTransform::<Icrs, Mci>::with_capacity(Duration::seconds(10), 2);
+        for _ in 0..3 {
+            compute(&lru, epoch1);
+            compute(&lru, epoch2);
+        }
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+
+        // A third epoch evicts the least recently used one (epoch1)
+        compute(&lru, epoch3);
+        assert!(!lru.is_valid_for(epoch1));
+        assert!(lru.is_valid_for(epoch2) && lru.is_valid_for(epoch3));
+
+        // A single-entry cache recomputes on every alternation
+        call_count.store(0, Ordering::SeqCst);
+        let single = CachedTransform::<Icrs, Mci>::new(Duration::seconds(10));
+        for _ in 0..3 {
+            compute(&single, epoch1);
+            compute(&single, epoch2);
+        }
+        assert_eq!(call_count.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn front_hit_only_takes_a_read_lock() {
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let cached = CachedTransform::<Icrs, Mci>::with_capacity(Duration::seconds(10), 2);
+        cached.get_or_compute(epoch + Duration::minutes(5), |_| unsafe { RigidBodyTransform::identity() });
+        cached.get_or_compute(epoch, |_| unsafe { RigidBodyTransform::identity() });
+
+        // While another reader holds the lock, a lookup that needed the write
+        // lock would block, so give it a deadline instead of hanging the test
+        let reader = cached.cached.read().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let shared = cached.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(shared.lookup(epoch).is_some());
+        });
+        let hit = receiver.recv_timeout(std::time::Duration::from_secs(5));
+        drop(reader);
+        assert_eq!(hit, Ok(true));
+    }
+
     #[test]
     fn invalidate_clears_cache() {
         let cached = CachedTransform::<Icrs, Mci>::new(Duration::seconds(60));
@@ -207,4 +339,27 @@ mod tests {
         cached.invalidate();
         assert!(!cached.is_valid_for(epoch));
     }
+
+    #[test]
+    fn failing_compute_leaves_cache_empty() {
+        use crate::errors::CelestialError;
+        use crate::time_scales::validate_epoch;
+        use chrono::TimeZone;
+
+        let cached = CachedTransform::<Icrs, Mci>::new(Duration::seconds(60));
+        let epoch = Utc.with_ymd_and_hms(2200, 1, 1, 0, 0, 0).unwrap();
+
+        let result = cached.get_or_compute_fallible(epoch, |e| {
+            validate_epoch(e)?;
+            Ok(unsafe { RigidBodyTransform::identity() })
+        });
+        assert!(matches!(result, Err(CelestialError::EpochOutOfRange { .. })));
+        assert!(!cached.is_valid_for(epoch));
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        assert!(cached
+            .get_or_compute_fallible(epoch, |_| Ok(unsafe { RigidBodyTransform::identity() }))
+            .is_ok());
+        assert!(cached.is_valid_for(epoch));
+    }
 }
@@ -6,6 +6,9 @@ use chrono::{DateTime, Utc};
 use nalgebra::Vector3;
 use std::sync::OnceLock;
 
+use crate::eop::EopProvider;
+use crate::time_scales::{gast, utc_to_tt};
+
 /// J2000.0 epoch: 2000-01-01 12:00:00 TT (Julian Date 2451545.0).
 pub const J2000_JD: f64 = 2451545.0;
 
@@ -33,6 +36,9 @@ pub const EARTH_RADIUS_POLAR: f64 = 6_356_752.314_245;
 /// Moon mean radius in meters (IAU/IAG).
 pub const MOON_RADIUS_MEAN: f64 = 1_737_400.0;
 
+/// Nominal solar radius in meters (IAU 2015 Resolution B3).
+pub const SUN_RADIUS: f64 = 695_700_000.0;
+
 /// Speed of light in vacuum (m/s, exact by definition).
 pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
 
@@ -45,6 +51,9 @@ pub const MU_EARTH: f64 = 3.986_004_418e14;
 /// Gravitational parameter of the Moon (m³/s²).
 pub const MU_MOON: f64 = 4.902_800_066e12;
 
+/// Earth's second zonal harmonic (unnormalized, EGM96).
+pub const J2_EARTH: f64 = 1.082_626_68e-3;
+
 /// IAU 2009 lunar orientation constants.
 pub mod lunar {
     /// Right ascension of lunar north pole (degrees).
@@ -55,24 +64,128 @@ pub mod lunar {
 
     /// Prime meridian angle (degrees).
     pub const W_DEG: f64 = 38.3213;
+
+    /// Rate of the pole right ascension (degrees per Julian century).
+    pub const RA_RATE_DEG_PER_CENTURY: f64 = 0.0031;
+
+    /// Rate of the pole declination (degrees per Julian century).
+    pub const DEC_RATE_DEG_PER_CENTURY: f64 = 0.0130;
+}
+
+/// IAU 1958 Galactic coordinate system orientation, referred to ICRS (J2000).
+pub mod galactic {
+    /// Right ascension of the north Galactic pole (degrees).
+    pub const POLE_RA_DEG: f64 = 192.859_48;
+
+    /// Declination of the north Galactic pole (degrees).
+    pub const POLE_DEC_DEG: f64 = 27.128_25;
+
+    /// Galactic longitude of the north celestial pole (degrees).
+    pub const NCP_LONGITUDE_DEG: f64 = 122.931_92;
+}
+
+/// IAU 2006 frame bias between ICRS and the mean J2000 (EME2000) frame.
+pub mod frame_bias {
+    /// ICRS right ascension origin offset dα₀ (milliarcseconds).
+    pub const D_ALPHA0_MAS: f64 = -14.6;
+
+    /// Celestial pole offset ξ₀ (milliarcseconds).
+    pub const XI0_MAS: f64 = -16.617;
+
+    /// Celestial pole offset η₀ (milliarcseconds).
+    pub const ETA0_MAS: f64 = -6.8192;
 }
 
 /// Cached MCI → ICRS rotation quaternion (IAU 2009 lunar orientation).
 static MCI_TO_ICRS_ROTATION: OnceLock<UnitQuaternion> = OnceLock::new();
 
+/// Rotation sequence aligning Moon's principal axes with ICRS (angles in degrees).
+fn lunar_orientation(ra_deg: f64, dec_deg: f64, w_deg: f64) -> UnitQuaternion {
+    UnitQuaternion::from_axis_angle(&Vector3::z_axis(), ra_deg.to_radians())
+        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), dec_deg.to_radians())
+        * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), w_deg.to_radians())
+}
+
 /// Get or compute the MCI → ICRS rotation.
 ///
 /// Uses IAU 2009 lunar orientation constants. The rotation is cached after first computation.
 pub fn mci_to_icrs_rotation() -> &'static UnitQuaternion {
-    MCI_TO_ICRS_ROTATION.get_or_init(|| {
-        let ra = lunar::RA_DEG.to_radians();
-        let dec = lunar::DEC_DEG.to_radians();
-        let w = lunar::W_DEG.to_radians();
-
-        // Rotation sequence aligning Moon's principal axes with ICRS
-        UnitQuaternion::from_axis_angle(&Vector3::z_axis(), ra)
-            * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), dec)
-            * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), w)
+    MCI_TO_ICRS_ROTATION.get_or_init(|| lunar_orientation(lunar::RA_DEG, lunar::DEC_DEG, lunar::W_DEG))
+}
+
+/// MCI → ICRS rotation with the lunar pole precessed to the given epoch.
+///
+/// Applies the IAU 2009 linear pole rates (`RA_RATE_DEG_PER_CENTURY`,
+/// `DEC_RATE_DEG_PER_CENTURY`) so the mean pole tracks its slow drift. The
+/// prime meridian is held at `W_DEG`, since MCI is inertial and does not
+/// rotate with the Moon. Libration is not included.
+#[must_use]
+pub fn mci_to_icrs_rotation_at(jd: f64) -> UnitQuaternion {
+    let t = (jd - J2000_JD) / DAYS_PER_CENTURY;
+    lunar_orientation(
+        lunar::RA_DEG + lunar::RA_RATE_DEG_PER_CENTURY * t,
+        lunar::DEC_DEG + lunar::DEC_RATE_DEG_PER_CENTURY * t,
+        lunar::W_DEG,
+    )
+}
+
+/// Cached ICRS → EME2000 frame bias rotation.
+static FRAME_BIAS_ROTATION: OnceLock<UnitQuaternion> = OnceLock::new();
+
+/// Get or compute the frame bias rotation `B = R1(-η₀)·R2(ξ₀)·R3(dα₀)`.
+///
+/// `B` maps ICRS vectors to the mean equator and equinox of J2000 (EME2000).
+/// The total rotation is about 23 milliarcseconds.
+///
+/// # Reference
+///
+/// IERS Conventions 2010, Chapter 5
+pub fn frame_bias_rotation() -> &'static UnitQuaternion {
+    FRAME_BIAS_ROTATION.get_or_init(|| {
+        let mas = ARCSEC_TO_RAD / 1000.0;
+        UnitQuaternion::from_axis_angle(&Vector3::x_axis(), frame_bias::ETA0_MAS * mas)
+            * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), -frame_bias::XI0_MAS * mas)
+            * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -frame_bias::D_ALPHA0_MAS * mas)
+    })
+}
+
+/// Cached J2000 mean equator → ecliptic rotation quaternion.
+static J2000_ECLIPTIC_ROTATION: OnceLock<UnitQuaternion> = OnceLock::new();
+
+/// Get or compute the J2000 mean equator → mean ecliptic rotation.
+///
+/// A rotation about the X axis (the J2000 equinox) by the IAU 2006 mean
+/// obliquity at J2000.0, ε₀ = 84381.406″ ≈ 23.4393°. The rotation is cached
+/// after first computation.
+pub fn j2000_ecliptic_rotation() -> &'static UnitQuaternion {
+    J2000_ECLIPTIC_ROTATION
+        .get_or_init(|| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), mean_obliquity(J2000_JD)))
+}
+
+/// Cached ICRS → Galactic rotation quaternion.
+static ICRS_TO_GALACTIC_ROTATION: OnceLock<UnitQuaternion> = OnceLock::new();
+
+/// Get or compute the ICRS → Galactic rotation.
+///
+/// The Galactic Z axis is the north Galactic pole and the X axis the
+/// Galactic center, placed so that the north celestial pole sits at
+/// longitude [`galactic::NCP_LONGITUDE_DEG`]. The quaternion's matrix has the
+/// Galactic axes (in ICRS) as columns. The rotation is cached after first
+/// computation.
+pub fn icrs_to_galactic_rotation() -> &'static UnitQuaternion {
+    ICRS_TO_GALACTIC_ROTATION.get_or_init(|| {
+        let (sin_dec, cos_dec) = galactic::POLE_DEC_DEG.to_radians().sin_cos();
+        let (sin_ra, cos_ra) = galactic::POLE_RA_DEG.to_radians().sin_cos();
+        let z = Vector3::new(cos_dec * cos_ra, cos_dec * sin_ra, sin_dec);
+
+        // Direction of the celestial pole within the Galactic plane, at
+        // longitude l_NCP; step back by l_NCP to reach the Galactic center.
+        let ncp = (Vector3::z() - z * z.z).normalize();
+        let (sin_l, cos_l) = galactic::NCP_LONGITUDE_DEG.to_radians().sin_cos();
+        let x = ncp * cos_l - z.cross(&ncp) * sin_l;
+        let y = z.cross(&x);
+
+        UnitQuaternion::from_rotation_matrix(&nalgebra::Rotation3::from_basis_unchecked(&[x, y, z]))
     })
 }
 
@@ -85,6 +198,46 @@ pub fn utc_to_julian_date(time: DateTime<Utc>) -> f64 {
     time.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5
 }
 
+/// Convert UTC DateTime to a two-part Julian Date `(jd1, jd2)`.
+///
+/// `jd1` is the whole Julian day number and `jd2` the fraction of the day in
+/// `[0, 1)`, following the SOFA two-part convention. A single `f64` Julian
+/// Date only resolves ~40 µs for modern epochs; the split form keeps the
+/// sub-second part of `time` (nanoseconds included) to ~1e-11 s. As with
+/// [`utc_to_julian_date`], UTC is treated as UT1.
+#[must_use]
+pub fn utc_to_julian_date_split(time: DateTime<Utc>) -> (f64, f64) {
+    let seconds = time.timestamp();
+    let day = seconds.div_euclid(86_400);
+    let seconds_of_day = seconds.rem_euclid(86_400) as f64 + f64::from(time.timestamp_subsec_nanos()) * 1e-9;
+
+    // Julian days start at noon, Unix days at midnight
+    let fraction = seconds_of_day / SECONDS_PER_DAY + 0.5;
+    if fraction >= 1.0 {
+        ((2_440_588 + day) as f64, fraction - 1.0)
+    } else {
+        ((2_440_587 + day) as f64, fraction)
+    }
+}
+
+/// Julian centuries of TT since J2000.0 for a two-part Julian Date.
+fn julian_centuries(jd1: f64, jd2: f64) -> f64 {
+    ((jd1 - J2000_JD) + jd2) / DAYS_PER_CENTURY
+}
+
+/// Mean obliquity of the ecliptic (radians) at a given Julian Date (TT).
+///
+/// # Reference
+///
+/// IAU 2006 precession (Capitaine et al. 2003), IERS Conventions 2010, Chapter 5
+#[must_use]
+pub fn mean_obliquity(jd: f64) -> f64 {
+    let t = (jd - J2000_JD) / DAYS_PER_CENTURY;
+    (84381.406
+        + t * (-46.836_769 + t * (-0.000_183_1 + t * (0.002_003_40 + t * (-0.000_000_576 - t * 0.000_000_043_4)))))
+        * ARCSEC_TO_RAD
+}
+
 /// Compute Earth Rotation Angle (ERA) for a given Julian Date.
 ///
 /// ERA is the angle of rotation of the Earth about the Celestial Intermediate Pole (CIP)
@@ -98,80 +251,296 @@ pub fn earth_rotation_angle(jd: f64) -> f64 {
     2.0 * std::f64::consts::PI * (0.7790572732640 + 1.002_737_811_911_354_6 * d).fract()
 }
 
+/// Earth Rotation Angle for a two-part Julian Date (UT1), in `[0, 2π)`.
+///
+/// Splits off the whole days before multiplying by the rotation rate, so
+/// `jd2` keeps its full precision; see [`utc_to_julian_date_split`].
+#[must_use]
+pub fn earth_rotation_angle_split(jd1: f64, jd2: f64) -> f64 {
+    let d = (jd1 - J2000_JD) + jd2;
+    let turns = jd1.fract() + jd2.fract() + 0.7790572732640 + 0.002_737_811_911_354_6 * d;
+    2.0 * std::f64::consts::PI * turns.rem_euclid(1.0)
+}
+
 /// Compute ICRS → ECEF rotation at a given time.
 ///
-/// Uses IAU 2006/2000A precession model and Earth Rotation Angle (ERA).
-/// This provides accuracy < 30 milliarcseconds for epochs 2020-2050.
+/// Uses the CIO-based chain of [`icrs_to_ecef_rotation_with_nutation`]
+/// without nutation.
 ///
 /// # Limitations
 ///
-/// - Nutation is not modeled (precession-only)
+/// - Nutation is not modeled, so the celestial pole is off by up to ~20
+///   arcseconds (the 18.6-year nutation amplitude)
 /// - No polar motion corrections
 /// - UTC treated as UT1 (UT1-UTC correction ignored)
+///
+/// See [`icrs_to_ecef_rotation_with_eop`] to supply Earth Orientation Parameters.
 pub fn icrs_to_ecef_rotation(time: DateTime<Utc>) -> UnitQuaternion {
     icrs_to_ecef_rotation_with_nutation(time, false)
 }
 
 /// Compute ICRS → ECEF rotation at a given time with optional nutation.
 ///
+/// Follows the CIO-based paradigm: the CIP coordinates X, Y are taken from
+/// the frame bias, IAU 2006 precession and (optionally) nutation matrix, the
+/// CIO locator `s` from the leading terms of its series, and the Earth is
+/// rotated by the Earth Rotation Angle.
+///
 /// # Parameters
 ///
 /// - `time`: The UTC time for the transformation
-/// - `include_nutation`: If true, includes IAU 2000B nutation model
+/// - `include_nutation`: If true, includes the truncated nutation series of [`nutation_matrix`]
 ///
 /// # Accuracy
 ///
-/// - Without nutation: < 30 milliarcseconds (2020-2050)
-/// - With nutation: < 1 milliarcsecond (2020-2050)
+/// - Without nutation: up to ~20 arcseconds, the nutation amplitude
+/// - With nutation: ~0.1-0.2 arcsecond, limited by the nutation series truncation
 pub fn icrs_to_ecef_rotation_with_nutation(
     time: DateTime<Utc>,
     include_nutation: bool,
 ) -> UnitQuaternion {
-    let jd = utc_to_julian_date(time);
-    let t_centuries = (jd - J2000_JD) / DAYS_PER_CENTURY;
+    icrs_to_ecef_rotation_with_eop(time, include_nutation, None)
+}
 
-    // IAU 2006/2000A precession angles (arcsec → radians)
-    let zeta =
-        (2306.2181 * t_centuries + 1.39656 * t_centuries.powi(2) + 0.000139 * t_centuries.powi(3))
-            * ARCSEC_TO_RAD;
+/// [`icrs_to_ecef_rotation_with_nutation`] with optional Earth Orientation
+/// Parameters.
+///
+/// With a provider, the Earth Rotation Angle is evaluated at UT1 = UTC +
+/// DUT1 and [`polar_motion_matrix`] replaces [`polar_motion_correction`].
+/// `None` behaves exactly like [`icrs_to_ecef_rotation_with_nutation`].
+pub fn icrs_to_ecef_rotation_with_eop(
+    time: DateTime<Utc>,
+    include_nutation: bool,
+    eop: Option<&dyn EopProvider>,
+) -> UnitQuaternion {
+    match eop {
+        Some(eop) => {
+            let (xp, yp) = eop.polar_motion(time);
+            let polar_motion = polar_motion_matrix(xp, yp, utc_to_tt(time));
+            cio_chain(time, include_nutation, eop.dut1(time), polar_motion, PrecessionNutationModel::Iau2006)
+        }
+        None => cio_chain(time, include_nutation, 0.0, *polar_motion_correction(), PrecessionNutationModel::Iau2006),
+    }
+}
 
-    let theta =
-        (2004.3109 * t_centuries - 0.42665 * t_centuries.powi(2) - 0.041833 * t_centuries.powi(3))
-            * ARCSEC_TO_RAD;
+/// [`icrs_to_ecef_rotation_with_nutation`] with polar motion from given pole
+/// coordinates, e.g. read off IERS Bulletin A.
+///
+/// UTC is still treated as UT1; use [`icrs_to_ecef_rotation_with_eop`] to
+/// supply DUT1 as well.
+///
+/// # Parameters
+///
+/// - `time`: The UTC time for the transformation
+/// - `include_nutation`: If true, includes the truncated nutation series of [`nutation_matrix`]
+/// - `xp`, `yp`: Pole coordinates in radians
+pub fn icrs_to_ecef_rotation_with_polar_motion(
+    time: DateTime<Utc>,
+    include_nutation: bool,
+    xp: f64,
+    yp: f64,
+) -> UnitQuaternion {
+    let polar_motion = polar_motion_matrix(xp, yp, utc_to_tt(time));
+    cio_chain(time, include_nutation, 0.0, polar_motion, PrecessionNutationModel::Iau2006)
+}
 
-    let z =
-        (2306.2181 * t_centuries + 1.09468 * t_centuries.powi(2) + 0.018203 * t_centuries.powi(3))
-            * ARCSEC_TO_RAD;
+/// [`icrs_to_ecef_rotation_with_nutation`] with a selectable precession
+/// model, e.g. [`PrecessionNutationModel::Iau1976`] to reproduce legacy
+/// FK5-era reductions. The default model gives exactly
+/// [`icrs_to_ecef_rotation_with_nutation`].
+pub fn icrs_to_ecef_rotation_with_model(
+    time: DateTime<Utc>,
+    include_nutation: bool,
+    model: PrecessionNutationModel,
+) -> UnitQuaternion {
+    cio_chain(time, include_nutation, 0.0, *polar_motion_correction(), model)
+}
 
-    // Precession rotation: Z(-ζ) * Y(θ) * Z(-z)
-    let precession = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -zeta)
-        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), theta)
-        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -z);
+/// Polar motion `W = R3(-s')·R2(xp)·R1(yp)`, taking ITRS (ECEF) vectors to
+/// the Terrestrial Intermediate Reference System.
+///
+/// `xp`, `yp` are the pole coordinates in radians and `jd_tt` fixes the TIO
+/// locator `s' = -47 µas·t`. Like the other rotations here, the quaternion
+/// is the active form, so each `R_k(θ)` becomes a rotation by `-θ`.
+#[must_use]
+pub fn polar_motion_matrix(xp: f64, yp: f64, jd_tt: f64) -> UnitQuaternion {
+    let t = (jd_tt - J2000_JD) / DAYS_PER_CENTURY;
+    let s_prime = -47.0 * ARCSEC_TO_RAD * 1e-6 * t;
+
+    UnitQuaternion::from_axis_angle(&Vector3::z_axis(), s_prime)
+        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), -xp)
+        * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), -yp)
+}
 
-    // Optional nutation correction
-    let nutation_rot = if include_nutation {
-        nutation_matrix(jd)
-    } else {
-        UnitQuaternion::identity()
-    };
+/// CIO-based ICRS → ECEF chain with the Earth Rotation Angle at UTC + `dut1`
+/// seconds, the given ITRS → TIRS polar motion rotation and precession model.
+fn cio_chain(
+    time: DateTime<Utc>,
+    include_nutation: bool,
+    dut1: f64,
+    polar_motion: UnitQuaternion,
+    model: PrecessionNutationModel,
+) -> UnitQuaternion {
+    let jd = utc_to_julian_date(time) + dut1 / SECONDS_PER_DAY;
+    let jd_tt = utc_to_tt(time);
+    let t = (jd_tt - J2000_JD) / DAYS_PER_CENTURY;
+
+    // CIP unit vector in the GCRS
+    let cip = bias_precession_nutation(jd_tt, include_nutation, model).transform_vector(&Vector3::z());
+    let e = cip.y.atan2(cip.x);
+    let d = cip.xy().norm().atan2(cip.z);
+
+    // CIO locator s = -XY/2 + leading polynomial and nutation terms (µas)
+    let omega = (125.044_555_01 - (6_962_890.543_1 * t) / 3600.0).to_radians();
+    let s_plus_xy_2 = (94.0 + t * (3_808.65 + t * (-122.68 - t * 72_574.11))
+        - 2_640.73 * omega.sin()
+        - 63.53 * (2.0 * omega).sin())
+        * ARCSEC_TO_RAD
+        * 1e-6;
+    let s = s_plus_xy_2 - cip.x * cip.y / 2.0;
+
+    // Q = R3(-E)·R2(-d)·R3(E)·R3(s) maps the CIRS to the GCRS
+    let cirs_to_gcrs = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), e)
+        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), d)
+        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -e - s);
+
+    cirs_to_gcrs
+        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), earth_rotation_angle(jd))
+        * polar_motion
+}
 
-    // Earth Rotation Angle (ERA)
-    let era = earth_rotation_angle(jd);
+/// Compute ICRS → ECEF rotation at a given time using the classical
+/// equinox-based method.
+///
+/// Passes through the intermediate frames
+///
+/// 1. EME2000 (mean equator and equinox of J2000) via the frame bias,
+/// 2. MOD (mean equator and equinox of date) via the precession of `model`,
+/// 3. TOD (true equator and equinox of date) via [`nutation_matrix`],
+/// 4. PEF (pseudo Earth-fixed) via Greenwich apparent sidereal time
+///    ([`gast`](crate::time_scales::gast)),
+///
+/// and finally polar motion ([`polar_motion_correction`]) to ECEF. This is
+/// the route used by most GMST-based software, and agrees with the CIO-based
+/// [`icrs_to_ecef_rotation_with_nutation`] (with nutation) to within a few
+/// milliarcseconds for 2020-2050 with [`PrecessionNutationModel::Iau2006`].
+/// It carries the same ~0.1-0.2 arcsecond nutation truncation error.
+pub fn icrs_to_ecef_rotation_equinox(time: DateTime<Utc>, model: PrecessionNutationModel) -> UnitQuaternion {
+    let jd_tt = utc_to_tt(time);
+    let gast = gast(utc_to_julian_date(time), jd_tt);
+
+    bias_precession_nutation(jd_tt, true, model)
+        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), gast)
+        * polar_motion_correction()
+}
+
+/// Precession-nutation model family.
+///
+/// Selects the precession of the ICRS → ECEF chain in
+/// [`icrs_to_ecef_rotation_with_model`] and [`icrs_to_ecef_rotation_equinox`]
+/// (and [`transforms::icrs_to_ecef_at_with_model`](crate::transforms::icrs_to_ecef_at_with_model)).
+/// The other transforms always use the default, [`Self::Iau2006`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrecessionNutationModel {
+    /// IAU 1976 precession (Lieske et al. 1977) without frame bias, for
+    /// reproducing legacy FK5-era reductions. Nutation and Earth rotation
+    /// are unchanged.
+    Iau1976,
+    /// IAU 2006 precession (Capitaine et al. 2003) with the frame bias.
+    #[default]
+    Iau2006,
+}
 
-    // Combined rotation: ERA * Nutation * Precession
-    UnitQuaternion::from_axis_angle(&Vector3::z_axis(), era) * nutation_rot * precession
+impl PrecessionNutationModel {
+    /// Precession rotation for this model, `t_centuries` Julian centuries of
+    /// TT after J2000.0. See [`precession_1976`] and [`precession_2006`].
+    #[must_use]
+    pub fn precession(self, t_centuries: f64) -> UnitQuaternion {
+        match self {
+            Self::Iau1976 => precession_1976(t_centuries),
+            Self::Iau2006 => precession_2006(t_centuries),
+        }
+    }
 }
 
-/// Compute IAU 2000B nutation matrix.
+/// IAU 1976 precession from the mean equator and equinox of J2000 to that of
+/// date, as a rotation taking mean-of-date vectors back to J2000.
 ///
-/// This is a simplified nutation model with 77 terms, providing
-/// milliarcsecond-level accuracy for most applications.
+/// Drifts from [`precession_2006`] by about 0.3 arcsecond per century.
 ///
 /// # Reference
 ///
-/// IERS Conventions 2003, Chapter 5
-pub fn nutation_matrix(jd: f64) -> UnitQuaternion {
-    let t = (jd - J2000_JD) / DAYS_PER_CENTURY;
+/// Lieske et al. (1977), Astronomy & Astrophysics 58, 1-16
+#[must_use]
+pub fn precession_1976(t_centuries: f64) -> UnitQuaternion {
+    let t = t_centuries;
+
+    let zeta = t * (2_306.218_1 + t * (0.301_88 + t * 0.017_998)) * ARCSEC_TO_RAD;
+    let theta = t * (2_004.310_9 + t * (-0.426_65 - t * 0.041_833)) * ARCSEC_TO_RAD;
+    let z = t * (2_306.218_1 + t * (1.094_68 + t * 0.018_203)) * ARCSEC_TO_RAD;
+
+    UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -zeta)
+        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), theta)
+        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -z)
+}
+
+/// IAU 2006 precession from the mean equator and equinox of J2000 to that of
+/// date, as a rotation taking mean-of-date vectors back to J2000.
+///
+/// # Reference
+///
+/// Capitaine et al. (2003), IERS Conventions 2010, Chapter 5
+#[must_use]
+pub fn precession_2006(t_centuries: f64) -> UnitQuaternion {
+    let t = t_centuries;
+
+    let zeta = (2.650_545
+        + t * (2_306.083_227 + t * (0.298_849_9 + t * (0.018_018_28 + t * (-0.000_005_971 - t * 0.000_000_317_3)))))
+        * ARCSEC_TO_RAD;
+    let theta = (t * (2_004.191_903 + t * (-0.429_493_4 + t * (-0.041_822_64 + t * (-0.000_007_089 - t * 0.000_000_127_4)))))
+        * ARCSEC_TO_RAD;
+    let z = (-2.650_545
+        + t * (2_306.077_181 + t * (1.092_734_8 + t * (0.018_268_37 + t * (-0.000_028_596 - t * 0.000_000_290_4)))))
+        * ARCSEC_TO_RAD;
+
+    UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -zeta)
+        * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), theta)
+        * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -z)
+}
+
+/// Rotation taking true-of-date (or, without nutation, mean-of-date) vectors
+/// to the ICRS: the transpose of the bias-precession-nutation matrix N·P·B.
+///
+/// The IAU 1976 chain has no frame bias, as in the FK5-era reductions it
+/// reproduces.
+pub(crate) fn bias_precession_nutation(
+    jd_tt: f64,
+    include_nutation: bool,
+    model: PrecessionNutationModel,
+) -> UnitQuaternion {
+    let nutation = if include_nutation {
+        nutation_matrix(jd_tt)
+    } else {
+        UnitQuaternion::identity()
+    };
+
+    let t = (jd_tt - J2000_JD) / DAYS_PER_CENTURY;
+    match model {
+        PrecessionNutationModel::Iau1976 => precession_1976(t) * nutation,
+        PrecessionNutationModel::Iau2006 => frame_bias_rotation().inverse() * precession_2006(t) * nutation,
+    }
+}
+
+/// Nutation in longitude and obliquity `(Δψ, Δε)` in radians, from the
+/// truncated series used by [`nutation_matrix`].
+pub(crate) fn nutation_angles(jd: f64) -> (f64, f64) {
+    nutation_angles_split(jd, 0.0)
+}
+
+/// [`nutation_angles`] for a two-part Julian Date.
+fn nutation_angles_split(jd1: f64, jd2: f64) -> (f64, f64) {
+    let t = julian_centuries(jd1, jd2);
 
     // Mean anomaly of the Moon (radians)
     let l = (134.96340251 + (1717915923.2178 * t + 31.8792 * t * t) / 3600.0).to_radians();
@@ -188,8 +557,7 @@ pub fn nutation_matrix(jd: f64) -> UnitQuaternion {
     // Longitude of the ascending node of the Moon's mean orbit
     let omega = (125.04455501 - (6962890.5431 * t + 7.4722 * t * t) / 3600.0).to_radians();
 
-    // Simplified IAU 2000B nutation series (5 largest terms)
-    // Full model has 77 terms; this is adequate for ~0.1 milliarcsecond accuracy
+    // Truncated IAU 2000B nutation series (5 largest terms of 77)
 
     // Nutation in longitude (arcsec)
     let dpsi = (-17.2064161 * omega.sin()
@@ -206,14 +574,44 @@ pub fn nutation_matrix(jd: f64) -> UnitQuaternion {
         - 0.0897492 * (2.0 * f + 2.0 * omega).cos())
         * ARCSEC_TO_RAD;
 
+    (dpsi, deps)
+}
+
+/// Compute a low-precision nutation matrix.
+///
+/// Evaluates only the five largest luni-solar terms of the IAU 2000B series
+/// (which itself has 77). The omitted terms reach ~0.15 arcsecond in
+/// longitude, so this captures the 18.6-year and semi-annual nutation but is
+/// not suitable for milliarcsecond work. The full IAU 2000A series (1365
+/// terms) is not implemented; precision orbit determination should use an
+/// external SOFA/ERFA binding.
+///
+/// The rotation takes true-of-date vectors to mean-of-date, the same
+/// direction as the precession rotations; it is the transpose of the IAU
+/// matrix `N = R1(-ε₀ - Δε)·R3(-Δψ)·R1(ε₀)`.
+///
+/// # Reference
+///
+/// IERS Conventions 2003, Chapter 5
+pub fn nutation_matrix(jd: f64) -> UnitQuaternion {
+    nutation_matrix_split(jd, 0.0)
+}
+
+/// [`nutation_matrix`] for a two-part Julian Date (TT), see
+/// [`utc_to_julian_date_split`].
+pub fn nutation_matrix_split(jd1: f64, jd2: f64) -> UnitQuaternion {
+    let t = julian_centuries(jd1, jd2);
+    let (dpsi, deps) = nutation_angles_split(jd1, jd2);
+
     // Mean obliquity of the ecliptic at J2000
     let eps0 = (84381.448 * ARCSEC_TO_RAD)
         + (-46.8150 * t - 0.00059 * t * t + 0.001813 * t * t * t) * ARCSEC_TO_RAD;
 
-    // Nutation rotation: R_x(-ε₀ - Δε) * R_z(-Δψ) * R_x(ε₀)
-    UnitQuaternion::from_axis_angle(&Vector3::x_axis(), -(eps0 + deps))
+    // Transpose of N = R1(-ε₀ - Δε)·R3(-Δψ)·R1(ε₀), taking true-of-date
+    // vectors to mean-of-date like the precession rotations
+    UnitQuaternion::from_axis_angle(&Vector3::x_axis(), eps0)
         * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -dpsi)
-        * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), eps0)
+        * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), -(eps0 + deps))
 }
 
 /// Cached polar motion correction (placeholder).
@@ -225,30 +623,45 @@ static POLAR_MOTION_CORRECTION: OnceLock<UnitQuaternion> = OnceLock::new();
 ///
 /// # Note
 ///
-/// Currently returns identity (no correction). For sub-arcsecond accuracy,
-/// this should query IERS Bulletin A for polar motion parameters xp and yp.
+/// Returns identity (no correction); this is the default used when no pole
+/// coordinates are known. For sub-arcsecond accuracy, build the rotation from
+/// IERS Bulletin A values with [`polar_motion_matrix`], or use
+/// [`icrs_to_ecef_rotation_with_polar_motion`] /
+/// [`icrs_to_ecef_rotation_with_eop`].
 pub fn polar_motion_correction() -> &'static UnitQuaternion {
     POLAR_MOTION_CORRECTION.get_or_init(UnitQuaternion::identity)
 }
 
 /// Compute precession between two arbitrary epochs.
 ///
-/// Returns the rotation matrix from epoch1 to epoch2 using IAU 2006 precession.
+/// Returns the rotation taking mean-of-date vectors at `epoch2_jd` to the mean
+/// equator and equinox of `epoch1_jd`, from the IAU 1976 precession angles
+/// ζ, z, θ (Lieske et al. 1977). These are not the IAU 2006 (P03) angles used
+/// by the CIO chain: the two drift apart by a few tens of milliarcseconds per
+/// century from J2000, which is well below the accuracy of the low-precision
+/// Sun and Moon positions built on this.
 pub fn precession_between_epochs(epoch1_jd: f64, epoch2_jd: f64) -> UnitQuaternion {
-    let t1 = (epoch1_jd - J2000_JD) / DAYS_PER_CENTURY;
-    let t2 = (epoch2_jd - J2000_JD) / DAYS_PER_CENTURY;
-    let dt = t2 - t1;
+    precession_between_epochs_split((epoch1_jd, 0.0), (epoch2_jd, 0.0))
+}
 
-    // Precession angles relative to epoch1
-    let zeta = (2306.2181 * dt + (1.39656 + 0.000139 * t1) * dt * dt + 0.000139 * dt * dt * dt)
-        * ARCSEC_TO_RAD;
+/// [`precession_between_epochs`] with both epochs as two-part Julian Dates
+/// (TT), see [`utc_to_julian_date_split`].
+pub fn precession_between_epochs_split(epoch1: (f64, f64), epoch2: (f64, f64)) -> UnitQuaternion {
+    // T: centuries from J2000 to epoch1; t: centuries from epoch1 to epoch2
+    let t1 = julian_centuries(epoch1.0, epoch1.1);
+    let dt = ((epoch2.0 - epoch1.0) + (epoch2.1 - epoch1.1)) / DAYS_PER_CENTURY;
 
-    let theta = (2004.3109 * dt - (0.42665 + 0.041833 * t1) * dt * dt - 0.041833 * dt * dt * dt)
-        * ARCSEC_TO_RAD;
+    // Lieske et al. (1977) precession angles relative to epoch1
+    let rate = 2306.2181 + 1.39656 * t1 - 0.000139 * t1 * t1;
+    let zeta = (rate * dt + (0.30188 - 0.000344 * t1) * dt * dt + 0.017998 * dt * dt * dt) * ARCSEC_TO_RAD;
 
-    let z = (2306.2181 * dt + (1.09468 + 0.018203 * t1) * dt * dt + 0.018203 * dt * dt * dt)
+    let theta = ((2004.3109 - 0.85330 * t1 - 0.000217 * t1 * t1) * dt
+        - (0.42665 + 0.000217 * t1) * dt * dt
+        - 0.041833 * dt * dt * dt)
         * ARCSEC_TO_RAD;
 
+    let z = (rate * dt + (1.09468 + 0.000066 * t1) * dt * dt + 0.018203 * dt * dt * dt) * ARCSEC_TO_RAD;
+
     // Precession rotation
     UnitQuaternion::from_axis_angle(&Vector3::z_axis(), -zeta)
         * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), theta)
@@ -260,6 +673,52 @@ mod tests {
     use super::*;
     use chrono::TimeZone;
 
+    #[test]
+    fn eop_shifts_pole_and_rotation_angle() {
+        use crate::eop::{EopRecord, NullEop, TableEop};
+
+        let time = Utc.with_ymd_and_hms(2024, 3, 1, 6, 0, 0).unwrap();
+        let base = icrs_to_ecef_rotation_with_nutation(time, true);
+        let null = icrs_to_ecef_rotation_with_eop(time, true, Some(&NullEop));
+        // Only the TIO locator s' (tens of µas) remains
+        assert!(base.angle_to(&null) < 1e-9);
+
+        // Bulletin A-sized values: xp = 0.1", yp = 0.3", DUT1 = -0.2 s
+        let (xp, yp) = (0.1 * ARCSEC_TO_RAD, 0.3 * ARCSEC_TO_RAD);
+        let table = TableEop::new(vec![EopRecord { epoch: time, xp, yp, dut1: -0.2 }]).unwrap();
+        let with_eop = icrs_to_ecef_rotation_with_eop(time, true, Some(&table));
+
+        // The CIP sits at (xp, -yp) in ITRS
+        let cip = base.transform_vector(&Vector3::z());
+        let cip_itrs = with_eop.inverse_transform_vector(&cip);
+        assert!((cip_itrs.x - xp).abs() < 1e-11);
+        assert!((cip_itrs.y + yp).abs() < 1e-11);
+
+        // DUT1 turns the Earth back by ω·0.2 s about the pole
+        let pole_only = TableEop::new(vec![EopRecord { epoch: time, xp: 0.0, yp: 0.0, dut1: -0.2 }]).unwrap();
+        let turned = base.angle_to(&icrs_to_ecef_rotation_with_eop(time, true, Some(&pole_only)));
+        let expected = 0.2 * 1.002_737_811_911_354_6 * std::f64::consts::TAU / SECONDS_PER_DAY;
+        assert!((turned - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn polar_motion_matrix_moves_pole_and_matches_eop_path() {
+        use crate::eop::{EopRecord, TableEop};
+
+        assert!(polar_motion_matrix(0.0, 0.0, J2000_JD).angle() < 1e-15);
+
+        // The ITRS pole seen from the TIRS sits at (-xp, yp)
+        let (xp, yp) = (0.2 * ARCSEC_TO_RAD, 0.4 * ARCSEC_TO_RAD);
+        let pole = polar_motion_matrix(xp, yp, J2000_JD).transform_vector(&Vector3::z());
+        assert!((pole.x + xp).abs() < 1e-15 && (pole.y - yp).abs() < 1e-15);
+
+        let time = Utc.with_ymd_and_hms(2031, 8, 20, 18, 0, 0).unwrap();
+        let table = TableEop::new(vec![EopRecord { epoch: time, xp, yp, dut1: 0.0 }]).unwrap();
+        let via_eop = icrs_to_ecef_rotation_with_eop(time, true, Some(&table));
+        let direct = icrs_to_ecef_rotation_with_polar_motion(time, true, xp, yp);
+        assert!(via_eop.angle_to(&direct) < 1e-15);
+    }
+
     #[test]
     fn era_at_j2000_is_approximately_zero() {
         let era = earth_rotation_angle(J2000_JD);
@@ -290,6 +749,100 @@ mod tests {
         assert!(angle < 0.001);
     }
 
+    #[test]
+    fn nutation_tilts_pole_by_dpsi_sin_eps_and_deps() {
+        let jd = J2000_JD + 4_000.0;
+        let (dpsi, deps) = nutation_angles(jd);
+        let pole = nutation_matrix(jd).transform_vector(&Vector3::z());
+
+        let eps = mean_obliquity(jd);
+        assert!((pole.x - dpsi * eps.sin()).abs() < 1e-8);
+        assert!((pole.y - deps).abs() < 1e-8);
+    }
+
+    #[test]
+    fn split_julian_date_recombines_and_keeps_microseconds() {
+        let time = Utc.with_ymd_and_hms(2031, 8, 9, 17, 45, 12).unwrap();
+        let (jd1, jd2) = utc_to_julian_date_split(time);
+        assert_eq!(jd1.fract(), 0.0);
+        assert!((0.0..1.0).contains(&jd2));
+        assert!((jd1 + jd2 - utc_to_julian_date(time)).abs() < 1e-9);
+
+        // One microsecond is below the resolution of a single f64 JD
+        let later = time + chrono::Duration::microseconds(1);
+        let (later1, later2) = utc_to_julian_date_split(later);
+        let step = 1e-6 / SECONDS_PER_DAY;
+        assert_eq!(later1, jd1);
+        assert!(((later2 - jd2) - step).abs() < 1e-16);
+        assert!((((later1 + later2) - (jd1 + jd2)) - step).abs() > 1e-13);
+
+        let era = earth_rotation_angle(utc_to_julian_date(time));
+        assert!((earth_rotation_angle_split(jd1, jd2) - era).abs() < 1e-8);
+        let nut = nutation_matrix(jd1 + jd2);
+        assert!(nutation_matrix_split(jd1, jd2).angle_to(&nut) < 1e-12);
+    }
+
+    #[test]
+    fn precession_models_agree_near_j2000_and_diverge_later() {
+        let arcsec = |t: f64| {
+            let iau1976 = PrecessionNutationModel::Iau1976.precession(t);
+            let iau2006 = PrecessionNutationModel::Iau2006.precession(t);
+            iau1976.angle_to(&iau2006) / ARCSEC_TO_RAD
+        };
+
+        assert!(arcsec(0.0) < 1e-9);
+        assert!(arcsec(0.1) < 0.1);
+        assert!(arcsec(-0.1) < 0.1);
+        assert!(arcsec(5.0) > 1.0);
+        assert!(arcsec(-5.0) > 1.0);
+    }
+
+    #[test]
+    fn equinox_and_cio_paths_agree() {
+        for year in [2020, 2035, 2050] {
+            let time = Utc.with_ymd_and_hms(year, 5, 17, 3, 20, 0).unwrap();
+            let cio = icrs_to_ecef_rotation_with_nutation(time, true);
+            let equinox = icrs_to_ecef_rotation_equinox(time, PrecessionNutationModel::Iau2006);
+
+            let mas = cio.angle_to(&equinox) / ARCSEC_TO_RAD * 1000.0;
+            assert!(mas < 5.0, "{year}: paths differ by {mas} mas");
+        }
+    }
+
+    #[test]
+    fn model_selects_the_precession_of_the_chain() {
+        let time = Utc.with_ymd_and_hms(2040, 9, 1, 0, 0, 0).unwrap();
+        let default = icrs_to_ecef_rotation_with_nutation(time, true);
+        let iau2006 = icrs_to_ecef_rotation_with_model(time, true, PrecessionNutationModel::Iau2006);
+        let iau1976 = icrs_to_ecef_rotation_with_model(time, true, PrecessionNutationModel::Iau1976);
+        assert!(default.angle_to(&iau2006) < 1e-15);
+
+        // Frame bias plus 0.4 century of precession drift: tens of mas
+        let mas = default.angle_to(&iau1976) / ARCSEC_TO_RAD * 1000.0;
+        assert!(mas > 10.0 && mas < 500.0, "{mas} mas");
+
+        let equinox_1976 = icrs_to_ecef_rotation_equinox(time, PrecessionNutationModel::Iau1976);
+        let equinox_2006 = icrs_to_ecef_rotation_equinox(time, PrecessionNutationModel::Iau2006);
+        assert!(equinox_1976.angle_to(&equinox_2006) > 10.0 * ARCSEC_TO_RAD / 1000.0);
+    }
+
+    #[test]
+    fn precession_reproduces_meeus_theta_persei() {
+        // Meeus, Astronomical Algorithms, example 21.b: θ Persei, J2000 to
+        // 2028 Nov 13.19 TD, with the proper motion already applied
+        let ra = 41.054_063_f64.to_radians();
+        let dec = 49.227_750_f64.to_radians();
+        let mean_j2000 = Vector3::new(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin());
+
+        let of_date = precession_between_epochs(J2000_JD, 2_462_088.69).inverse_transform_vector(&mean_j2000);
+        let ra_of_date = of_date.y.atan2(of_date.x).to_degrees();
+        let dec_of_date = of_date.z.asin().to_degrees();
+
+        // 2h46m11.331s, +49°20'54.54"
+        assert!((ra_of_date - 41.547_212_5).abs() * 3600.0 < 0.01, "ra = {ra_of_date}");
+        assert!((dec_of_date - 49.348_483_3).abs() * 3600.0 < 0.01, "dec = {dec_of_date}");
+    }
+
     #[test]
     fn precession_between_same_epoch_is_identity() {
         let prec = precession_between_epochs(J2000_JD, J2000_JD);
@@ -0,0 +1,151 @@
+//! Earth Orientation Parameters (EOP).
+//!
+//! Polar motion and UT1-UTC are measured, not modelled, so the crate does not
+//! bundle them. Without them the ICRS → ECEF rotation is off by up to ~0.3
+//! arcsec of polar motion and up to 0.9 s of Earth rotation (~400 m at the
+//! equator). An [`EopProvider`] supplies the values, typically from IERS
+//! Bulletin A, to [`icrs_to_ecef_rotation_with_eop`](crate::constants::icrs_to_ecef_rotation_with_eop).
+
+use chrono::{DateTime, Utc};
+
+use crate::errors::{CelestialError, CelestialResult};
+
+/// Source of Earth Orientation Parameters.
+pub trait EopProvider {
+    /// Pole coordinates `(xp, yp)` in radians at `utc`.
+    fn polar_motion(&self, utc: DateTime<Utc>) -> (f64, f64);
+
+    /// UT1-UTC in seconds at `utc`.
+    fn dut1(&self, utc: DateTime<Utc>) -> f64;
+}
+
+/// Provider returning zero polar motion and UT1 = UTC.
+///
+/// Matches the EOP-free transforms apart from the TIO locator `s'`, a few
+/// tens of microarcseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NullEop;
+
+impl EopProvider for NullEop {
+    fn polar_motion(&self, _utc: DateTime<Utc>) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+
+    fn dut1(&self, _utc: DateTime<Utc>) -> f64 {
+        0.0
+    }
+}
+
+/// One row of an EOP table, e.g. a daily IERS Bulletin A entry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EopRecord {
+    /// Epoch of the values (Bulletin A uses 0h UTC)
+    pub epoch: DateTime<Utc>,
+    /// Pole x coordinate (radians)
+    pub xp: f64,
+    /// Pole y coordinate (radians)
+    pub yp: f64,
+    /// UT1-UTC (seconds)
+    pub dut1: f64,
+}
+
+/// In-memory EOP table with linear interpolation between records.
+///
+/// Queries before the first or after the last record return that record's
+/// values. UT1-UTC jumps by one second at a leap second; give the records on
+/// either side of it so no interpolation interval spans the jump.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableEop {
+    /// Records sorted by epoch
+    records: Vec<EopRecord>,
+}
+
+impl TableEop {
+    /// Build a table from EOP records.
+    ///
+    /// Records are sorted by epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` if no records are given
+    /// or two records share an epoch.
+    pub fn new(mut records: Vec<EopRecord>) -> CelestialResult<Self> {
+        if records.is_empty() {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: "EOP table needs at least one record".to_string(),
+            });
+        }
+        records.sort_by_key(|r| r.epoch);
+        if let Some(pair) = records.windows(2).find(|pair| pair[0].epoch == pair[1].epoch) {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: format!("duplicate EOP epoch {}", pair[0].epoch),
+            });
+        }
+        Ok(Self { records })
+    }
+
+    /// The records, sorted by epoch.
+    #[must_use]
+    pub fn records(&self) -> &[EopRecord] {
+        &self.records
+    }
+
+    /// Interpolated `(xp, yp, dut1)` at `utc`.
+    fn interpolate(&self, utc: DateTime<Utc>) -> (f64, f64, f64) {
+        let values = |r: &EopRecord| (r.xp, r.yp, r.dut1);
+
+        let next = self.records.partition_point(|r| r.epoch <= utc);
+        if next == 0 {
+            return values(&self.records[0]);
+        }
+        if next == self.records.len() {
+            return values(&self.records[next - 1]);
+        }
+
+        let (a, b) = (&self.records[next - 1], &self.records[next]);
+        let f = crate::orbital::seconds_between(a.epoch, utc) / crate::orbital::seconds_between(a.epoch, b.epoch);
+        let lerp = |x: f64, y: f64| x + (y - x) * f;
+        (lerp(a.xp, b.xp), lerp(a.yp, b.yp), lerp(a.dut1, b.dut1))
+    }
+}
+
+impl EopProvider for TableEop {
+    fn polar_motion(&self, utc: DateTime<Utc>) -> (f64, f64) {
+        let (xp, yp, _) = self.interpolate(utc);
+        (xp, yp)
+    }
+
+    fn dut1(&self, utc: DateTime<Utc>) -> f64 {
+        self.interpolate(utc).2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn table_interpolates_and_clamps() {
+        let day1 = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let day2 = Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap();
+        let table = TableEop::new(vec![
+            EopRecord { epoch: day2, xp: 2e-6, yp: 4e-6, dut1: -0.2 },
+            EopRecord { epoch: day1, xp: 1e-6, yp: 2e-6, dut1: -0.1 },
+        ])
+        .unwrap();
+
+        let noon = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let (xp, yp) = table.polar_motion(noon);
+        assert!((xp - 1.5e-6).abs() < 1e-18 && (yp - 3e-6).abs() < 1e-18);
+        assert!((table.dut1(noon) + 0.15).abs() < 1e-12);
+
+        assert_eq!(table.dut1(day1 - chrono::Duration::days(5)), -0.1);
+        assert_eq!(table.dut1(day2 + chrono::Duration::days(5)), -0.2);
+
+        assert!(TableEop::new(vec![]).is_err());
+        let record = table.records()[0];
+        assert!(TableEop::new(vec![record, record]).is_err());
+    }
+}
@@ -33,6 +33,14 @@ pub enum CelestialError {
         /// Description of the precision issue
         reason: String,
     },
+
+    /// The requested model path or input combination is not implemented.
+    ///
+    /// Returned instead of a silently approximate result.
+    Unsupported {
+        /// Description of the unsupported feature
+        feature: String,
+    },
 }
 
 impl fmt::Display for CelestialError {
@@ -54,6 +62,9 @@ impl fmt::Display for CelestialError {
             Self::NumericalPrecisionError { reason } => {
                 write!(f, "Numerical precision error: {}", reason)
             }
+            Self::Unsupported { feature } => {
+                write!(f, "Unsupported: {} is not implemented", feature)
+            }
         }
     }
 }
@@ -62,3 +73,16 @@ impl std::error::Error for CelestialError {}
 
 /// Result type for celestial operations.
 pub type CelestialResult<T> = Result<T, CelestialError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_display_names_the_feature() {
+        let err = CelestialError::Unsupported {
+            feature: "hyperbolic propagation".to_string(),
+        };
+        assert_eq!(err.to_string(), "Unsupported: hyperbolic propagation is not implemented");
+    }
+}
@@ -14,7 +14,7 @@ use chrono::{DateTime, Utc};
 use sguaba::{math::RigidBodyTransform, systems::Ecef, Coordinate};
 use uom::si::f64::{Angle, Length};
 
-use crate::{Icrs, Mci};
+use crate::{EquatorialCoords, Icrs, Mci};
 
 /// Extension methods for ICRS coordinates.
 ///
@@ -37,14 +37,55 @@ pub trait IcrsCoordinateExt {
     /// - `ra`: Right ascension (any value, will be normalized to 0-2π)
     /// - `dec`: Declination (must be in range [-π/2, π/2])
     /// - `distance`: Radial distance from origin
+    #[deprecated(note = "use `from_equatorial`, whose named fields cannot be transposed")]
     fn from_ra_dec(ra: Angle, dec: Angle, distance: Length) -> Self;
 
+    /// Convert to named spherical celestial coordinates.
+    ///
+    /// Same values as [`to_spherical_celestial`](Self::to_spherical_celestial),
+    /// but with named fields so RA and Dec cannot be transposed.
+    fn to_spherical_celestial_named(&self) -> EquatorialCoords {
+        self.to_spherical_celestial().into()
+    }
+
+    /// Construct ICRS coordinate from named spherical celestial coordinates.
+    ///
+    /// Equivalent to [`from_ra_dec`](Self::from_ra_dec) with the struct's fields,
+    /// which it replaces.
+    fn from_equatorial(coords: EquatorialCoords) -> Self
+    where
+        Self: Sized,
+    {
+        #[allow(deprecated)]
+        Self::from_ra_dec(coords.ra, coords.dec, coords.distance)
+    }
+
     /// Build ICRS coordinate from cartesian components.
     ///
     /// # Parameters
     ///
     /// - `components`: Cartesian X, Y, Z components
     fn build(components: crate::frames::CelestialComponents) -> Self;
+
+    /// Convert to ecliptic coordinates at the given time.
+    ///
+    /// One-off equivalent of applying
+    /// [`transforms::icrs_to_ecliptic_at`](crate::transforms::icrs_to_ecliptic_at).
+    fn to_ecliptic(&self, time: DateTime<Utc>, origin: crate::EclipticOrigin) -> Coordinate<crate::Ecliptic>;
+
+    /// Great-circle angle between the directions to `self` and `other`.
+    ///
+    /// Uses `atan2(|a × b|, a · b)`, which keeps full precision for nearly
+    /// coincident and nearly antipodal directions where `acos` of the dot
+    /// product rounds to zero or π. Distances are ignored.
+    fn angular_separation(&self, other: &Coordinate<Icrs>) -> Angle;
+
+    /// Position angle of `other` as seen from `self`, in `[0, 2π)`.
+    ///
+    /// Measured from north (towards the ICRS pole) through east, the usual
+    /// convention for double stars and offsets on the sky. Undefined when the
+    /// two directions coincide or `self` is at a pole.
+    fn position_angle(&self, other: &Coordinate<Icrs>) -> Angle;
 }
 
 impl IcrsCoordinateExt for Coordinate<Icrs> {
@@ -105,12 +146,63 @@ impl IcrsCoordinateExt for Coordinate<Icrs> {
         #[allow(deprecated)]
         Self::from_cartesian(components.x, components.y, components.z)
     }
+
+    fn to_ecliptic(&self, time: DateTime<Utc>, origin: crate::EclipticOrigin) -> Coordinate<crate::Ecliptic> {
+        crate::transforms::icrs_to_ecliptic_at(time, origin).transform(*self)
+    }
+
+    fn angular_separation(&self, other: &Coordinate<Icrs>) -> Angle {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let a = nalgebra::Vector3::from(self.to_cartesian().map(|c| c.get::<meter>()));
+        let b = nalgebra::Vector3::from(other.to_cartesian().map(|c| c.get::<meter>()));
+        Angle::new::<radian>(crate::rotation_helper::angle_between(&a, &b))
+    }
+
+    fn position_angle(&self, other: &Coordinate<Icrs>) -> Angle {
+        use std::f64::consts::TAU;
+        use uom::si::angle::radian;
+
+        let (ra1, dec1, _) = self.to_spherical_celestial();
+        let (ra2, dec2, _) = other.to_spherical_celestial();
+        let (sin_dra, cos_dra) = (ra2 - ra1).get::<radian>().sin_cos();
+        let (sin_dec1, cos_dec1) = dec1.get::<radian>().sin_cos();
+        let (sin_dec2, cos_dec2) = dec2.get::<radian>().sin_cos();
+
+        let east = sin_dra * cos_dec2;
+        let north = cos_dec1 * sin_dec2 - sin_dec1 * cos_dec2 * cos_dra;
+        Angle::new::<radian>(east.atan2(north).rem_euclid(TAU))
+    }
 }
 
 /// Extension methods for MCI coordinates.
 pub trait MciCoordinateExt {
     /// Build MCI coordinate from cartesian components.
     fn build(components: crate::frames::CelestialComponents) -> Self;
+
+    /// Construct MCI coordinate from angles relative to the lunar equator.
+    ///
+    /// The lunar analogue of [`IcrsCoordinateExt::from_ra_dec`]: longitude is
+    /// measured in the lunar equatorial plane from the MCI X axis, latitude
+    /// towards the lunar north pole.
+    ///
+    /// # Parameters
+    ///
+    /// - `ra_like`: Longitude in the lunar equatorial plane (any value)
+    /// - `dec_like`: Latitude above the lunar equator (must be in range [-π/2, π/2])
+    /// - `distance`: Radial distance from the Moon's center
+    fn from_selenographic_equatorial(ra_like: Angle, dec_like: Angle, distance: Length) -> Self;
+
+    /// Convert to angles relative to the lunar equator.
+    ///
+    /// # Returns
+    ///
+    /// `(ra_like, dec_like, distance)` where:
+    /// - `ra_like`: Longitude in the lunar equatorial plane (0 to 2π radians)
+    /// - `dec_like`: Latitude above the lunar equator (-π/2 to π/2 radians)
+    /// - `distance`: Distance from the Moon's center
+    fn to_selenographic(&self) -> (Angle, Angle, Length);
 }
 
 impl MciCoordinateExt for Coordinate<Mci> {
@@ -118,6 +210,37 @@ impl MciCoordinateExt for Coordinate<Mci> {
         #[allow(deprecated)]
         Self::from_cartesian(components.x, components.y, components.z)
     }
+
+    fn from_selenographic_equatorial(ra_like: Angle, dec_like: Angle, distance: Length) -> Self {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let r = distance.get::<meter>();
+        let (sin_lat, cos_lat) = dec_like.get::<radian>().sin_cos();
+        let (sin_lon, cos_lon) = ra_like.get::<radian>().sin_cos();
+
+        coordinate_from(
+            Length::new::<meter>(r * cos_lat * cos_lon),
+            Length::new::<meter>(r * cos_lat * sin_lon),
+            Length::new::<meter>(r * sin_lat),
+        )
+    }
+
+    fn to_selenographic(&self) -> (Angle, Angle, Length) {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let [x, y, z] = self.to_cartesian().map(|c| c.get::<meter>());
+        let r = (x * x + y * y + z * z).sqrt();
+        let lon = y.atan2(x).rem_euclid(std::f64::consts::TAU);
+        let lat = if r > 0.0 { (z / r).asin() } else { 0.0 };
+
+        (
+            Angle::new::<radian>(lon),
+            Angle::new::<radian>(lat),
+            Length::new::<meter>(r),
+        )
+    }
 }
 
 /// Extension methods for GCRF coordinates.
@@ -133,6 +256,56 @@ impl GcrfCoordinateExt for Coordinate<crate::Gcrf> {
     }
 }
 
+/// Extension methods for Galactic coordinates.
+pub trait GalacticCoordinateExt {
+    /// Galactic longitude and latitude.
+    ///
+    /// # Returns
+    ///
+    /// `(l, b)` where:
+    /// - `l`: Galactic longitude (0 to 2π radians)
+    /// - `b`: Galactic latitude (-π/2 to π/2 radians)
+    fn to_galactic_lb(&self) -> (Angle, Angle);
+}
+
+impl GalacticCoordinateExt for Coordinate<crate::Galactic> {
+    fn to_galactic_lb(&self) -> (Angle, Angle) {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let [x, y, z] = self.to_cartesian().map(|c| c.get::<meter>());
+        let l = y.atan2(x).rem_euclid(std::f64::consts::TAU);
+        let b = z.atan2(x.hypot(y));
+
+        (Angle::new::<radian>(l), Angle::new::<radian>(b))
+    }
+}
+
+/// Extension methods for topocentric (horizontal) coordinates.
+pub trait TopocentricCoordinateExt {
+    /// Altitude above the horizon and azimuth.
+    ///
+    /// # Returns
+    ///
+    /// `(altitude, azimuth)` where:
+    /// - `altitude`: Elevation above the local horizon (-π/2 to π/2 radians)
+    /// - `azimuth`: Measured from north through east (0 to 2π radians)
+    fn to_alt_az(&self) -> (Angle, Angle);
+}
+
+impl TopocentricCoordinateExt for Coordinate<crate::Topocentric> {
+    fn to_alt_az(&self) -> (Angle, Angle) {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let [east, north, up] = self.to_cartesian().map(|c| c.get::<meter>());
+        let altitude = up.atan2(east.hypot(north));
+        let azimuth = east.atan2(north).rem_euclid(std::f64::consts::TAU);
+
+        (Angle::new::<radian>(altitude), Angle::new::<radian>(azimuth))
+    }
+}
+
 /// Extension methods for time-dependent celestial transforms.
 pub trait CelestialTransformExt {
     /// Constructs the transform from ICRS to ECEF at the specified time.
@@ -156,8 +329,10 @@ pub trait CelestialTransformExt {
 pub trait VelocityTransformExt<From, To> {
     /// Transform a velocity vector from one frame to another.
     ///
-    /// For rotating frames (like ICRS to ECEF), this accounts for the frame rotation
-    /// by applying the rotation to the velocity vector.
+    /// This only rotates the velocity vector. For frames that rotate relative
+    /// to each other (like ICRS to ECEF) it omits the `ω × r` transport term;
+    /// use [`transform_velocity_rotating`](Self::transform_velocity_rotating)
+    /// for those.
     ///
     /// # Parameters
     ///
@@ -172,6 +347,26 @@ pub trait VelocityTransformExt<From, To> {
         position: sguaba::Coordinate<From>,
         velocity: [f64; 3],
     ) -> [f64; 3];
+
+    /// Transform a velocity into a frame that rotates relative to the source.
+    ///
+    /// Applies `v_to = R·v_from - ω × r_to`, where `r_to` is `position`
+    /// expressed in the target frame and `omega` is the target frame's angular
+    /// velocity relative to the source, in rad/s and target-frame axes. For
+    /// ICRS → ECEF that is `[0, 0, EARTH_ROTATION_RATE]`; see also
+    /// [`icrs_to_ecef_velocity`].
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: The position at which the velocity is defined
+    /// - `velocity`: The velocity vector in the source frame [vx, vy, vz] in m/s
+    /// - `omega`: Angular velocity of the target frame [wx, wy, wz] in rad/s
+    fn transform_velocity_rotating(
+        &self,
+        position: sguaba::Coordinate<From>,
+        velocity: [f64; 3],
+        omega: [f64; 3],
+    ) -> [f64; 3];
 }
 
 impl<From, To> VelocityTransformExt<From, To> for RigidBodyTransform<From, To>
@@ -212,4 +407,1164 @@ where
             (vz_new - vz_orig).get::<meter>(),
         ]
     }
+
+    fn transform_velocity_rotating(
+        &self,
+        position: sguaba::Coordinate<From>,
+        velocity: [f64; 3],
+        omega: [f64; 3],
+    ) -> [f64; 3] {
+        use nalgebra::Vector3;
+        use uom::si::length::meter;
+
+        let r = Vector3::from(self.transform(position).to_cartesian().map(|c| c.get::<meter>()));
+        let rotated = Vector3::from(self.transform_velocity(position, velocity));
+
+        (rotated - Vector3::from(omega).cross(&r)).into()
+    }
+}
+
+/// Velocity of an Earth-fixed point as seen from ICRS, in m/s.
+///
+/// Adds the Earth-rotation term ω × r to the ECEF velocity before rotating it
+/// into ICRS, so a ground-fixed object (zero ECEF velocity) gets its ~465 m/s
+/// eastward inertial velocity at the equator. This is the inverse of
+/// [`icrs_to_ecef_velocity`]; converting radar tracks to inertial velocities
+/// is the typical use. Polar motion is neglected.
+#[must_use]
+pub fn ecef_to_icrs_velocity(position: Coordinate<Ecef>, velocity: [f64; 3], time: DateTime<Utc>) -> [f64; 3] {
+    use nalgebra::Vector3;
+    use uom::si::length::meter;
+
+    let r = Vector3::from(position.to_cartesian().map(|c| c.get::<meter>()));
+    let omega = Vector3::new(0.0, 0.0, crate::constants::EARTH_ROTATION_RATE);
+    let inertial = Vector3::from(velocity) + omega.cross(&r);
+
+    crate::transforms::ecef_to_icrs_at(time).transform_velocity(position, inertial.into())
+}
+
+/// Velocity of an ICRS object relative to the rotating Earth, in m/s.
+///
+/// Rotates the velocity into ECEF and subtracts ω × r at the ECEF position.
+/// Inverse of [`ecef_to_icrs_velocity`].
+#[must_use]
+pub fn icrs_to_ecef_velocity(position: Coordinate<Icrs>, velocity: [f64; 3], time: DateTime<Utc>) -> [f64; 3] {
+    let omega = [0.0, 0.0, crate::constants::EARTH_ROTATION_RATE];
+    crate::transforms::icrs_to_ecef_at(time).transform_velocity_rotating(position, velocity, omega)
+}
+
+/// Day/night terminator as a polyline of `(latitude, longitude)` points.
+///
+/// Returns `num_points` points evenly spaced along the great circle lying 90°
+/// from the [sub-solar point](crate::astrodynamics::subsolar_point), treating
+/// the Earth as a sphere. Longitudes are wrapped into (-π, π], so map overlays
+/// should break the line where consecutive points jump across the antimeridian.
+#[must_use]
+pub fn terminator(time: DateTime<Utc>, num_points: usize) -> Vec<(Angle, Angle)> {
+    use uom::si::angle::radian;
+
+    let (lat, lon) = crate::astrodynamics::subsolar_point(time);
+
+    // The terminator plane is spanned by local east and north at the sub-solar point
+    let [east, north, _] = enu_basis(lat, lon);
+
+    (0..num_points)
+        .map(|k| {
+            let theta = 2.0 * std::f64::consts::PI * k as f64 / num_points as f64;
+            let p = theta.cos() * east + theta.sin() * north;
+            (
+                Angle::new::<radian>(p.z.clamp(-1.0, 1.0).asin()),
+                Angle::new::<radian>(p.y.atan2(p.x)),
+            )
+        })
+        .collect()
+}
+
+
+/// Elevation of the Sun above the local horizon at a ground point.
+///
+/// Rotates the Sun's ECEF direction into the local East-North-Up frame at the
+/// given geodetic latitude/longitude. Negative values mean the Sun is below
+/// the horizon (night). Topocentric parallax (< 9 arcsec) is neglected.
+#[must_use]
+pub fn solar_elevation(lat: Angle, lon: Angle, time: DateTime<Utc>) -> Angle {
+    use uom::si::angle::radian;
+    use uom::si::length::meter;
+
+    let sun_icrs = crate::astrodynamics::sun_position_icrs(time);
+    let sun_ecef = crate::transforms::icrs_to_ecef_at(time).transform(sun_icrs);
+    let sun = nalgebra::Vector3::from(sun_ecef.to_cartesian().map(|c| c.get::<meter>()));
+
+    let [east, north, up] = enu_basis(lat, lon);
+    let (e, n, u) = (east.dot(&sun), north.dot(&sun), up.dot(&sun));
+
+    Angle::new::<radian>(u.atan2(e.hypot(n)))
+}
+
+/// Solar elevation over a global latitude/longitude grid.
+///
+/// The Sun's direction is computed once, from the
+/// [sub-solar point](crate::astrodynamics::subsolar_point), and each cell only
+/// takes a dot product with its local up vector, so large grids stay cheap.
+/// Values match [`solar_elevation`] at the cell centers.
+///
+/// # Returns
+///
+/// `lat_steps` rows of `lon_steps` elevations. Row `i` is centered on latitude
+/// `-90° + (i + ½)·180°/lat_steps` and column `j` on longitude
+/// `-180° + (j + ½)·360°/lon_steps`, so rows run south to north and columns
+/// west to east.
+#[must_use]
+pub fn solar_elevation_grid(time: DateTime<Utc>, lat_steps: usize, lon_steps: usize) -> Vec<Vec<Angle>> {
+    use uom::si::angle::{degree, radian};
+
+    let (sun_lat, sun_lon) = crate::astrodynamics::subsolar_point(time);
+    let [_, _, sun] = enu_basis(sun_lat, sun_lon);
+
+    (0..lat_steps)
+        .map(|i| {
+            let lat = Angle::new::<degree>(-90.0 + (i as f64 + 0.5) * 180.0 / lat_steps as f64);
+            (0..lon_steps)
+                .map(|j| {
+                    let lon = Angle::new::<degree>(-180.0 + (j as f64 + 0.5) * 360.0 / lon_steps as f64);
+                    let [_, _, up] = enu_basis(lat, lon);
+                    Angle::new::<radian>(up.dot(&sun).clamp(-1.0, 1.0).asin())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Zenith angle (90° minus altitude) of a target.
+#[must_use]
+pub fn zenith_angle(altitude: Angle) -> Angle {
+    Angle::new::<uom::si::angle::degree>(90.0) - altitude
+}
+
+/// Relative air mass along the line of sight at a given altitude.
+///
+/// Uses the Kasten-Young (1989) formula, which stays finite at the horizon
+/// (≈ 38). Targets below the horizon return `f64::INFINITY`, so a simple
+/// `air_mass(alt) < limit` test rejects them.
+#[must_use]
+pub fn air_mass(altitude: Angle) -> f64 {
+    use uom::si::angle::degree;
+
+    if altitude.get::<degree>() < 0.0 {
+        return f64::INFINITY;
+    }
+
+    let z = zenith_angle(altitude).get::<degree>();
+    1.0 / (z.to_radians().cos() + 0.505_72 * (96.079_95 - z).powf(-1.6364))
+}
+
+/// KML document drawing a trajectory as a 3D line for Google Earth.
+///
+/// Each track point is `(time, latitude, longitude, altitude above the
+/// ellipsoid)`. Points are emitted as `lon,lat,alt` tuples (degrees, degrees,
+/// meters) in a single `LineString` with `altitudeMode` set to `absolute`; the
+/// first and last timestamps become the placemark's `TimeSpan`.
+#[must_use]
+pub fn trajectory_kml(track: &[(DateTime<Utc>, Angle, Angle, Length)]) -> String {
+    use std::fmt::Write;
+    use uom::si::angle::degree;
+    use uom::si::length::meter;
+
+    let mut kml = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n",
+        "<Document>\n",
+        "<Placemark>\n",
+        "<name>Trajectory</name>\n",
+    ));
+
+    if let (Some(first), Some(last)) = (track.first(), track.last()) {
+        let _ = writeln!(
+            kml,
+            "<TimeSpan><begin>{}</begin><end>{}</end></TimeSpan>",
+            first.0.to_rfc3339(),
+            last.0.to_rfc3339()
+        );
+    }
+
+    kml.push_str("<LineString>\n<altitudeMode>absolute</altitudeMode>\n<coordinates>\n");
+    for (_, lat, lon, alt) in track {
+        let _ = writeln!(
+            kml,
+            "{:.6},{:.6},{:.1}",
+            lon.get::<degree>(),
+            lat.get::<degree>(),
+            alt.get::<meter>()
+        );
+    }
+    kml.push_str("</coordinates>\n</LineString>\n</Placemark>\n</Document>\n</kml>\n");
+
+    kml
+}
+
+/// Great-circle interpolation between two sky positions.
+///
+/// Slerps the unit directions of `a` and `b` and linearly interpolates their
+/// distances from the origin, so `t = 0` gives `a`, `t = 1` gives `b`, and
+/// intermediate values trace the arc between them on the sky.
+#[must_use]
+pub fn celestial_slerp(a: &Coordinate<Icrs>, b: &Coordinate<Icrs>, t: f64) -> Coordinate<Icrs> {
+    use uom::si::length::meter;
+
+    let va = nalgebra::Vector3::from(a.to_cartesian().map(|c| c.get::<meter>()));
+    let vb = nalgebra::Vector3::from(b.to_cartesian().map(|c| c.get::<meter>()));
+    let (ra, rb) = (va.norm(), vb.norm());
+
+    let direction = crate::rotation_helper::slerp(&(va / ra), &(vb / rb), t);
+    let p = direction * (ra + (rb - ra) * t);
+
+    #[allow(deprecated)]
+    Coordinate::<Icrs>::from_cartesian(
+        Length::new::<meter>(p.x),
+        Length::new::<meter>(p.y),
+        Length::new::<meter>(p.z),
+    )
+}
+
+/// Topocentric RA/Dec of a moving target and their rates, as seen by a ground observer.
+///
+/// The observer's inertial position and velocity (from Earth rotation) are
+/// derived from its ECEF position at `time`; the rates follow from the
+/// relative state, which is what a telescope tracking loop consumes.
+///
+/// # Returns
+///
+/// `(ra, dec, ra_rate, dec_rate, range_rate)` where the angular rates are in
+/// rad/s and the range rate is in m/s (positive when receding).
+#[must_use]
+pub fn topocentric_rates(
+    state: &crate::EphemerisState<Icrs>,
+    observer_ecef: &Coordinate<Ecef>,
+    time: DateTime<Utc>,
+) -> (Angle, Angle, f64, f64, f64) {
+    use nalgebra::Vector3;
+    use uom::si::angle::radian;
+    use uom::si::length::meter;
+
+    let to_icrs = crate::transforms::ecef_to_icrs_at(time);
+    let observer_icrs = to_icrs.transform(*observer_ecef);
+
+    // Observer velocity from Earth rotation
+    let observer_velocity = Vector3::from(ecef_to_icrs_velocity(*observer_ecef, [0.0; 3], time));
+
+    let target = Vector3::from(state.position().to_cartesian().map(|c| c.get::<meter>()));
+    let observer = Vector3::from(observer_icrs.to_cartesian().map(|c| c.get::<meter>()));
+    let target_velocity = Vector3::from(crate::timed::velocity_components(state.velocity()));
+
+    let rho = target - observer;
+    let rho_dot = target_velocity - observer_velocity;
+
+    let range = rho.norm();
+    let horizontal_sq = rho.x * rho.x + rho.y * rho.y;
+    let range_rate = rho.dot(&rho_dot) / range;
+
+    let ra = rho.y.atan2(rho.x).rem_euclid(2.0 * std::f64::consts::PI);
+    let dec = (rho.z / range).asin();
+    let ra_rate = (rho.x * rho_dot.y - rho.y * rho_dot.x) / horizontal_sq;
+    let dec_rate = (rho_dot.z - rho.z * range_rate / range) / horizontal_sq.sqrt();
+
+    (
+        Angle::new::<radian>(ra),
+        Angle::new::<radian>(dec),
+        ra_rate,
+        dec_rate,
+        range_rate,
+    )
+}
+
+/// Build a coordinate from Cartesian components in a given length unit.
+fn coordinate_from<S: sguaba::CoordinateSystem>(x: Length, y: Length, z: Length) -> Coordinate<S> {
+    #[allow(deprecated)]
+    Coordinate::from_cartesian(x, y, z)
+}
+
+/// ICRS coordinate from Cartesian components in kilometers.
+#[must_use]
+pub fn icrs_from_km(x: f64, y: f64, z: f64) -> Coordinate<Icrs> {
+    use uom::si::length::kilometer;
+    let km = Length::new::<kilometer>;
+    coordinate_from(km(x), km(y), km(z))
+}
+
+/// ICRS coordinate from Cartesian components in meters.
+#[must_use]
+pub fn icrs_from_m(x: f64, y: f64, z: f64) -> Coordinate<Icrs> {
+    use uom::si::length::meter;
+    let m = Length::new::<meter>;
+    coordinate_from(m(x), m(y), m(z))
+}
+
+/// MCI coordinate from Cartesian components in kilometers.
+#[must_use]
+pub fn mci_from_km(x: f64, y: f64, z: f64) -> Coordinate<Mci> {
+    use uom::si::length::kilometer;
+    let km = Length::new::<kilometer>;
+    coordinate_from(km(x), km(y), km(z))
+}
+
+/// MCI coordinate from Cartesian components in meters.
+#[must_use]
+pub fn mci_from_m(x: f64, y: f64, z: f64) -> Coordinate<Mci> {
+    use uom::si::length::meter;
+    let m = Length::new::<meter>;
+    coordinate_from(m(x), m(y), m(z))
+}
+
+/// GCRF coordinate from Cartesian components in kilometers.
+#[must_use]
+pub fn gcrf_from_km(x: f64, y: f64, z: f64) -> Coordinate<crate::Gcrf> {
+    use uom::si::length::kilometer;
+    let km = Length::new::<kilometer>;
+    coordinate_from(km(x), km(y), km(z))
+}
+
+/// GCRF coordinate from Cartesian components in meters.
+#[must_use]
+pub fn gcrf_from_m(x: f64, y: f64, z: f64) -> Coordinate<crate::Gcrf> {
+    use uom::si::length::meter;
+    let m = Length::new::<meter>;
+    coordinate_from(m(x), m(y), m(z))
+}
+
+/// Assert that frames `A` and `B` are equivalent.
+///
+/// The relationship is checked at compile time: the call only type-checks when
+/// `A` and `B` implement `EquivalentTo` for each other. At runtime a probe
+/// coordinate is cast `A → B → A` and must come back bit-for-bit unchanged.
+///
+/// # Panics
+///
+/// Panics if the runtime roundtrip alters the coordinate.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "celestial")] {
+/// use crate::{assert_frames_equivalent, Gcrf, Icrs};
+///
+/// assert_frames_equivalent::<Gcrf, Icrs>();
+/// # }
+/// ```
+///
+/// Frames that are related by a rotation are rejected:
+///
+/// ```compile_fail
+/// use sguaba_celestial::{assert_frames_equivalent, Icrs, Mci};
+///
+/// assert_frames_equivalent::<Icrs, Mci>();
+/// ```
+pub fn assert_frames_equivalent<A, B>()
+where
+    A: sguaba::CoordinateSystem + sguaba::systems::EquivalentTo<B>,
+    B: sguaba::CoordinateSystem + sguaba::systems::EquivalentTo<A>,
+{
+    use uom::si::length::meter;
+    let m = Length::new::<meter>;
+
+    let probe = coordinate_from::<A>(m(1.0), m(-2.0), m(3.0));
+    let cast = probe.cast::<B>();
+    assert_eq!(cast.to_cartesian(), probe.to_cartesian());
+    assert_eq!(cast.cast::<A>().to_cartesian(), probe.to_cartesian());
+}
+
+/// Closest point of `set` to `query` by Euclidean distance.
+///
+/// # Returns
+///
+/// `(index, distance)` of the nearest point, or `None` if `set` is empty.
+#[must_use]
+pub fn nearest<S: sguaba::CoordinateSystem>(
+    query: &Coordinate<S>,
+    set: &[Coordinate<S>],
+) -> Option<(usize, Length)> {
+    k_nearest(query, set, 1).into_iter().next()
+}
+
+/// The `k` closest points of `set` to `query` by Euclidean distance.
+///
+/// # Returns
+///
+/// Up to `k` `(index, distance)` pairs, nearest first.
+#[must_use]
+pub fn k_nearest<S: sguaba::CoordinateSystem>(
+    query: &Coordinate<S>,
+    set: &[Coordinate<S>],
+    k: usize,
+) -> Vec<(usize, Length)> {
+    use uom::si::length::meter;
+
+    let mut matches: Vec<(usize, Length)> = set
+        .iter()
+        .enumerate()
+        .map(|(i, point)| (i, query.distance_from(point)))
+        .collect();
+    matches.sort_by(|a, b| a.1.get::<meter>().total_cmp(&b.1.get::<meter>()));
+    matches.truncate(k);
+    matches
+}
+
+/// Closest point of `set` to `query` by angular separation on the sky.
+///
+/// Only directions from the origin are compared, so points at very different
+/// distances match when they lie along the same line of sight, which is what
+/// catalog cross-identification needs.
+///
+/// # Returns
+///
+/// `(index, separation)` of the nearest direction, or `None` if `set` is empty.
+#[must_use]
+pub fn nearest_on_sky(query: &Coordinate<Icrs>, set: &[Coordinate<Icrs>]) -> Option<(usize, Angle)> {
+    use uom::si::angle::radian;
+    use uom::si::length::meter;
+
+    let direction =
+        |c: &Coordinate<Icrs>| nalgebra::Vector3::from(c.to_cartesian().map(|x| x.get::<meter>()));
+    let q = direction(query);
+
+    set.iter()
+        .enumerate()
+        .map(|(i, point)| (i, crate::rotation_helper::angle_between(&q, &direction(point))))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, separation)| (i, Angle::new::<radian>(separation)))
+}
+
+/// Local East, North and Up unit vectors (in ECEF) at a geodetic latitude/longitude.
+pub(crate) fn enu_basis(lat: Angle, lon: Angle) -> [nalgebra::Vector3<f64>; 3] {
+    use nalgebra::Vector3;
+    use uom::si::angle::radian;
+
+    let (sin_lat, cos_lat) = lat.get::<radian>().sin_cos();
+    let (sin_lon, cos_lon) = lon.get::<radian>().sin_cos();
+
+    [
+        Vector3::new(-sin_lon, cos_lon, 0.0),
+        Vector3::new(-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat),
+        Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat),
+    ]
+}
+
+/// Look angles from a ground station to an Earth-fixed target.
+///
+/// Transforms `sat` into the observer's [`Topocentric`](crate::Topocentric)
+/// frame with [`ecef_to_topocentric`](crate::transforms::ecef_to_topocentric)
+/// and reads off [`to_alt_az`](TopocentricCoordinateExt::to_alt_az).
+///
+/// There is no `time` parameter: the station and an ECEF position are both
+/// Earth-fixed, so the look angles do not depend on the epoch. For an ICRS
+/// position, which does, use
+/// [`icrs_to_topocentric_at`](crate::transforms::icrs_to_topocentric_at).
+/// Refraction is neglected.
+///
+/// # Returns
+///
+/// `(elevation, azimuth)`, with azimuth measured from north through east.
+#[must_use]
+pub fn elevation_azimuth(observer: &crate::Observer, sat: &Coordinate<Ecef>) -> (Angle, Angle) {
+    crate::transforms::ecef_to_topocentric(observer).transform(*sat).to_alt_az()
+}
+
+/// Whether `sat` is at least `min_elevation` above the observer's horizon.
+///
+/// See [`elevation_azimuth`]; stations typically use a 5-10° mask to clear
+/// terrain and multipath.
+#[must_use]
+pub fn is_visible(observer: &crate::Observer, sat: &Coordinate<Ecef>, min_elevation: Angle) -> bool {
+    elevation_azimuth(observer, sat).0 >= min_elevation
+}
+
+/// Range rate from a ground station to a satellite.
+///
+/// The station is fixed in ECEF, so this is the satellite's ECEF velocity
+/// projected onto the station-to-satellite line of sight. Negative while the
+/// satellite approaches.
+///
+/// There is no `time` parameter: the state already carries its epoch, and an
+/// Earth-fixed station and ECEF state need no rotation to that epoch.
+#[must_use]
+pub fn range_rate(observer: &crate::Observer, state: &crate::EphemerisState<Ecef>) -> uom::si::f64::Velocity {
+    use uom::si::length::meter;
+    use uom::si::velocity::meter_per_second;
+
+    let position = |c: &Coordinate<Ecef>| nalgebra::Vector3::from(c.to_cartesian().map(|x| x.get::<meter>()));
+    let line_of_sight = (position(state.position()) - position(&observer.to_ecef())).normalize();
+    let velocity = nalgebra::Vector3::from(crate::timed::velocity_components(state.velocity()));
+
+    uom::si::f64::Velocity::new::<meter_per_second>(velocity.dot(&line_of_sight))
+}
+
+/// First-order Doppler shift (Hz) of a carrier at `carrier_freq` Hz.
+///
+/// `Δf = -f·ṙ/c` with [`SPEED_OF_LIGHT`](crate::constants::SPEED_OF_LIGHT):
+/// positive while the range shrinks. Relativistic terms are below 1 Hz per
+/// GHz at orbital speeds.
+#[must_use]
+pub fn doppler_shift(range_rate: uom::si::f64::Velocity, carrier_freq: f64) -> f64 {
+    use uom::si::velocity::meter_per_second;
+
+    -carrier_freq * range_rate.get::<meter_per_second>() / crate::constants::SPEED_OF_LIGHT
+}
+
+/// ECEF position of a WGS84 geodetic latitude, longitude and ellipsoidal height.
+///
+/// Uses the prime-vertical radius of curvature `N = a / √(1 - e²·sin² φ)`;
+/// the inverse of [`ecef_to_geodetic`].
+#[must_use]
+pub fn geodetic_to_ecef(lat: Angle, lon: Angle, alt: Length) -> Coordinate<Ecef> {
+    use crate::constants::{EARTH_RADIUS_EQUATORIAL, EARTH_RADIUS_POLAR};
+    use uom::si::angle::radian;
+    use uom::si::length::meter;
+
+    let a = EARTH_RADIUS_EQUATORIAL;
+    let e2 = 1.0 - (EARTH_RADIUS_POLAR / a).powi(2);
+    let h = alt.get::<meter>();
+    let (sin_lat, cos_lat) = lat.get::<radian>().sin_cos();
+    let (sin_lon, cos_lon) = lon.get::<radian>().sin_cos();
+
+    let n = a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+    #[allow(deprecated)]
+    Coordinate::<Ecef>::from_cartesian(
+        Length::new::<meter>((n + h) * cos_lat * cos_lon),
+        Length::new::<meter>((n + h) * cos_lat * sin_lon),
+        Length::new::<meter>((n * (1.0 - e2) + h) * sin_lat),
+    )
+}
+
+/// WGS84 geodetic latitude, longitude and ellipsoidal height of an ECEF point.
+///
+/// Iterates `φ = atan2(z + e²·N(φ)·sin φ, p)` on the ellipsoid defined by
+/// [`EARTH_RADIUS_EQUATORIAL`](crate::constants::EARTH_RADIUS_EQUATORIAL)
+/// and the flattening implied by
+/// [`EARTH_RADIUS_POLAR`](crate::constants::EARTH_RADIUS_POLAR), until the
+/// latitude correction meets [`SolverConfig::default`](crate::SolverConfig),
+/// then takes the height along the normal as
+/// `p·cos φ + z·sin φ - a·√(1 - e²·sin² φ)`. Both stay finite on the polar
+/// axis, where the latitude is exactly ±90°, and below the surface, where the
+/// height is negative. The Earth's centre has no unique normal and maps to
+/// latitude 0 and height `-a`.
+///
+/// If the iteration does not converge, the last iterate is returned; use
+/// [`try_ecef_to_geodetic`] to detect that.
+///
+/// # Returns
+///
+/// `(latitude, longitude, height)` with longitude in (-π, π].
+#[must_use]
+pub fn ecef_to_geodetic(coord: &Coordinate<Ecef>) -> (Angle, Angle, Length) {
+    geodetic_from_ecef(coord, crate::SolverConfig::default()).0
+}
+
+/// Fallible form of [`ecef_to_geodetic`] with explicit solver settings.
+///
+/// `None` uses [`SolverConfig::default`](crate::SolverConfig::default), a
+/// latitude tolerance of 1e-12 rad (~6 µm on the surface).
+///
+/// # Errors
+///
+/// Returns `CelestialError::NumericalPrecisionError` if the latitude does not
+/// converge within `config`, including for non-finite coordinates.
+pub fn try_ecef_to_geodetic(
+    coord: &Coordinate<Ecef>,
+    config: Option<crate::SolverConfig>,
+) -> crate::CelestialResult<(Angle, Angle, Length)> {
+    let config = config.unwrap_or_default();
+    match geodetic_from_ecef(coord, config) {
+        (geodetic, true) => Ok(geodetic),
+        (_, false) => Err(crate::CelestialError::NumericalPrecisionError {
+            reason: format!(
+                "geodetic latitude did not converge to {:e} within {} iterations",
+                config.rel_tol, config.max_iter
+            ),
+        }),
+    }
+}
+
+/// Fixed-point iteration for [`ecef_to_geodetic`], returning the last iterate
+/// and whether the latitude met the tolerance within `config.max_iter` steps.
+fn geodetic_from_ecef(coord: &Coordinate<Ecef>, config: crate::SolverConfig) -> ((Angle, Angle, Length), bool) {
+    use crate::constants::{EARTH_RADIUS_EQUATORIAL, EARTH_RADIUS_POLAR};
+    use uom::si::angle::radian;
+    use uom::si::length::meter;
+
+    let a = EARTH_RADIUS_EQUATORIAL;
+    let e2 = 1.0 - (EARTH_RADIUS_POLAR / a).powi(2);
+    let [x, y, z] = coord.to_cartesian().map(|c| c.get::<meter>());
+    let p = x.hypot(y);
+
+    // Converges by a factor of ~e² per step near the surface, slower deep inside
+    let mut lat = z.atan2(p * (1.0 - e2));
+    let mut converged = false;
+    for _ in 0..config.max_iter {
+        let sin_lat = lat.sin();
+        let n = a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        let next = (z + e2 * n * sin_lat).atan2(p);
+        converged = config.converged(next - lat, next);
+        lat = next;
+        if converged {
+            break;
+        }
+    }
+
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let height = p * cos_lat + z * sin_lat - a * (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+    let geodetic = (Angle::new::<radian>(lat), Angle::new::<radian>(y.atan2(x)), Length::new::<meter>(height));
+    (geodetic, converged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uom::si::angle::radian;
+
+    #[test]
+    fn equator_point_maps_to_minus_obliquity() {
+        use chrono::TimeZone;
+        use uom::si::length::meter;
+
+        let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let ra_90 = icrs_from_km(0.0, 1.0, 0.0);
+        let ecl = ra_90.to_ecliptic(j2000, crate::EclipticOrigin::Geocentric);
+
+        let [x, y, z] = ecl.to_cartesian().map(|c| c.get::<meter>());
+        let latitude = z.atan2(x.hypot(y)).to_degrees();
+        assert!((latitude + 23.439_279).abs() < 1e-5);
+        assert!((ecl.distance_from_origin().get::<meter>() - 1000.0).abs() < 1e-9);
+
+        // The equinox direction is common to both planes, up to the ~23 mas
+        // frame bias between the ICRS X axis and the J2000 equinox
+        let equinox = icrs_from_km(1.0, 0.0, 0.0).to_ecliptic(j2000, crate::EclipticOrigin::Geocentric);
+        assert!(equinox.to_cartesian()[2].get::<meter>().abs() < 1e3 * 0.025 * crate::constants::ARCSEC_TO_RAD);
+    }
+
+    #[test]
+    fn stationary_equator_point_moves_east_inertially() {
+        use uom::si::length::meter;
+
+        let time = Utc.with_ymd_and_hms(2025, 9, 1, 6, 0, 0).unwrap();
+        #[allow(deprecated)]
+        let site = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<meter>(crate::constants::EARTH_RADIUS_EQUATORIAL),
+            Length::new::<meter>(0.0),
+            Length::new::<meter>(0.0),
+        );
+
+        let v = ecef_to_icrs_velocity(site, [0.0; 3], time);
+        let speed = crate::constants::EARTH_ROTATION_RATE * crate::constants::EARTH_RADIUS_EQUATORIAL;
+        assert!((speed - 465.1).abs() < 0.1);
+
+        let east = crate::transforms::ecef_to_icrs_at(time).transform_velocity(site, [0.0, speed, 0.0]);
+        for i in 0..3 {
+            assert!((v[i] - east[i]).abs() < 1e-9);
+        }
+
+        let site_icrs = crate::transforms::ecef_to_icrs_at(time).transform(site);
+        let [x, y, _] = site_icrs.to_cartesian().map(|c| c.get::<meter>());
+        assert!(x * v[1] - y * v[0] > 0.0, "inertial velocity should point east");
+
+        let back = icrs_to_ecef_velocity(site_icrs, v, time);
+        assert!(back.iter().all(|c| c.abs() < 1e-6));
+    }
+
+    #[test]
+    fn inertially_fixed_point_drifts_west_in_ecef() {
+        use uom::si::length::meter;
+
+        let time = Utc.with_ymd_and_hms(2025, 2, 14, 11, 30, 0).unwrap();
+        let to_ecef = crate::transforms::icrs_to_ecef_at(time);
+        let point = icrs_from_km(7_000.0, 0.0, 0.0);
+        let omega = [0.0, 0.0, crate::constants::EARTH_ROTATION_RATE];
+
+        let v = to_ecef.transform_velocity_rotating(point, [0.0; 3], omega);
+        let [x, y, z] = to_ecef.transform(point).to_cartesian().map(|c| c.get::<meter>());
+
+        // -ω × r: magnitude ω·ρ (ρ the distance from the pole axis), heading west
+        let expected = crate::constants::EARTH_ROTATION_RATE * x.hypot(y);
+        assert!((v[0].hypot(v[1]) - expected).abs() < 1e-9);
+        assert!((expected - 510.4).abs() < 0.5);
+        assert!(x * v[1] - y * v[0] < 0.0);
+        assert!(v[2].abs() < 1e-12);
+        assert!(z.abs() < 100e3);
+
+        // Without rotation the stationary point stays stationary
+        assert_eq!(to_ecef.transform_velocity(point, [0.0; 3]), [0.0; 3]);
+    }
+
+    #[test]
+    fn selenographic_equatorial_roundtrip() {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        for (lon, lat) in [(0.0, 0.0), (123.4, -45.6), (300.0, 89.0)] {
+            let c = Coordinate::<Mci>::from_selenographic_equatorial(
+                Angle::new::<degree>(lon),
+                Angle::new::<degree>(lat),
+                Length::new::<kilometer>(1_837.4),
+            );
+            let (lon_out, lat_out, r) = c.to_selenographic();
+            assert!((lon_out.get::<degree>() - lon).abs() < 1e-9);
+            assert!((lat_out.get::<degree>() - lat).abs() < 1e-9);
+            assert!((r.get::<kilometer>() - 1_837.4).abs() < 1e-9);
+        }
+
+        let pole = Coordinate::<Mci>::from_selenographic_equatorial(
+            Angle::new::<degree>(10.0),
+            Angle::new::<degree>(90.0),
+            Length::new::<kilometer>(2_000.0),
+        );
+        let [x, y, z] = pole.to_cartesian().map(|c| c.get::<kilometer>());
+        assert!(x.abs() < 1e-9 && y.abs() < 1e-9 && (z - 2_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn zenith_source_has_altitude_90_degrees() {
+        use crate::transforms::{ecef_to_icrs_at, icrs_to_topocentric_at, topocentric_to_icrs_at};
+        use crate::Observer;
+        use uom::si::angle::degree;
+        use uom::si::length::{kilometer, meter};
+
+        let time = Utc.with_ymd_and_hms(2025, 7, 4, 3, 0, 0).unwrap();
+        let observer = Observer::new(
+            Angle::new::<degree>(40.0),
+            Angle::new::<degree>(-105.3),
+            Length::new::<meter>(1_650.0),
+        );
+        let [_, north, up] = enu_basis(observer.latitude, observer.longitude);
+        let site = nalgebra::Vector3::from(observer.to_ecef().to_cartesian().map(|c| c.get::<meter>()));
+        let ecef_point = |offset: nalgebra::Vector3<f64>| {
+            let p = site + offset;
+            ecef_to_icrs_at(time).transform(coordinate_from::<Ecef>(
+                Length::new::<meter>(p.x),
+                Length::new::<meter>(p.y),
+                Length::new::<meter>(p.z),
+            ))
+        };
+
+        let to_topo = icrs_to_topocentric_at(&observer, time);
+        let overhead = ecef_point(up * 500e3);
+        let (altitude, _) = to_topo.transform(overhead).to_alt_az();
+        assert!((altitude.get::<degree>() - 90.0).abs() < 1e-6);
+
+        let (altitude, azimuth) = to_topo.transform(ecef_point(north * 10e3)).to_alt_az();
+        assert!(altitude.get::<degree>().abs() < 1e-6);
+        assert!(azimuth.get::<degree>().abs() < 1e-6 || (azimuth.get::<degree>() - 360.0).abs() < 1e-6);
+
+        let back = topocentric_to_icrs_at(&observer, time).transform(to_topo.transform(overhead));
+        assert!(back.distance_from(&overhead).get::<kilometer>() < 1e-9);
+    }
+
+    #[test]
+    fn trajectory_kml_lists_every_point() {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let track: Vec<_> = (0..4)
+            .map(|i| {
+                (
+                    start + chrono::Duration::minutes(i),
+                    Angle::new::<degree>(10.0 * i as f64),
+                    Angle::new::<degree>(-120.0 + 5.0 * i as f64),
+                    Length::new::<kilometer>(400.0),
+                )
+            })
+            .collect();
+
+        let kml = trajectory_kml(&track);
+        let open = kml.find("<coordinates>").unwrap() + "<coordinates>".len();
+        let close = kml.find("</coordinates>").unwrap();
+        let tuples: Vec<&str> = kml[open..close].split_whitespace().collect();
+
+        assert_eq!(tuples.len(), 4);
+        assert_eq!(tuples[1], "-115.000000,10.000000,400000.0");
+        assert!(kml.contains("<altitudeMode>absolute</altitudeMode>"));
+        assert!(kml.trim_end().ends_with("</kml>"));
+    }
+
+    #[test]
+    fn air_mass_rises_steeply_toward_horizon() {
+        use uom::si::angle::degree;
+        let alt = Angle::new::<degree>;
+
+        assert!((air_mass(alt(90.0)) - 1.0).abs() < 1e-3);
+        assert!((air_mass(alt(30.0)) - 2.0).abs() < 0.01);
+        assert!(air_mass(alt(5.0)) > 10.0);
+        assert!(air_mass(alt(0.0)) > 35.0 && air_mass(alt(0.0)).is_finite());
+        assert_eq!(air_mass(alt(-1.0)), f64::INFINITY);
+        assert!((zenith_angle(alt(30.0)).get::<degree>() - 60.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gcrf_and_icrs_are_equivalent() {
+        assert_frames_equivalent::<crate::Gcrf, Icrs>();
+        assert_frames_equivalent::<Icrs, crate::Gcrf>();
+    }
+
+    #[test]
+    fn terminator_is_quarter_circle_from_subsolar_point() {
+        let time = Utc.with_ymd_and_hms(2025, 6, 21, 15, 0, 0).unwrap();
+        let (sub_lat, sub_lon) = crate::astrodynamics::subsolar_point(time);
+
+        let points = terminator(time, 72);
+        assert_eq!(points.len(), 72);
+
+        for (lat, lon) in points {
+            let (lat, lon) = (lat.get::<radian>(), lon.get::<radian>());
+            let cos_sep = lat.sin() * sub_lat.get::<radian>().sin()
+                + lat.cos() * sub_lat.get::<radian>().cos() * (lon - sub_lon.get::<radian>()).cos();
+            assert!((cos_sep.acos().to_degrees() - 90.0).abs() < 1e-9);
+            assert!(lon.to_degrees() > -180.0 && lon.to_degrees() <= 180.0);
+        }
+    }
+
+    #[test]
+    fn solar_elevation_grid_peaks_at_subsolar_point() {
+        use uom::si::angle::degree;
+
+        let time = Utc.with_ymd_and_hms(2025, 6, 1, 15, 30, 0).unwrap();
+        let (lat_steps, lon_steps) = (90, 180);
+        let grid = solar_elevation_grid(time, lat_steps, lon_steps);
+        assert_eq!(grid.len(), lat_steps);
+        assert!(grid.iter().all(|row| row.len() == lon_steps));
+
+        let (i, j) = (0..lat_steps)
+            .flat_map(|i| (0..lon_steps).map(move |j| (i, j)))
+            .max_by(|&(a, b), &(c, d)| grid[a][b].partial_cmp(&grid[c][d]).unwrap())
+            .unwrap();
+        let lat = -90.0 + (i as f64 + 0.5) * 2.0;
+        let lon = -180.0 + (j as f64 + 0.5) * 2.0;
+
+        let (sun_lat, sun_lon) = crate::astrodynamics::subsolar_point(time);
+        assert!((lat - sun_lat.get::<degree>()).abs() <= 1.0);
+        assert!((lon - sun_lon.get::<degree>()).abs() <= 1.0);
+        assert!(grid[i][j].get::<degree>() > 88.0);
+
+        let direct = solar_elevation(Angle::new::<degree>(lat), Angle::new::<degree>(lon), time);
+        assert!((direct - grid[i][j]).get::<degree>().abs() < 1e-3);
+    }
+
+    #[test]
+    fn solar_elevation_overhead_at_equinox_noon_on_equator() {
+        use uom::si::angle::degree;
+
+        let time = Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap();
+        let (_, noon_lon) = crate::astrodynamics::subsolar_point(time);
+
+        let noon = solar_elevation(Angle::new::<degree>(0.0), noon_lon, time);
+        let midnight = solar_elevation(
+            Angle::new::<degree>(0.0),
+            noon_lon + Angle::new::<degree>(180.0),
+            time,
+        );
+
+        assert!(noon.get::<degree>() > 89.5);
+        assert!(midnight.get::<degree>() < -89.5);
+    }
+
+    #[test]
+    fn celestial_slerp_midpoint_bisects_great_circle() {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let a = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(10.0),
+            dec: Angle::new::<degree>(20.0),
+            distance: Length::new::<kilometer>(1000.0),
+        });
+        let b = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(80.0),
+            dec: Angle::new::<degree>(-15.0),
+            distance: Length::new::<kilometer>(3000.0),
+        });
+
+        let mid = celestial_slerp(&a, &b, 0.5);
+        let unit = |c: &Coordinate<Icrs>| {
+            nalgebra::Vector3::from(c.to_cartesian().map(|l| l.get::<kilometer>())).normalize()
+        };
+        let (ua, ub, um) = (unit(&a), unit(&b), unit(&mid));
+
+        assert!((ua.angle(&um) - um.angle(&ub)).abs() < 1e-12);
+        assert!(um.dot(&ua.cross(&ub).normalize()).abs() < 1e-12);
+        assert!((mid.distance_from_origin().get::<kilometer>() - 2000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn co_moving_target_has_zero_topocentric_rates() {
+        use crate::EphemerisState;
+        use uom::si::f64::Velocity;
+        use uom::si::length::{kilometer, meter};
+        use uom::si::velocity::meter_per_second;
+
+        let time = Utc.with_ymd_and_hms(2025, 5, 5, 22, 0, 0).unwrap();
+        #[allow(deprecated)]
+        let observer = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<kilometer>(3_900.0),
+            Length::new::<kilometer>(900.0),
+            Length::new::<kilometer>(4_900.0),
+        );
+
+        // A target rigidly offset from the observer and moving with it
+        let to_icrs = crate::transforms::ecef_to_icrs_at(time);
+        let [x, y, z] = to_icrs.transform(observer).to_cartesian().map(|c| c.get::<meter>());
+        let [ox, oy, _] = observer.to_cartesian().map(|c| c.get::<meter>());
+        let rate = crate::constants::EARTH_ROTATION_RATE;
+        let v_obs = to_icrs.transform_velocity(observer, [-rate * oy, rate * ox, 0.0]);
+
+        #[allow(deprecated)]
+        let target = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<meter>(x + 1.0e6),
+            Length::new::<meter>(y + 2.0e6),
+            Length::new::<meter>(z + 3.0e6),
+        );
+        #[allow(deprecated)]
+        let velocity = sguaba::Vector::from_cartesian(
+            Velocity::new::<meter_per_second>(v_obs[0]),
+            Velocity::new::<meter_per_second>(v_obs[1]),
+            Velocity::new::<meter_per_second>(v_obs[2]),
+        );
+        let state = EphemerisState::new(target, velocity, time);
+
+        let (_, _, ra_rate, dec_rate, range_rate) = topocentric_rates(&state, &observer, time);
+
+        assert!(ra_rate.abs() < 1e-12);
+        assert!(dec_rate.abs() < 1e-12);
+        assert!(range_rate.abs() < 1e-6);
+    }
+
+    #[test]
+    fn nearest_finds_closest_point() {
+        use uom::si::length::kilometer;
+
+        let km = |x: f64, y: f64, z: f64| {
+            #[allow(deprecated)]
+            Coordinate::<Ecef>::from_cartesian(
+                Length::new::<kilometer>(x),
+                Length::new::<kilometer>(y),
+                Length::new::<kilometer>(z),
+            )
+        };
+        let set = [km(100.0, 0.0, 0.0), km(0.0, 3.0, 4.0), km(-10.0, 0.0, 0.0), km(0.0, 0.0, 7.0)];
+
+        let (index, distance) = nearest(&km(0.0, 0.0, 0.0), &set).unwrap();
+        assert_eq!(index, 1);
+        assert!((distance.get::<kilometer>() - 5.0).abs() < 1e-9);
+
+        let order: Vec<usize> = k_nearest(&km(0.0, 0.0, 0.0), &set, 3).iter().map(|m| m.0).collect();
+        assert_eq!(order, vec![1, 3, 2]);
+        assert!(nearest(&km(0.0, 0.0, 0.0), &[]).is_none());
+    }
+
+    #[test]
+    fn separation_is_precise_for_nearly_coincident_directions() {
+        use uom::si::angle::{degree, radian};
+        use uom::si::length::kilometer;
+
+        let sky = |ra: f64, dec: f64| {
+            Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+                ra: Angle::new::<radian>(ra),
+                dec: Angle::new::<radian>(dec),
+                distance: Length::new::<kilometer>(1.0),
+            })
+        };
+        let a = sky(1.2, 0.3);
+
+        // 1 nrad (0.2 mas): acos of the dot product would return 0 here
+        let separation = a.angular_separation(&sky(1.2, 0.3 + 1e-9)).get::<radian>();
+        assert!((separation - 1e-9).abs() < 1e-15);
+
+        let antipode = sky(1.2 + std::f64::consts::PI, -0.3 + 1e-9);
+        assert!((a.angular_separation(&antipode).get::<radian>() - (std::f64::consts::PI - 1e-9)).abs() < 1e-14);
+
+        let offset = |dra: f64, ddec: f64| a.position_angle(&sky(1.2 + dra, 0.3 + ddec)).get::<degree>();
+        assert!(offset(0.0, 1e-6).abs() < 1e-6);
+        assert!((offset(1e-6, 0.0) - 90.0).abs() < 1e-3);
+        assert!((offset(0.0, -1e-6) - 180.0).abs() < 1e-6);
+        assert!((offset(-1e-6, 0.0) - 270.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn geodetic_conversion_handles_equator_poles_and_interior() {
+        use crate::constants::{EARTH_RADIUS_EQUATORIAL, EARTH_RADIUS_POLAR};
+        use crate::Observer;
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        #[allow(deprecated)]
+        let ecef = |x: f64, y: f64, z: f64| {
+            Coordinate::<Ecef>::from_cartesian(
+                Length::new::<meter>(x),
+                Length::new::<meter>(y),
+                Length::new::<meter>(z),
+            )
+        };
+
+        let (lat, lon, alt) = ecef_to_geodetic(&ecef(EARTH_RADIUS_EQUATORIAL, 0.0, 0.0));
+        assert_eq!(lat.get::<degree>(), 0.0);
+        assert_eq!(lon.get::<degree>(), 0.0);
+        assert!(alt.get::<meter>().abs() < 1e-9);
+
+        let (lat, _, alt) = ecef_to_geodetic(&ecef(0.0, 0.0, -(EARTH_RADIUS_POLAR + 250.0)));
+        assert_eq!(lat.get::<degree>(), -90.0);
+        assert!((alt.get::<meter>() - 250.0).abs() < 1e-6);
+
+        // Below the surface and at satellite altitude, against the forward conversion
+        for (lat, lon, alt) in [(37.5, -122.0, -4_000.0), (-62.0, 151.0, 35_786e3), (89.9, 10.0, 100.0)] {
+            let observer = Observer::new(
+                Angle::new::<degree>(lat),
+                Angle::new::<degree>(lon),
+                Length::new::<meter>(alt),
+            );
+            let (lat2, lon2, alt2) = ecef_to_geodetic(&observer.to_ecef());
+            assert!((lat2.get::<degree>() - lat).abs() < 1e-10);
+            assert!((lon2.get::<degree>() - lon).abs() < 1e-10);
+            assert!((alt2.get::<meter>() - alt).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn geodetic_solver_reports_non_convergence() {
+        use crate::{CelestialError, Observer, SolverConfig};
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        let ecef = Observer::new(
+            Angle::new::<degree>(51.5),
+            Angle::new::<degree>(-0.1),
+            Length::new::<meter>(45.0),
+        )
+        .to_ecef();
+        let (lat, _, alt) = try_ecef_to_geodetic(&ecef, None).unwrap();
+        assert!((lat.get::<degree>() - 51.5).abs() < 1e-10);
+        assert!((alt.get::<meter>() - 45.0).abs() < 1e-6);
+
+        // A single step cannot reach the tolerance off the equator
+        let starved = Some(SolverConfig::new(1, 1e-12));
+        assert!(matches!(
+            try_ecef_to_geodetic(&ecef, starved),
+            Err(CelestialError::NumericalPrecisionError { .. })
+        ));
+
+        #[allow(deprecated)]
+        let nan = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<meter>(f64::NAN),
+            Length::new::<meter>(0.0),
+            Length::new::<meter>(0.0),
+        );
+        assert!(try_ecef_to_geodetic(&nan, None).is_err());
+    }
+
+    #[test]
+    fn geodetic_roundtrip_closes_below_a_millimetre() {
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        for lat in (-90..=90).step_by(15) {
+            for lon in (-180..180).step_by(45) {
+                for alt in [-10_000.0, 0.0, 400e3, 42_164e3] {
+                    let (lat, lon) = (Angle::new::<degree>(lat as f64), Angle::new::<degree>(lon as f64));
+                    let ecef = geodetic_to_ecef(lat, lon, Length::new::<meter>(alt));
+                    let (lat2, lon2, alt2) = ecef_to_geodetic(&ecef);
+
+                    assert!((alt2.get::<meter>() - alt).abs() < 1e-3);
+                    assert!((lat2 - lat).get::<radian>().abs() < 1e-12);
+                    // Longitude is undefined on the polar axis
+                    if lat.get::<degree>().abs() < 90.0 {
+                        assert!((lon2 - lon).get::<radian>().abs() < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn satellite_overhead_is_at_zenith_and_visible() {
+        use crate::Observer;
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        let (lat, lon) = (Angle::new::<degree>(40.0), Angle::new::<degree>(-105.0));
+        let station = Observer::new(lat, lon, Length::new::<meter>(1_600.0));
+        let mask = Angle::new::<degree>(10.0);
+
+        let overhead = geodetic_to_ecef(lat, lon, Length::new::<meter>(500e3));
+        let (elevation, _) = elevation_azimuth(&station, &overhead);
+        assert!((elevation.get::<degree>() - 90.0).abs() < 1e-9);
+        assert!(is_visible(&station, &overhead, mask));
+
+        // Due north, low on the horizon, and on the far side of the Earth
+        let north = geodetic_to_ecef(Angle::new::<degree>(55.0), lon, Length::new::<meter>(500e3));
+        let (elevation, azimuth) = elevation_azimuth(&station, &north);
+        assert!(azimuth.get::<degree>() < 1e-6 || azimuth.get::<degree>() > 360.0 - 1e-6);
+        assert!(elevation.get::<degree>() < 10.0 && !is_visible(&station, &north, mask));
+
+        let antipode = geodetic_to_ecef(-lat, lon + Angle::new::<degree>(180.0), Length::new::<meter>(500e3));
+        assert!(elevation_azimuth(&station, &antipode).0.get::<degree>() < -80.0);
+    }
+
+    #[test]
+    fn approaching_satellite_has_negative_range_rate_and_upshift() {
+        use crate::timed::velocity_from_components;
+        use crate::{EphemerisState, Observer};
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+        use uom::si::velocity::meter_per_second;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let station = Observer::new(Angle::new::<degree>(0.0), Angle::new::<degree>(0.0), Length::new::<meter>(0.0));
+        // 500 km up, north of the station, heading south towards it
+        let position = geodetic_to_ecef(Angle::new::<degree>(10.0), Angle::new::<degree>(0.0), Length::new::<meter>(500e3));
+        let southbound = EphemerisState::new(position, velocity_from_components([0.0, 0.0, -7_500.0]), epoch);
+
+        let rate = range_rate(&station, &southbound);
+        assert!(rate.get::<meter_per_second>() < -1_000.0);
+        let shift = doppler_shift(rate, 437e6);
+        assert!(shift > 0.0);
+        assert!((shift + 437e6 * rate.get::<meter_per_second>() / crate::constants::SPEED_OF_LIGHT).abs() < 1e-9);
+
+        // Motion straight along the line of sight is reported in full
+        let xyz = |c: Coordinate<Ecef>| nalgebra::Vector3::from(c.to_cartesian().map(|x| x.get::<meter>()));
+        let away = (xyz(position) - xyz(station.to_ecef())).normalize() * 1_000.0;
+        let receding = EphemerisState::new(position, velocity_from_components(away.into()), epoch);
+        assert!((range_rate(&station, &receding).get::<meter_per_second>() - 1_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nearest_on_sky_ignores_distance() {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let sky = |ra: f64, dec: f64, km: f64| {
+            Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+                ra: Angle::new::<degree>(ra),
+                dec: Angle::new::<degree>(dec),
+                distance: Length::new::<kilometer>(km),
+            })
+        };
+        let query = sky(120.0, 10.0, 1.0e6);
+        // The first entry is far closer in space but 5° away; the second is
+        // 1000× farther along almost the same line of sight.
+        let set = [sky(125.0, 10.0, 1.0e6), sky(120.0, 10.01, 1.0e9), sky(0.0, -40.0, 1.0e6)];
+
+        let (index, separation) = nearest_on_sky(&query, &set).unwrap();
+        assert_eq!(index, 1);
+        assert!((separation.get::<degree>() - 0.01).abs() < 1e-9);
+        assert_eq!(nearest(&query, &set).unwrap().0, 0);
+    }
+
+    #[test]
+    fn km_and_m_constructors_agree() {
+        use uom::si::length::kilometer;
+
+        let icrs = icrs_from_km(7000.0, 0.0, 0.0);
+        assert!((icrs.distance_from_origin().get::<kilometer>() - 7000.0).abs() < 1e-9);
+        assert_eq!(icrs, icrs_from_m(7.0e6, 0.0, 0.0));
+        assert_eq!(mci_from_km(1.0, 2.0, 3.0), mci_from_m(1.0e3, 2.0e3, 3.0e3));
+        assert_eq!(gcrf_from_km(1.0, 2.0, 3.0), gcrf_from_m(1.0e3, 2.0e3, 3.0e3));
+    }
 }
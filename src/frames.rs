@@ -1,9 +1,13 @@
 //! Celestial coordinate system definitions.
 
-use sguaba::CoordinateSystem;
-use uom::si::f64::Length;
+use chrono::{DateTime, Utc};
+use sguaba::math::RigidBodyTransform;
+use sguaba::{Coordinate, CoordinateSystem};
+use uom::si::f64::{Angle, Length};
 use uom::si::length::meter;
 
+use crate::errors::{CelestialError, CelestialResult};
+
 /// Celestial coordinate convention (right-handed XYZ).
 ///
 /// Used for inertial celestial reference frames like ICRS and MCI.
@@ -21,6 +25,42 @@ pub struct CelestialComponents {
     pub z: Length,
 }
 
+impl CelestialComponents {
+    /// Iterate over the X, Y and Z components in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Length> {
+        [&self.x, &self.y, &self.z].into_iter()
+    }
+}
+
+impl std::ops::Index<usize> for CelestialComponents {
+    type Output = Length;
+
+    /// Component by axis: 0 = X, 1 = Y, 2 = Z.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    fn index(&self, index: usize) -> &Length {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("CelestialComponents index out of range: {index} (expected 0, 1 or 2)"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for CelestialComponents {
+    fn index_mut(&mut self, index: usize) -> &mut Length {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("CelestialComponents index out of range: {index} (expected 0, 1 or 2)"),
+        }
+    }
+}
+
 impl From<CelestialComponents> for [Length; 3] {
     fn from(c: CelestialComponents) -> Self {
         [c.x, c.y, c.z]
@@ -53,6 +93,140 @@ impl From<[f64; 3]> for CelestialComponents {
     }
 }
 
+/// Spherical equatorial coordinates (Right Ascension, Declination, Distance).
+///
+/// Named-field alternative to the `(ra, dec, distance)` tuple returned by
+/// [`IcrsCoordinateExt::to_spherical_celestial`](crate::IcrsCoordinateExt::to_spherical_celestial),
+/// which makes it impossible to silently swap RA and Dec.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquatorialCoords {
+    /// Right ascension (0 to 2π radians)
+    pub ra: Angle,
+    /// Declination (-π/2 to π/2 radians)
+    pub dec: Angle,
+    /// Radial distance from origin
+    pub distance: Length,
+}
+
+impl From<(Angle, Angle, Length)> for EquatorialCoords {
+    fn from((ra, dec, distance): (Angle, Angle, Length)) -> Self {
+        Self { ra, dec, distance }
+    }
+}
+
+impl From<EquatorialCoords> for (Angle, Angle, Length) {
+    fn from(c: EquatorialCoords) -> Self {
+        (c.ra, c.dec, c.distance)
+    }
+}
+
+/// Format a right ascension as sexagesimal hours, e.g. `12h34m56.70s`.
+///
+/// Seconds are rounded to 0.01 s (0.15″ at the equator) and the result is
+/// wrapped into `[0h, 24h)`, so negative angles and values that round up to
+/// 24h come out as the equivalent hour angle.
+#[must_use]
+pub fn format_ra_hms(ra: Angle) -> String {
+    use uom::si::angle::degree;
+
+    const CENTISECONDS_PER_DAY: i64 = 24 * 3600 * 100;
+    let total = (ra.get::<degree>() / 15.0 * 360_000.0).round() as i64;
+    let total = total.rem_euclid(CENTISECONDS_PER_DAY);
+
+    let (hours, rest) = (total / 360_000, total % 360_000);
+    let (minutes, centiseconds) = (rest / 6_000, rest % 6_000);
+    format!("{:02}h{:02}m{:02}.{:02}s", hours, minutes, centiseconds / 100, centiseconds % 100)
+}
+
+/// Format a declination as signed sexagesimal degrees, e.g. `-05d06m07.8s`.
+///
+/// Arcseconds are rounded to 0.1″. The sign is always written, so
+/// declinations between -1° and 0° keep their `-`.
+#[must_use]
+pub fn format_dec_dms(dec: Angle) -> String {
+    use uom::si::angle::degree;
+
+    let degrees = dec.get::<degree>();
+    let sign = if degrees < 0.0 { '-' } else { '+' };
+    let total = (degrees.abs() * 36_000.0).round() as i64;
+
+    let (whole, rest) = (total / 36_000, total % 36_000);
+    let (minutes, tenths) = (rest / 600, rest % 600);
+    format!("{}{:02}d{:02}m{:02}.{}s", sign, whole, minutes, tenths / 10, tenths % 10)
+}
+
+/// Parse a sexagesimal right ascension such as `12h34m56.7s`, `12:34:56.7`
+/// or `12 34 56.7`.
+///
+/// Minutes and seconds may be omitted from the right (`12h34m`, `12.5h`).
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if the text is not
+/// sexagesimal, carries a sign, or the hours are outside `[0, 24)`.
+pub fn parse_ra_hms(text: &str) -> CelestialResult<Angle> {
+    use uom::si::angle::degree;
+
+    let (negative, hours) = parse_sexagesimal(text, "right ascension")?;
+    if negative || !(0.0..24.0).contains(&hours) {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Right ascension {:?} is outside 0h to 24h", text),
+        });
+    }
+    Ok(Angle::new::<degree>(hours * 15.0))
+}
+
+/// Parse a sexagesimal declination such as `-05d06m07.8s`, `-05°06'07.8"`,
+/// `-05:06:07.8` or `+05 06 07.8`.
+///
+/// The sign applies to the whole value, so `-00d30m` is -0.5°.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if the text is not
+/// sexagesimal or the value is outside `[-90°, 90°]`.
+pub fn parse_dec_dms(text: &str) -> CelestialResult<Angle> {
+    use uom::si::angle::degree;
+
+    let (negative, degrees) = parse_sexagesimal(text, "declination")?;
+    if degrees > 90.0 {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Declination {:?} is outside -90° to 90°", text),
+        });
+    }
+    Ok(Angle::new::<degree>(if negative { -degrees } else { degrees }))
+}
+
+/// Split `[sign]a[sep]b[sep]c[sep]` into its sign and `a + b/60 + c/3600`.
+///
+/// Any run of characters other than digits and `.` separates the fields, so
+/// `h`/`d`/`°`, `m`/`'`, `s`/`"`, colons and spaces are all accepted.
+fn parse_sexagesimal(text: &str, name: &str) -> CelestialResult<(bool, f64)> {
+    let invalid = || CelestialError::InvalidCoordinates {
+        reason: format!("Invalid {}: {:?}", name, text),
+    };
+
+    let text = text.trim();
+    let (negative, body) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let fields = body
+        .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .filter(|field| !field.is_empty())
+        .map(|field| field.parse::<f64>().map_err(|_| invalid()))
+        .collect::<CelestialResult<Vec<f64>>>()?;
+
+    match fields[..] {
+        [whole] => Ok((negative, whole)),
+        [whole, minutes] if minutes < 60.0 => Ok((negative, whole + minutes / 60.0)),
+        [whole, minutes, seconds] if minutes < 60.0 && seconds < 60.0 => {
+            Ok((negative, whole + minutes / 60.0 + seconds / 3600.0))
+        }
+        _ => Err(invalid()),
+    }
+}
+
 /// International Celestial Reference System (ICRS).
 ///
 /// ICRS is the fundamental celestial reference frame adopted by the International
@@ -156,6 +330,105 @@ impl CoordinateSystem for Mci {
 
 // Coordinate<Icrs> methods moved to IcrsCoordinateExt trait in ext module
 
+/// Coordinate whose axes refer to the mean equator and equinox of a given epoch.
+///
+/// Positions referred to different equinoxes differ by precession (about
+/// 50 arcsec per year), which the type system alone cannot see. Operations
+/// that combine two dated coordinates, or apply a transform built for a
+/// given epoch, return [`CelestialError::InvalidCoordinates`] when the epochs
+/// differ. Bring coordinates to a common equinox first, e.g. with
+/// [`Dated::<Icrs>::precess_to`](Dated::precess_to).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dated<S: CoordinateSystem> {
+    /// The coordinate, in axes of the epoch below
+    coordinate: Coordinate<S>,
+    /// The equinox epoch the axes refer to
+    epoch: DateTime<Utc>,
+}
+
+impl<S: CoordinateSystem> Dated<S> {
+    /// Tag a coordinate with the equinox epoch of its axes.
+    #[must_use]
+    pub const fn new(coordinate: Coordinate<S>, epoch: DateTime<Utc>) -> Self {
+        Self { coordinate, epoch }
+    }
+
+    /// Get the coordinate.
+    #[must_use]
+    pub const fn coordinate(&self) -> &Coordinate<S> {
+        &self.coordinate
+    }
+
+    /// Get the equinox epoch.
+    #[must_use]
+    pub const fn epoch(&self) -> DateTime<Utc> {
+        self.epoch
+    }
+
+    /// Distance to another coordinate referred to the same equinox.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CelestialError::InvalidCoordinates`] if the epochs differ.
+    pub fn distance_from(&self, other: &Self) -> CelestialResult<Length> {
+        self.ensure_epoch(other.epoch)?;
+        Ok(self.coordinate.distance_from(&other.coordinate))
+    }
+
+    /// Apply a transform that is valid for `transform_epoch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CelestialError::InvalidCoordinates`] if `transform_epoch`
+    /// differs from this coordinate's epoch.
+    pub fn transform<To: CoordinateSystem>(
+        &self,
+        transform: &RigidBodyTransform<S, To>,
+        transform_epoch: DateTime<Utc>,
+    ) -> CelestialResult<Dated<To>> {
+        self.ensure_epoch(transform_epoch)?;
+        Ok(Dated::new(transform.transform(self.coordinate), self.epoch))
+    }
+
+    fn ensure_epoch(&self, epoch: DateTime<Utc>) -> CelestialResult<()> {
+        if self.epoch == epoch {
+            Ok(())
+        } else {
+            Err(CelestialError::InvalidCoordinates {
+                reason: format!(
+                    "equinox epochs differ ({} vs {}); precess to a common epoch first",
+                    self.epoch, epoch
+                ),
+            })
+        }
+    }
+}
+
+impl Dated<Icrs> {
+    /// Precess to the mean equator and equinox of `epoch`.
+    ///
+    /// Goes through J2000.0, where the precession series is referred, so
+    /// precessing there and back again returns the original coordinate.
+    #[must_use]
+    pub fn precess_to(&self, epoch: DateTime<Utc>) -> Self {
+        use crate::constants::{precession_between_epochs, utc_to_julian_date, J2000_JD};
+
+        let [x, y, z] = self.coordinate.to_cartesian().map(|c| c.get::<meter>());
+        let to_j2000 = precession_between_epochs(J2000_JD, utc_to_julian_date(self.epoch));
+        let from_j2000 = precession_between_epochs(J2000_JD, utc_to_julian_date(epoch)).inverse();
+        let p = (from_j2000 * to_j2000).transform_vector(&nalgebra::Vector3::new(x, y, z));
+
+        #[allow(deprecated)]
+        let coordinate = Coordinate::from_cartesian(
+            Length::new::<meter>(p.x),
+            Length::new::<meter>(p.y),
+            Length::new::<meter>(p.z),
+        );
+        Self::new(coordinate, epoch)
+    }
+}
+
 #[cfg(test)]
 mod celestial_coords_tests {
     use super::*;
@@ -164,12 +437,42 @@ mod celestial_coords_tests {
     use uom::si::f64::{Angle, Length};
     use uom::si::length::kilometer;
 
+    #[test]
+    fn dated_coordinates_with_different_equinoxes_are_rejected() {
+        use chrono::TimeZone;
+
+        let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let j2050 = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+        let star = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(101.287),
+            dec: Angle::new::<degree>(-16.716),
+            distance: Length::new::<kilometer>(1.0),
+        });
+
+        let catalog = Dated::new(star, j2000);
+        let observed = Dated::new(star, j2050);
+        assert!(matches!(
+            catalog.distance_from(&observed),
+            Err(CelestialError::InvalidCoordinates { .. })
+        ));
+        assert!(catalog.transform(&crate::transforms::icrs_to_mci(), j2050).is_err());
+        assert!(catalog.transform(&crate::transforms::icrs_to_mci(), j2000).is_ok());
+
+        // Precessing first makes them comparable: ~50"/yr over 50 years
+        let precessed = catalog.precess_to(j2050);
+        let shift = precessed.distance_from(&observed).unwrap().get::<kilometer>();
+        assert!(shift > 0.005 && shift < 0.015, "shift = {shift}");
+        let back = precessed.precess_to(j2000).distance_from(&catalog).unwrap();
+        assert!(back.get::<kilometer>() < 1e-12);
+    }
+
     #[test]
     fn ra_dec_roundtrip() {
         let ra_in = Angle::new::<degree>(45.0);
         let dec_in = Angle::new::<degree>(30.0);
         let dist_in = Length::new::<kilometer>(1000.0);
         
+        #[allow(deprecated)]
         let pos = sguaba::Coordinate::<Icrs>::from_ra_dec(ra_in, dec_in, dist_in);
         let (ra_out, dec_out, dist_out) = pos.to_spherical_celestial();
         
@@ -183,16 +486,89 @@ mod celestial_coords_tests {
         let dec = Angle::new::<degree>(90.0);
         let dist = Length::new::<kilometer>(1000.0);
         
-        let pos = sguaba::Coordinate::<Icrs>::from_ra_dec(
-            Angle::new::<degree>(0.0),
+        let pos = sguaba::Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(0.0),
             dec,
-            dist,
-        );
+            distance: dist,
+        });
         
         let [x, y, z] = pos.to_cartesian();
         assert!(x.get::<kilometer>().abs() < 0.001);
         assert!(y.get::<kilometer>().abs() < 0.001);
         assert!((z.get::<kilometer>() - 1000.0).abs() < 0.001);
     }
+
+    #[test]
+    fn named_and_tuple_ra_dec_agree() {
+        let pos = sguaba::Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(123.4),
+            dec: Angle::new::<degree>(-56.7),
+            distance: Length::new::<kilometer>(42_000.0),
+        });
+
+        let (ra, dec, dist) = pos.to_spherical_celestial();
+        let named = pos.to_spherical_celestial_named();
+
+        assert_eq!(named.ra, ra);
+        assert_eq!(named.dec, dec);
+        assert_eq!(named.distance, dist);
+
+        let rebuilt = sguaba::Coordinate::<Icrs>::from_equatorial(named);
+        assert!(rebuilt.distance_from(&pos).get::<kilometer>() < 1e-9);
+    }
+
+    #[test]
+    fn components_index_by_axis() {
+        let mut c = CelestialComponents {
+            x: Length::new::<kilometer>(1.0),
+            y: Length::new::<kilometer>(2.0),
+            z: Length::new::<kilometer>(3.0),
+        };
+
+        assert_eq!(c[0], c.x);
+        assert_eq!(c[1], c.y);
+        assert_eq!(c[2], c.z);
+
+        c[1] = Length::new::<kilometer>(-2.0);
+        assert_eq!(c.y, Length::new::<kilometer>(-2.0));
+        assert_eq!(c.iter().count(), 3);
+        assert_eq!(c.iter().nth(2), Some(&c.z));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of range: 3")]
+    fn components_index_3_panics() {
+        let c = CelestialComponents::from([0.0, 0.0, 0.0]);
+        let _ = c[3];
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::angle::degree;
+
+    #[test]
+    fn sexagesimal_roundtrips() {
+        let ra = parse_ra_hms("12h34m56.7s").unwrap();
+        assert!((ra.get::<degree>() - 15.0 * (12.0 + 34.0 / 60.0 + 56.7 / 3600.0)).abs() < 1e-12);
+        assert_eq!(format_ra_hms(ra), "12h34m56.70s");
+        assert_eq!(parse_ra_hms("12:34:56.70").unwrap(), ra);
+
+        let dec = parse_dec_dms("-05d06m07.8s").unwrap();
+        assert!((dec.get::<degree>() + 5.0 + 6.0 / 60.0 + 7.8 / 3600.0).abs() < 1e-12);
+        assert_eq!(format_dec_dms(dec), "-05d06m07.8s");
+        assert_eq!(parse_dec_dms("-05°06'07.8\"").unwrap(), dec);
+
+        // Sign of small negative declinations and RA wraparound
+        assert_eq!(format_dec_dms(parse_dec_dms("-00 30 00").unwrap()), "-00d30m00.0s");
+        assert_eq!(format_ra_hms(Angle::new::<degree>(-15.0)), "23h00m00.00s");
+        assert_eq!(format_ra_hms(Angle::new::<degree>(359.999_999_9)), "00h00m00.00s");
+
+        assert!(parse_ra_hms("24h00m00s").is_err());
+        assert!(parse_ra_hms("-01h00m00s").is_err());
+        assert!(parse_dec_dms("+91d").is_err());
+        assert!(parse_dec_dms("10d75m").is_err());
+        assert!(parse_dec_dms("north").is_err());
+    }
+}
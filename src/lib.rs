@@ -8,6 +8,7 @@
 //! - [`Gcrf`]: Geocentric Celestial Reference Frame (equivalent to ICRS)
 //! - [`Eme2000`]: Earth Mean Equator and Equinox of J2000
 //! - [`Ecliptic`]: Ecliptic coordinate system
+//! - [`EclipticOfDate`]: Ecliptic and equinox of date
 //!
 //! # Features
 //!
@@ -18,14 +19,15 @@
 //!
 //! Transform accuracies for the epoch range 2020-2050:
 //!
-//! - **ICRS ↔ ECEF**: < 30 milliarcseconds (mas) using IAU 2006/2000A precession + ERA
-//! - **ICRS ↔ ECEF (with nutation)**: < 1 mas using IAU 2000B nutation model
+//! - **ICRS ↔ ECEF**: up to ~20 arcsec without nutation (IAU 2006 precession + frame bias + ERA)
+//! - **ICRS ↔ ECEF (with nutation)**: ~0.1-0.2 arcsec using a truncated IAU 2000B nutation series
 //! - **MCI ↔ ICRS**: Arcsecond-level using IAU 2009 lunar orientation constants
 //!
 //! # Limitations
 //!
-//! - Polar motion not included (default implementation returns identity)
-//! - UT1-UTC correction not applied (UTC treated as UT1)
+//! - Polar motion and UT1-UTC are only applied when an [`EopProvider`] is
+//!   supplied (e.g. [`transforms::icrs_to_ecef_at_with_eop`]); otherwise UTC is
+//!   treated as UT1 and polar motion is ignored
 //! - Lunar libration not included in MCI frame
 //!
 //! # Coordinate System Selection
@@ -56,17 +58,17 @@
 //!
 //! ```no_run
 //! # #[cfg(feature = "celestial")] {
-//! use sguaba::{celestial::Icrs, Coordinate};
+//! use sguaba::{celestial::{EquatorialCoords, Icrs}, Coordinate};
 //! use uom::si::f64::{Angle, Length};
 //! use uom::si::angle::degree;
 //! use uom::si::length::kilometer;
 //!
 //! // Create from RA/Dec
-//! let pos = Coordinate::<Icrs>::from_ra_dec(
-//!     Angle::new::<degree>(45.0),
-//!     Angle::new::<degree>(30.0),
-//!     Length::new::<kilometer>(7000.0),
-//! );
+//! let pos = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+//!     ra: Angle::new::<degree>(45.0),
+//!     dec: Angle::new::<degree>(30.0),
+//!     distance: Length::new::<kilometer>(7000.0),
+//! });
 //!
 //! // Convert back to RA/Dec
 //! let (ra, dec, dist) = pos.to_spherical_celestial();
@@ -78,29 +80,43 @@ mod ext;
 pub use ext::*;
 
 mod rotation_helper;
+mod sgp4;
 
 pub mod additional_frames;
 pub mod astrodynamics;
 pub mod builder;
 pub mod cached;
 pub mod constants;
+pub mod eop;
 pub mod errors;
 pub mod frames;
+pub mod omm;
 pub mod orbital;
+pub mod solver;
 pub mod time_scales;
 pub mod timed;
 pub mod tle;
 pub mod transforms;
 
-pub use additional_frames::{Ecliptic, Eme2000, Gcrf};
-pub use astrodynamics::{AccelerationVector, AngularVelocityVector, SpecificAngularMomentum};
+pub use additional_frames::{
+    Ecliptic, EclipticOfDate, EclipticOrigin, Eme2000, Galactic, Gcrf, Obliquity, Observer, Topocentric,
+};
+pub use astrodynamics::{AccelerationVector, AngularVelocityVector, ShadowState, SpecificAngularMomentum};
 pub use cached::CachedTransform;
+pub use eop::{EopProvider, EopRecord, NullEop, TableEop};
 pub use errors::{CelestialError, CelestialResult};
-pub use frames::{CelestialComponents, CelestialConvention, Icrs, Mci};
-pub use orbital::KeplerianElements;
-pub use timed::{EphemerisState, TimedCoordinate, VelocityVector};
+pub use frames::{
+    format_dec_dms, format_ra_hms, parse_dec_dms, parse_ra_hms, CelestialComponents, CelestialConvention, Dated,
+    EquatorialCoords, Icrs, Mci,
+};
+pub use orbital::{KeplerianElements, Pass, TrackDirection};
+pub use solver::SolverConfig;
+pub use timed::{EphemerisState, EphemerisTable, TimedCoordinate, VelocityVector};
 pub use tle::TleElements;
 
 // Re-export commonly used time scale functions
-pub use time_scales::{utc_to_tai, utc_to_tdb, utc_to_tt, utc_to_ut1, validate_epoch};
+pub use time_scales::{
+    checked_add, gast, gmst, leap_seconds_at, leap_seconds_between, utc_to_tai, utc_to_tdb,
+    utc_to_tdb_at, utc_to_tt, utc_to_ut1, validate_epoch,
+};
 
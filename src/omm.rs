@@ -0,0 +1,250 @@
+//! CCSDS Orbit Mean-Elements Message (OMM) support.
+//!
+//! An OMM carries the same SGP4 mean elements as a TLE under named keys
+//! (`MEAN_MOTION`, `ECCENTRICITY`, `BSTAR`, ...) instead of fixed columns, so
+//! it has no five-digit catalog number limit. [`parse_kvn`] reads the
+//! keyword = value text form; with the `serde` feature, [`parse_json`] reads
+//! the JSON served by Space-Track and CelesTrak. Both produce
+//! [`TleElements`], ready for [`propagate_sgp4`](TleElements::propagate_sgp4).
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uom::si::angle::degree;
+use uom::si::f64::Angle;
+
+use crate::errors::{CelestialError, CelestialResult};
+use crate::tle::TleElements;
+
+/// Parse the KVN (keyword = value notation) form of an OMM.
+///
+/// Reads one message. `COMMENT` lines, blank lines and unit annotations such
+/// as `[rev/day]` are ignored.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if a required key
+/// (`NORAD_CAT_ID`, `EPOCH` and the six mean elements) is missing or a value
+/// does not parse, and `CelestialError::Unsupported` if `MEAN_ELEMENT_THEORY`
+/// names a theory other than SGP4.
+pub fn parse_kvn(text: &str) -> CelestialResult<TleElements> {
+    let mut fields = HashMap::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with("COMMENT") {
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or_else(|| CelestialError::InvalidCoordinates {
+            reason: format!("Invalid OMM line: {:?}", line),
+        })?;
+        let value = value.split_once('[').map_or(value, |(value, _units)| value);
+        fields.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    elements_from_fields(&fields)
+}
+
+/// Parse the JSON form of an OMM.
+///
+/// Accepts a single message object or an array of them, as returned by
+/// Space-Track and CelesTrak queries. Values may be JSON numbers or numeric
+/// strings.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if the JSON is malformed or
+/// any message fails as described for [`parse_kvn`].
+#[cfg(feature = "serde")]
+pub fn parse_json(json: &str) -> CelestialResult<Vec<TleElements>> {
+    use serde_json::Value;
+
+    let invalid = |reason: String| CelestialError::InvalidCoordinates { reason };
+    let value: Value = serde_json::from_str(json).map_err(|e| invalid(format!("Invalid OMM JSON: {}", e)))?;
+    let messages = match value {
+        Value::Array(messages) => messages,
+        message => vec![message],
+    };
+
+    messages
+        .into_iter()
+        .map(|message| {
+            let Value::Object(object) = message else {
+                return Err(invalid("OMM JSON message must be an object".to_string()));
+            };
+            let fields = object
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    Value::String(s) => Some((key, s)),
+                    Value::Number(n) => Some((key, n.to_string())),
+                    _ => None,
+                })
+                .collect();
+            elements_from_fields(&fields)
+        })
+        .collect()
+}
+
+/// Build elements from OMM keys, shared by the KVN and JSON readers.
+fn elements_from_fields(fields: &HashMap<String, String>) -> CelestialResult<TleElements> {
+    let get = |key: &str| fields.get(key).map(String::as_str).filter(|v| !v.is_empty());
+    let required = |key: &str| {
+        get(key).ok_or_else(|| CelestialError::InvalidCoordinates {
+            reason: format!("Missing OMM key {}", key),
+        })
+    };
+    let number = |key: &str, value: &str| {
+        value.parse::<f64>().map_err(|_| CelestialError::InvalidCoordinates {
+            reason: format!("Invalid OMM value for {}: {:?}", key, value),
+        })
+    };
+    let required_number = |key: &str| required(key).and_then(|value| number(key, value));
+    let optional_number = |key: &str| get(key).map_or(Ok(0.0), |value| number(key, value));
+    let integer = |key: &str, value: &str| {
+        value.parse::<u32>().map_err(|_| CelestialError::InvalidCoordinates {
+            reason: format!("Invalid OMM value for {}: {:?}", key, value),
+        })
+    };
+    let counter = |key: &str| get(key).map_or(Ok(0), |value| integer(key, value));
+
+    if let Some(theory) = get("MEAN_ELEMENT_THEORY") {
+        if !theory.eq_ignore_ascii_case("SGP4") {
+            return Err(CelestialError::Unsupported {
+                feature: format!("OMM mean element theory {:?} (only SGP4 elements are read)", theory),
+            });
+        }
+    }
+
+    let ephemeris_type = counter("EPHEMERIS_TYPE")?;
+
+    Ok(TleElements {
+        name: get("OBJECT_NAME").map(str::to_string),
+        catalog_number: required("NORAD_CAT_ID").and_then(|value| integer("NORAD_CAT_ID", value))?,
+        epoch: parse_epoch(required("EPOCH")?)?,
+        inclination: Angle::new::<degree>(required_number("INCLINATION")?),
+        raan: Angle::new::<degree>(required_number("RA_OF_ASC_NODE")?),
+        eccentricity: required_number("ECCENTRICITY")?,
+        arg_perigee: Angle::new::<degree>(required_number("ARG_OF_PERICENTER")?),
+        mean_anomaly: Angle::new::<degree>(required_number("MEAN_ANOMALY")?),
+        mean_motion: required_number("MEAN_MOTION")?,
+        classification: get("CLASSIFICATION_TYPE").and_then(|c| c.chars().next()).unwrap_or('U'),
+        intl_designator: get("OBJECT_ID").map_or_else(String::new, tle_designator),
+        ephemeris_type: u8::try_from(ephemeris_type).map_err(|_| CelestialError::InvalidCoordinates {
+            reason: format!("Invalid OMM value for EPHEMERIS_TYPE: {}", ephemeris_type),
+        })?,
+        ndot: optional_number("MEAN_MOTION_DOT")?,
+        nddot: optional_number("MEAN_MOTION_DDOT")?,
+        bstar: optional_number("BSTAR")?,
+        element_set_number: counter("ELEMENT_SET_NO")?,
+        rev_number: counter("REV_AT_EPOCH")?,
+    })
+}
+
+/// Parse an OMM epoch such as `2020-07-24T04:26:58.214400`, which is UTC.
+fn parse_epoch(value: &str) -> CelestialResult<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|naive| naive.and_utc())
+        .map_err(|_| CelestialError::InvalidCoordinates {
+            reason: format!("Invalid OMM EPOCH: {:?}", value),
+        })
+}
+
+/// Convert a COSPAR ID (`1998-067A`) to the TLE designator form (`98067A`).
+fn tle_designator(object_id: &str) -> String {
+    match object_id.split_once('-') {
+        Some((year, rest)) if year.len() == 4 => format!("{}{}", &year[2..], rest),
+        _ => object_id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISS_LINE1: &str = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+    const ISS_LINE2: &str = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+    fn assert_matches_iss_tle(elements: &TleElements) {
+        let tle = TleElements::from_3lines("ISS (ZARYA)", ISS_LINE1, ISS_LINE2).unwrap();
+        assert_eq!(elements.name(), tle.name());
+        assert_eq!(elements.catalog_number(), tle.catalog_number());
+        assert_eq!(elements.intl_designator(), tle.intl_designator());
+        assert_eq!(elements.element_set_number(), tle.element_set_number());
+        assert_eq!(elements.rev_number(), tle.rev_number());
+        assert!((elements.epoch() - tle.epoch()).num_microseconds().unwrap().abs() <= 1);
+        assert!((elements.inclination().get::<degree>() - 51.6461).abs() < 1e-12);
+        assert!((elements.eccentricity() - tle.eccentricity()).abs() < 1e-15);
+        assert!((elements.bstar() - tle.bstar()).abs() < 1e-15);
+        assert!((elements.ndot() - tle.ndot()).abs() < 1e-15);
+        assert_eq!(elements.to_lines().unwrap(), tle.to_lines().unwrap());
+    }
+
+    #[test]
+    fn kvn_matches_equivalent_tle() {
+        let kvn = "CCSDS_OMM_VERS = 2.0
+COMMENT Generated from the ISS TLE
+CREATION_DATE = 2020-07-24T10:00:00
+ORIGINATOR = 18 SPCS
+OBJECT_NAME = ISS (ZARYA)
+OBJECT_ID = 1998-067A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP4
+EPOCH = 2020-07-24T04:26:58.214400
+MEAN_MOTION = 15.49309432 [rev/day]
+ECCENTRICITY = 0.0001473
+INCLINATION = 51.6461 [deg]
+RA_OF_ASC_NODE = 339.8014 [deg]
+ARG_OF_PERICENTER = 94.8340 [deg]
+MEAN_ANOMALY = 265.2864 [deg]
+EPHEMERIS_TYPE = 0
+CLASSIFICATION_TYPE = U
+NORAD_CAT_ID = 25544
+ELEMENT_SET_NO = 999
+REV_AT_EPOCH = 23600
+BSTAR = 0.33518e-4 [1/ER]
+MEAN_MOTION_DOT = 0.00001406 [rev/day**2]
+MEAN_MOTION_DDOT = 0 [rev/day**3]
+";
+        assert_matches_iss_tle(&parse_kvn(kvn).unwrap());
+
+        let missing = kvn.replace("MEAN_MOTION = 15.49309432 [rev/day]\n", "");
+        assert!(matches!(
+            parse_kvn(&missing),
+            Err(CelestialError::InvalidCoordinates { reason }) if reason.contains("MEAN_MOTION")
+        ));
+
+        let other_theory = kvn.replace("MEAN_ELEMENT_THEORY = SGP4", "MEAN_ELEMENT_THEORY = DSST");
+        assert!(matches!(parse_kvn(&other_theory), Err(CelestialError::Unsupported { .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_matches_equivalent_tle() {
+        // Space-Track quotes every value; CelesTrak uses JSON numbers
+        let json = r#"[{
+            "OBJECT_NAME": "ISS (ZARYA)", "OBJECT_ID": "1998-067A",
+            "EPOCH": "2020-07-24T04:26:58.214400", "MEAN_MOTION": "15.49309432",
+            "ECCENTRICITY": "0.0001473", "INCLINATION": "51.6461",
+            "RA_OF_ASC_NODE": "339.8014", "ARG_OF_PERICENTER": "94.8340",
+            "MEAN_ANOMALY": "265.2864", "EPHEMERIS_TYPE": "0",
+            "CLASSIFICATION_TYPE": "U", "NORAD_CAT_ID": "25544",
+            "ELEMENT_SET_NO": "999", "REV_AT_EPOCH": "23600", "BSTAR": "0.33518e-4",
+            "MEAN_MOTION_DOT": "0.00001406", "MEAN_MOTION_DDOT": "0",
+            "DECAY_DATE": null
+        }, {
+            "OBJECT_NAME": "ISS (ZARYA)", "OBJECT_ID": "1998-067A",
+            "EPOCH": "2020-07-24T04:26:58.214400", "MEAN_MOTION": 15.49309432,
+            "ECCENTRICITY": 0.0001473, "INCLINATION": 51.6461,
+            "RA_OF_ASC_NODE": 339.8014, "ARG_OF_PERICENTER": 94.834,
+            "MEAN_ANOMALY": 265.2864, "EPHEMERIS_TYPE": 0,
+            "CLASSIFICATION_TYPE": "U", "NORAD_CAT_ID": 25544,
+            "ELEMENT_SET_NO": 999, "REV_AT_EPOCH": 23600, "BSTAR": 3.3518e-5,
+            "MEAN_MOTION_DOT": 1.406e-5, "MEAN_MOTION_DDOT": 0
+        }]"#;
+
+        let elements = parse_json(json).unwrap();
+        assert_eq!(elements.len(), 2);
+        elements.iter().for_each(assert_matches_iss_tle);
+
+        assert!(parse_json("{\"EPOCH\": ").is_err());
+    }
+}
@@ -5,13 +5,17 @@
 
 use chrono::{DateTime, Utc};
 use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
+use uom::si::f64::{Angle, Length, Time};
 
 use sguaba::Coordinate;
 
 #[allow(unused_imports)]
 use super::constants::{MU_EARTH, J2000_JD, utc_to_julian_date};
+use super::errors::{CelestialError, CelestialResult};
 use super::frames::Icrs;
+use super::solver::SolverConfig;
+use super::time_scales::checked_add_seconds;
+use super::timed::{velocity_components, velocity_from_components, EphemerisState, TimedCoordinate};
 
 /// Keplerian orbital elements.
 ///
@@ -54,6 +58,19 @@ pub struct KeplerianElements {
     pub mu: f64,
 }
 
+/// Direction of the sub-satellite point in latitude at the current position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackDirection {
+    /// Moving north (argument of latitude in (-90°, 90°)).
+    Ascending,
+    /// Moving south (argument of latitude in (90°, 270°)).
+    Descending,
+    /// Equatorial orbit, or exactly at the northern/southern extreme of the
+    /// ground track; latitude is not changing.
+    Stationary,
+}
+
 impl Default for KeplerianElements {
     fn default() -> Self {
         Self {
@@ -97,13 +114,111 @@ impl KeplerianElements {
         self
     }
 
+    /// Circular orbit at a given altitude above a spherical body.
+    ///
+    /// RAAN, argument of periapsis and true anomaly are zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `altitude` - Height above `body_radius`
+    /// * `inclination` - Orbit inclination
+    /// * `body_radius` - Radius of the central body
+    /// * `mu` - Gravitational parameter of the central body (m³/s²)
+    #[must_use]
+    pub fn circular_at_altitude(altitude: Length, inclination: Angle, body_radius: Length, mu: f64) -> Self {
+        Self {
+            semi_major_axis: body_radius + altitude,
+            eccentricity: 0.0,
+            inclination,
+            ..Self::default()
+        }
+        .with_mu(mu)
+    }
+
+    /// Orbital period `2π·√(a³/μ)`.
+    #[must_use]
+    pub fn period(&self) -> Time {
+        use uom::si::length::meter;
+        use uom::si::time::second;
+
+        let a = self.semi_major_axis.get::<meter>();
+        Time::new::<second>(std::f64::consts::TAU * (a.powi(3) / self.mu).sqrt())
+    }
+
+    /// Apoapsis radius `a(1 + e)`, measured from the center of the body.
+    #[must_use]
+    pub fn apoapsis_radius(&self) -> Length {
+        self.semi_major_axis * (1.0 + self.eccentricity)
+    }
+
+    /// Periapsis radius `a(1 - e)`, measured from the center of the body.
+    #[must_use]
+    pub fn periapsis_radius(&self) -> Length {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    /// Whether the orbit escapes the central body (specific energy `-μ/2a ≥ 0`).
+    ///
+    /// Hyperbolic elements carry a negative semi-major axis, as produced by
+    /// [`from_state_vectors`](Self::from_state_vectors).
+    #[must_use]
+    pub fn is_escape(&self) -> bool {
+        self.characteristic_energy() >= 0.0
+    }
+
+    /// Characteristic energy C3 = `-μ/a` (m²/s²).
+    ///
+    /// Twice the specific orbital energy. For hyperbolic orbits this is the
+    /// hyperbolic excess speed squared, `v∞²`, as quoted by launch providers
+    /// for escape missions; bound orbits give a negative value.
+    #[must_use]
+    pub fn characteristic_energy(&self) -> f64 {
+        use uom::si::length::meter;
+
+        -self.mu / self.semi_major_axis.get::<meter>()
+    }
+
+    /// Whether the orbit is retrograde (inclination above 90°).
+    ///
+    /// Retrograde orbits, including sun-synchronous ones, cross the equator
+    /// heading west, so their ascending passes run north-west rather than
+    /// north-east.
+    #[must_use]
+    pub fn is_retrograde(&self) -> bool {
+        self.inclination.get::<radian>() > std::f64::consts::FRAC_PI_2
+    }
+
+    /// Whether the satellite is currently on the ascending or descending half
+    /// of its ground track.
+    ///
+    /// Determined from the argument of latitude u = ω + ν: latitude follows
+    /// sin(i)·sin(u), so it is increasing while cos(u) > 0 for any inclination
+    /// strictly between 0° and 180°.
+    #[must_use]
+    pub fn ground_track_direction(&self) -> TrackDirection {
+        const EPS: f64 = 1e-9;
+
+        let sin_i = self.inclination.get::<radian>().sin();
+        let cos_u = (self.argument_of_periapsis + self.true_anomaly).get::<radian>().cos();
+        if sin_i.abs() < EPS || cos_u.abs() < EPS {
+            TrackDirection::Stationary
+        } else if cos_u > 0.0 {
+            TrackDirection::Ascending
+        } else {
+            TrackDirection::Descending
+        }
+    }
+
     /// Convert to position and velocity in ICRS frame.
     ///
     /// Uses the classical orbital elements to compute Cartesian state vectors.
     ///
     /// # Returns
     ///
-    /// `(position, velocity)` tuple in ICRS frame.
+    /// `(position, velocity)` tuple in ICRS frame, with velocity in m/s.
+    ///
+    /// Prefer [`to_ephemeris_state`](Self::to_ephemeris_state), which keeps
+    /// the velocity unit-typed; this method is planned for deprecation.
     #[must_use]
     pub fn to_state_vectors(&self) -> (Coordinate<Icrs>, [f64; 3]) {
         use uom::si::length::meter;
@@ -170,47 +285,868 @@ impl KeplerianElements {
         (position, velocity)
     }
 
+    /// Convert to a time-tagged ICRS state at `epoch`.
+    ///
+    /// Same position and velocity as [`to_state_vectors`](Self::to_state_vectors),
+    /// with the velocity as a [`VelocityVector`](crate::VelocityVector).
+    #[must_use]
+    pub fn to_ephemeris_state(&self, epoch: DateTime<Utc>) -> EphemerisState<Icrs> {
+        let (position, velocity) = self.to_state_vectors();
+        EphemerisState::new(position, velocity_from_components(velocity), epoch)
+    }
+
+    /// Compute osculating elements from a Cartesian state.
+    ///
+    /// Uses the specific angular momentum `h = r × v`, the node vector
+    /// `n = ẑ × h` and the eccentricity vector to recover the classical elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Position relative to the central body
+    /// * `velocity` - Velocity in m/s
+    /// * `mu` - Gravitational parameter (m³/s²)
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::NumericalPrecisionError` for degenerate states
+    /// (zero position or angular momentum).
+    ///
+    /// # Degenerate orbits
+    ///
+    /// Where an angle is undefined it is set to zero and the next angle is
+    /// measured from the reference direction instead:
+    ///
+    /// - Equatorial (i ≈ 0° or 180°): RAAN = 0 and the argument of periapsis
+    ///   is the longitude of periapsis, measured from the ICRS x-axis
+    /// - Circular (e ≈ 0): argument of periapsis = 0 and the true anomaly is
+    ///   the argument of latitude, measured from the ascending node
+    /// - Circular equatorial: both are zero and the true anomaly is the true
+    ///   longitude
+    ///
+    /// so that [`to_state_vectors`](Self::to_state_vectors) reproduces the state.
+    pub fn from_state_vectors(position: Coordinate<Icrs>, velocity: [f64; 3], mu: f64) -> CelestialResult<Self> {
+        use nalgebra::Vector3;
+        use uom::si::length::meter;
+
+        /// Below this eccentricity or relative node magnitude, the angle is undefined.
+        const DEGENERATE: f64 = 1e-11;
+
+        let r = Vector3::from(position.to_cartesian().map(|c| c.get::<meter>()));
+        let v = Vector3::from(velocity);
+        let h = r.cross(&v);
+
+        if r.norm() == 0.0 || h.norm() == 0.0 {
+            return Err(CelestialError::NumericalPrecisionError {
+                reason: "state has zero position or angular momentum".to_string(),
+            });
+        }
+
+        let node = Vector3::z().cross(&h);
+        let e_vec = v.cross(&h) / mu - r.normalize();
+        let e = e_vec.norm();
+        let energy = 0.5 * v.norm_squared() - mu / r.norm();
+
+        let equatorial = node.norm() < DEGENERATE * h.norm();
+        let circular = e < DEGENERATE;
+
+        // Angles are measured in the direction of motion, about h
+        let h_hat = h.normalize();
+        let wrap = |angle: f64| angle.rem_euclid(std::f64::consts::TAU);
+        let angle_from = |from: &Vector3<f64>, to: &Vector3<f64>| wrap(h_hat.dot(&from.cross(to)).atan2(from.dot(to)));
+
+        // Fall back to the x-axis for the node and to the node for periapsis
+        let node_dir = if equatorial { Vector3::x() } else { node.normalize() };
+        let periapsis_dir = if circular { node_dir } else { e_vec / e };
+
+        let inclination = (h.z / h.norm()).clamp(-1.0, 1.0).acos();
+        let raan = if equatorial { 0.0 } else { wrap(node.y.atan2(node.x)) };
+        let omega = angle_from(&node_dir, &periapsis_dir);
+        let nu = angle_from(&periapsis_dir, &r);
+
+        Ok(Self {
+            semi_major_axis: Length::new::<meter>(-mu / (2.0 * energy)),
+            eccentricity: e,
+            inclination: Angle::new::<radian>(inclination),
+            raan: Angle::new::<radian>(raan),
+            argument_of_periapsis: Angle::new::<radian>(omega),
+            true_anomaly: Angle::new::<radian>(nu),
+            mu,
+        })
+    }
+
     /// Propagate orbit to a new epoch using simple Keplerian motion.
     ///
     /// # Note
     ///
     /// This uses two-body dynamics only (no perturbations). For accurate
     /// long-term propagation, use a numerical integrator with perturbation models.
+    ///
+    /// Never panics: for eccentricities outside `[0, 1)` the resulting true
+    /// anomaly is NaN, and if the Kepler solver does not converge the last
+    /// iterate is used. Use [`try_propagate_to`](Self::try_propagate_to) to
+    /// detect either case.
     #[must_use]
     pub fn propagate_to(&self, target_epoch: DateTime<Utc>, current_epoch: DateTime<Utc>) -> Self {
+        self.propagate_to_with_config(target_epoch, current_epoch, None)
+    }
+
+    /// Propagate to `target_epoch` and return the time-tagged position there.
+    ///
+    /// Shorthand for [`propagate_to`](Self::propagate_to) followed by
+    /// [`to_state_vectors`](Self::to_state_vectors), keeping only the position.
+    #[must_use]
+    pub fn propagate_to_timed(
+        &self,
+        target_epoch: DateTime<Utc>,
+        current_epoch: DateTime<Utc>,
+    ) -> TimedCoordinate<Icrs> {
+        let (position, _) = self.propagate_to(target_epoch, current_epoch).to_state_vectors();
+        TimedCoordinate::new(position, target_epoch)
+    }
+
+    /// Propagate orbit to a new epoch with explicit Kepler solver settings.
+    ///
+    /// Identical to [`propagate_to`](Self::propagate_to), but lets
+    /// precision-critical callers tighten (or loosen) the convergence of the
+    /// Kepler equation solver. `None` uses [`SolverConfig::default`].
+    #[must_use]
+    pub fn propagate_to_with_config(
+        &self,
+        target_epoch: DateTime<Utc>,
+        current_epoch: DateTime<Utc>,
+        config: Option<SolverConfig>,
+    ) -> Self {
+        self.propagate_by(seconds_between(current_epoch, target_epoch), config)
+    }
+
+    /// Fallible form of [`propagate_to`](Self::propagate_to).
+    ///
+    /// # Errors
+    ///
+    /// - [`CelestialError::InvalidCoordinates`] for a negative or NaN eccentricity
+    /// - [`CelestialError::Unsupported`] for parabolic and hyperbolic orbits (`e ≥ 1`)
+    /// - [`CelestialError::NumericalPrecisionError`] if the Kepler solver does
+    ///   not converge within [`SolverConfig::default`]
+    pub fn try_propagate_to(&self, target_epoch: DateTime<Utc>, current_epoch: DateTime<Utc>) -> CelestialResult<Self> {
+        self.try_propagate_to_with_config(target_epoch, current_epoch, None)
+    }
+
+    /// Fallible form of [`propagate_to_with_config`](Self::propagate_to_with_config).
+    ///
+    /// # Errors
+    ///
+    /// As [`try_propagate_to`](Self::try_propagate_to), with convergence
+    /// judged against `config`.
+    pub fn try_propagate_to_with_config(
+        &self,
+        target_epoch: DateTime<Utc>,
+        current_epoch: DateTime<Utc>,
+        config: Option<SolverConfig>,
+    ) -> CelestialResult<Self> {
+        let e = self.eccentricity;
+        if e >= 1.0 {
+            return Err(CelestialError::Unsupported {
+                feature: format!("Keplerian propagation of open orbits (e = {e})"),
+            });
+        }
+        if !(0.0..1.0).contains(&e) {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: format!("eccentricity must be non-negative, got e = {e}"),
+            });
+        }
+
+        let (propagated, converged) = self.propagate_by_checked(seconds_between(current_epoch, target_epoch), config);
+        if converged {
+            Ok(propagated)
+        } else {
+            let config = config.unwrap_or_default();
+            Err(CelestialError::NumericalPrecisionError {
+                reason: format!(
+                    "Kepler equation did not converge to {:e} within {} iterations (e = {e})",
+                    config.rel_tol, config.max_iter
+                ),
+            })
+        }
+    }
+
+    /// Propagate the orbit forward by `dt` seconds using two-body motion.
+    fn propagate_by(&self, dt: f64, config: Option<SolverConfig>) -> Self {
+        self.propagate_by_checked(dt, config).0
+    }
+
+    /// [`propagate_by`](Self::propagate_by), also reporting whether the
+    /// Kepler solver converged.
+    fn propagate_by_checked(&self, dt: f64, config: Option<SolverConfig>) -> (Self, bool) {
         use uom::si::length::meter;
-        
-        let dt = (utc_to_julian_date(target_epoch) - utc_to_julian_date(current_epoch)) * 86400.0; // seconds
-        
+
         let a = self.semi_major_axis.get::<meter>();
         let n = (self.mu / a.powi(3)).sqrt(); // Mean motion (rad/s)
         
         // Mean anomaly change
         let delta_m = n * dt;
         
-        // Current mean anomaly (simplified from true anomaly)
+        // Current mean anomaly
         let e = self.eccentricity;
-        let nu = self.true_anomaly.get::<radian>();
-        let ecc_anomaly = 2.0 * ((nu / 2.0).tan() / ((1.0 + e) / (1.0 - e)).sqrt()).atan();
+        let ecc_anomaly = true_to_eccentric(self.true_anomaly.get::<radian>(), e);
         let mean_anomaly = ecc_anomaly - e * ecc_anomaly.sin();
         
         // New mean anomaly
         let new_mean_anomaly = mean_anomaly + delta_m;
         
-        // Solve Kepler's equation for new eccentric anomaly (Newton-Raphson)
-        let mut e_anom = new_mean_anomaly;
-        for _ in 0..10 {
-            e_anom = e_anom - (e_anom - e * e_anom.sin() - new_mean_anomaly) / (1.0 - e * e_anom.cos());
-        }
+        // Solve Kepler's equation for the new eccentric anomaly
+        let (e_anom, converged) = iterate_kepler(new_mean_anomaly, e, config.unwrap_or_default());
         
         // New true anomaly
-        let new_nu = 2.0 * (((1.0 + e) / (1.0 - e)).sqrt() * (e_anom / 2.0).tan()).atan();
+        let new_nu = eccentric_to_true(e_anom, e);
         
-        Self {
+        let propagated = Self {
             true_anomaly: Angle::new::<radian>(new_nu),
             ..*self
+        };
+        (propagated, converged)
+    }
+}
+
+/// Unit-explicit JSON form of [`KeplerianElements`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeplerianElementsJson {
+    frame: String,
+    semi_major_axis_km: f64,
+    eccentricity: f64,
+    inclination_deg: f64,
+    raan_deg: f64,
+    argument_of_periapsis_deg: f64,
+    true_anomaly_deg: f64,
+    mu_m3_s2: f64,
+}
+
+#[cfg(feature = "serde")]
+impl KeplerianElements {
+    /// Serialize to a self-describing JSON object with explicit units.
+    ///
+    /// Unlike the derived `Serialize` impl, which exposes `uom`'s internal SI
+    /// values (meters, radians), the keys carry their units
+    /// (`semi_major_axis_km`, `inclination_deg`, ...) and a `frame` tag records
+    /// that the elements are referred to ICRS.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let json = KeplerianElementsJson {
+            frame: "ICRS".to_string(),
+            semi_major_axis_km: self.semi_major_axis.get::<kilometer>(),
+            eccentricity: self.eccentricity,
+            inclination_deg: self.inclination.get::<degree>(),
+            raan_deg: self.raan.get::<degree>(),
+            argument_of_periapsis_deg: self.argument_of_periapsis.get::<degree>(),
+            true_anomaly_deg: self.true_anomaly.get::<degree>(),
+            mu_m3_s2: self.mu,
+        };
+        serde_json::to_string(&json).expect("plain numeric struct always serializes")
+    }
+
+    /// Parse the JSON produced by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` if the JSON is malformed or
+    /// a field is missing, and `CelestialError::Unsupported` if the `frame` tag
+    /// is not `ICRS`.
+    pub fn from_json(json: &str) -> CelestialResult<Self> {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let parsed: KeplerianElementsJson =
+            serde_json::from_str(json).map_err(|e| CelestialError::InvalidCoordinates {
+                reason: format!("Invalid Keplerian elements JSON: {}", e),
+            })?;
+        if parsed.frame != "ICRS" {
+            return Err(CelestialError::Unsupported {
+                feature: format!("element frame {:?} (only \"ICRS\" is read)", parsed.frame),
+            });
+        }
+
+        Ok(Self {
+            semi_major_axis: Length::new::<kilometer>(parsed.semi_major_axis_km),
+            eccentricity: parsed.eccentricity,
+            inclination: Angle::new::<degree>(parsed.inclination_deg),
+            raan: Angle::new::<degree>(parsed.raan_deg),
+            argument_of_periapsis: Angle::new::<degree>(parsed.argument_of_periapsis_deg),
+            true_anomaly: Angle::new::<degree>(parsed.true_anomaly_deg),
+            mu: parsed.mu_m3_s2,
+        })
+    }
+}
+
+/// Find the time of closest approach (TCA) and miss distance between two orbits.
+///
+/// Both element sets are taken to be valid at `epoch`. The relative distance is
+/// minimized over `bracket` with a golden-section search, so the bracket should
+/// contain a single minimum (as produced by a coarse conjunction screening).
+///
+/// # Arguments
+///
+/// * `a` - Elements of the first object
+/// * `b` - Elements of the second object
+/// * `epoch` - Epoch at which both element sets are valid
+/// * `bracket` - `(start, end)` search window containing the approach
+///
+/// # Returns
+///
+/// `(tca, miss_distance)` tuple.
+#[must_use]
+pub fn closest_approach(
+    a: &KeplerianElements,
+    b: &KeplerianElements,
+    epoch: DateTime<Utc>,
+    bracket: (DateTime<Utc>, DateTime<Utc>),
+) -> (DateTime<Utc>, Length) {
+    use uom::si::length::meter;
+
+    /// Convergence tolerance on the TCA (seconds).
+    const TIME_TOLERANCE: f64 = 1e-3;
+
+    let (start, end) = bracket;
+    let offset = seconds_between(epoch, start);
+
+    let separation = |t: f64| {
+        let (pos_a, _) = a.propagate_by(offset + t, None).to_state_vectors();
+        let (pos_b, _) = b.propagate_by(offset + t, None).to_state_vectors();
+        pos_a.distance_from(&pos_b).get::<meter>()
+    };
+
+    // Search over [0, width] seconds from the bracket start
+    let t = golden_section_minimum(separation, 0.0, seconds_between(start, end).max(0.0), TIME_TOLERANCE);
+    let tca = start + chrono::Duration::microseconds((t * 1e6).round() as i64);
+
+    (tca, Length::new::<meter>(separation(t)))
+}
+
+/// Minimizer of a unimodal `f` on `[lo, hi]` by golden-section search, to `tolerance`.
+fn golden_section_minimum(f: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64, tolerance: f64) -> f64 {
+    let inv_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut x1 = hi - inv_phi * (hi - lo);
+    let mut x2 = lo + inv_phi * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+
+    while hi - lo > tolerance {
+        if f1 < f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - inv_phi * (hi - lo);
+            f1 = f(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + inv_phi * (hi - lo);
+            f2 = f(x2);
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+/// Osculating elements along an ephemeris.
+///
+/// Converts each state with [`KeplerianElements::from_state_vectors`], which
+/// exposes secular drift and periodic oscillation in perturbed trajectories.
+///
+/// # Errors
+///
+/// Returns the error for the first state whose elements are undefined (see
+/// [`from_state_vectors`](KeplerianElements::from_state_vectors)), e.g.
+/// `CelestialError::NumericalPrecisionError` for a purely radial state.
+pub fn element_history(
+    states: &[EphemerisState<Icrs>],
+    mu: f64,
+) -> CelestialResult<Vec<(DateTime<Utc>, KeplerianElements)>> {
+    states
+        .iter()
+        .map(|state| {
+            let velocity = velocity_components(state.velocity());
+            let elements = KeplerianElements::from_state_vectors(*state.position(), velocity, mu)?;
+            Ok((state.epoch(), elements))
+        })
+        .collect()
+}
+
+/// Sub-satellite track: geodetic latitude and longitude over time.
+///
+/// Propagates `elements` (valid at `epoch`) with two-body motion to every
+/// `step` from `start` through `end` inclusive, rotates each position to ECEF
+/// at its own time and converts it to WGS84 geodetic coordinates. Longitudes
+/// are in (-π, π]. Returns an empty track when `step` is not positive or
+/// `end` precedes `start`.
+#[must_use]
+pub fn ground_track(
+    elements: &KeplerianElements,
+    epoch: DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: chrono::Duration,
+) -> Vec<(DateTime<Utc>, Angle, Angle)> {
+    let mut track = Vec::new();
+    if step <= chrono::Duration::zero() {
+        return track;
+    }
+
+    let mut time = start;
+    while time <= end {
+        let (position, _) = elements.propagate_to(time, epoch).to_state_vectors();
+        let ecef = crate::transforms::icrs_to_ecef_at(time).transform(position);
+        let (latitude, longitude, _) = crate::ext::ecef_to_geodetic(&ecef);
+        track.push((time, latitude, longitude));
+
+        match time.checked_add_signed(step) {
+            Some(next) => time = next,
+            None => break,
+        }
+    }
+    track
+}
+
+/// One pass of a satellite over a ground station.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pass {
+    /// Acquisition of signal: the satellite rises above the elevation mask
+    pub aos: DateTime<Utc>,
+    /// Loss of signal: the satellite sets below the elevation mask
+    pub los: DateTime<Utc>,
+    /// Highest elevation reached during the pass
+    pub max_elevation: Angle,
+    /// Time of the highest elevation
+    pub max_elevation_time: DateTime<Utc>,
+}
+
+/// Predict passes of a satellite over a ground station in a time window.
+///
+/// Propagates `elements` (valid at `epoch`) with two-body motion, sampling
+/// the elevation from [`elevation_azimuth`](crate::elevation_azimuth) every
+/// 30 s from `start` to `end`. Crossings of `min_elevation` are refined by
+/// bisection and the culmination by golden-section search, both to 1 ms.
+/// Passes already in progress at `start` or still in progress at `end` are
+/// cut at the window edges. Passes shorter than the 30 s sampling step can
+/// be missed. A window whose length cannot be added to `start` without
+/// leaving chrono's date range yields no passes.
+#[must_use]
+pub fn predict_passes(
+    observer: &crate::Observer,
+    elements: &KeplerianElements,
+    epoch: DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    min_elevation: Angle,
+) -> Vec<Pass> {
+    /// Coarse sampling step (seconds), well under a LEO pass length.
+    const STEP: f64 = 30.0;
+    /// Convergence tolerance on AOS, LOS and culmination (seconds).
+    const TIME_TOLERANCE: f64 = 1e-3;
+
+    let mut passes = Vec::new();
+    let width = seconds_between(start, end);
+    if width <= 0.0 {
+        return passes;
+    }
+
+    // Every sample lies between `start` and `start + width`; if the sum is not
+    // representable (end of chrono's range) there is nothing to search
+    if checked_add_seconds(start, width).is_err() {
+        return passes;
+    }
+    let offset = seconds_between(epoch, start);
+    let at = |t: f64| checked_add_seconds(start, t).unwrap_or(end);
+    let mask = min_elevation.get::<radian>();
+    let elevation = |t: f64| {
+        let (position, _) = elements.propagate_by(offset + t, None).to_state_vectors();
+        let ecef = crate::transforms::icrs_to_ecef_at(at(t)).transform(position);
+        crate::ext::elevation_azimuth(observer, &ecef).0.get::<radian>()
+    };
+
+    // Bisect a bracket whose ends lie on opposite sides of the mask
+    let crossing = |mut lo: f64, mut hi: f64| {
+        let rising = elevation(lo) < mask;
+        while hi - lo > TIME_TOLERANCE {
+            let mid = 0.5 * (lo + hi);
+            if (elevation(mid) >= mask) == rising {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    };
+    let pass = |aos: f64, los: f64, peak: f64| {
+        let culmination =
+            golden_section_minimum(|t| -elevation(t), (peak - STEP).max(aos), (peak + STEP).min(los), TIME_TOLERANCE);
+        Pass {
+            aos: at(aos),
+            los: at(los),
+            max_elevation: Angle::new::<radian>(elevation(culmination)),
+            max_elevation_time: at(culmination),
+        }
+    };
+
+    let first = elevation(0.0);
+    let mut previous = (0.0, first >= mask);
+    let mut current = previous.1.then_some((0.0, (0.0, first)));
+    let mut k = 1.0;
+    loop {
+        let t = (k * STEP).min(width);
+        let e = elevation(t);
+        let up = e >= mask;
+        match (previous.1, up, current) {
+            (false, true, _) => current = Some((crossing(previous.0, t), (t, e))),
+            (true, true, Some((aos, best))) if e > best.1 => current = Some((aos, (t, e))),
+            (true, false, Some((aos, (peak, _)))) => {
+                passes.push(pass(aos, crossing(previous.0, t), peak));
+                current = None;
+            }
+            _ => {}
+        }
+        previous = (t, up);
+        if t >= width {
+            break;
+        }
+        k += 1.0;
+    }
+    if let Some((aos, (peak, _))) = current {
+        passes.push(pass(aos, width, peak));
+    }
+    passes
+}
+
+/// Compute B-plane targeting parameters for a hyperbolic flyby.
+///
+/// The B-plane passes through the central body perpendicular to the incoming
+/// asymptote `Ŝ`. Its axes are `T̂ = Ŝ × ẑ / |Ŝ × ẑ|` (with `ẑ` the ICRS pole)
+/// and `R̂ = Ŝ × T̂`; the B-vector points from the body to where the incoming
+/// asymptote pierces the plane.
+///
+/// # Arguments
+///
+/// * `state` - Position and velocity relative to the central body
+/// * `mu` - Gravitational parameter of the central body (m³/s²)
+///
+/// # Returns
+///
+/// `(b_dot_t, b_dot_r, b_magnitude)` tuple.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if the orbit is not
+/// hyperbolic, or if the incoming asymptote is parallel to the ICRS pole
+/// (the `T̂` axis is then undefined).
+pub fn bplane(state: &EphemerisState<Icrs>, mu: f64) -> CelestialResult<(Length, Length, Length)> {
+    use nalgebra::Vector3;
+    use uom::si::length::meter;
+
+    let r = Vector3::from(state.position().to_cartesian().map(|c| c.get::<meter>()));
+    let v = Vector3::from(velocity_components(state.velocity()));
+
+    let h = r.cross(&v);
+    let e_vec = v.cross(&h) / mu - r.normalize();
+    let e = e_vec.norm();
+    let energy = 0.5 * v.norm_squared() - mu / r.norm();
+
+    if e <= 1.0 || energy <= 0.0 {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("B-plane requires a hyperbolic orbit (e = {e:.6})"),
+        });
+    }
+
+    // Incoming asymptote: cos(β) = 1/e measured from periapsis
+    let h_hat = h.normalize();
+    let e_hat = e_vec / e;
+    let (cos_beta, sin_beta) = (1.0 / e, (1.0 - 1.0 / (e * e)).sqrt());
+    let s_hat = cos_beta * e_hat + sin_beta * h_hat.cross(&e_hat);
+
+    let t_vec = s_hat.cross(&Vector3::z());
+    if t_vec.norm() < 1e-12 {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: "incoming asymptote is parallel to the ICRS pole".to_string(),
+        });
+    }
+    let t_hat = t_vec.normalize();
+    let r_hat = s_hat.cross(&t_hat);
+
+    let v_inf = (2.0 * energy).sqrt();
+    let b_mag = h.norm() / v_inf;
+    let b_vec = b_mag * s_hat.cross(&h_hat);
+
+    Ok((
+        Length::new::<meter>(b_vec.dot(&t_hat)),
+        Length::new::<meter>(b_vec.dot(&r_hat)),
+        Length::new::<meter>(b_mag),
+    ))
+}
+
+/// Solve Lambert's problem: the two-body transfer from `r1` to `r2` in `tof`.
+///
+/// Universal-variable formulation with bisection on `ψ = Δχ²/a` (Vallado,
+/// *Fundamentals of Astrodynamics and Applications*, algorithm 58), for the
+/// single-revolution (zero extra revolutions) case. `prograde` selects the
+/// short or long way round so that the transfer angular momentum has a
+/// positive (prograde) or negative (retrograde) ICRS z-component.
+///
+/// # Returns
+///
+/// `(v1, v2)`: the velocities in m/s at departure and arrival.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if `tof` is not positive or
+/// the positions are collinear with the focus (0° or 180° transfers, whose
+/// plane is undefined), and `CelestialError::NumericalPrecisionError` if the
+/// time-of-flight equation does not converge, which includes strongly
+/// hyperbolic transfers with `ψ` below the bracket's `-4π`.
+pub fn lambert(
+    r1: Coordinate<Icrs>,
+    r2: Coordinate<Icrs>,
+    tof: Time,
+    mu: f64,
+    prograde: bool,
+) -> CelestialResult<([f64; 3], [f64; 3])> {
+    use nalgebra::Vector3;
+    use std::f64::consts::PI;
+    use uom::si::length::meter;
+    use uom::si::time::second;
+
+    /// Bisection steps; each halves the ψ bracket of width ~4π².
+    const MAX_ITER: usize = 200;
+
+    let tof = tof.get::<second>();
+    if tof <= 0.0 {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Lambert time of flight must be positive, got {} s", tof),
+        });
+    }
+
+    let r1 = Vector3::from(r1.to_cartesian().map(|c| c.get::<meter>()));
+    let r2 = Vector3::from(r2.to_cartesian().map(|c| c.get::<meter>()));
+    let (r1_norm, r2_norm) = (r1.norm(), r2.norm());
+
+    // Transfer angle on the requested side of the r1-r2 plane
+    let cos_dnu = (r1.dot(&r2) / (r1_norm * r2_norm)).clamp(-1.0, 1.0);
+    let short_way = (r1.cross(&r2).z >= 0.0) == prograde;
+    let dnu = if short_way { cos_dnu.acos() } else { 2.0 * PI - cos_dnu.acos() };
+
+    let direction = if dnu < PI { 1.0 } else { -1.0 };
+    let a = direction * (r1_norm * r2_norm * (1.0 + cos_dnu)).sqrt();
+    if a.abs() < 1e-9 * (r1_norm + r2_norm) || (1.0 - cos_dnu) < 1e-12 {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: "Lambert positions are collinear with the focus; the transfer plane is undefined".into(),
+        });
+    }
+
+    let y = |psi: f64| {
+        let (c2, c3) = stumpff(psi);
+        r1_norm + r2_norm + a * (psi * c3 - 1.0) / c2.sqrt()
+    };
+    let time_of_flight = |psi: f64, y: f64| {
+        let (c2, c3) = stumpff(psi);
+        let chi = (y / c2).sqrt();
+        (chi.powi(3) * c3 + a * y.sqrt()) / mu.sqrt()
+    };
+
+    // ψ ranges from hyperbolic (negative) to the one-revolution limit 4π²
+    let (mut lo, mut hi) = (-4.0 * PI, 4.0 * PI * PI);
+    for _ in 0..MAX_ITER {
+        let psi = 0.5 * (lo + hi);
+        let y_psi = y(psi);
+        // y < 0 only occurs for A > 0 at small ψ, where the transfer is faster still
+        if y_psi < 0.0 {
+            lo = psi;
+            continue;
+        }
+
+        let dt = time_of_flight(psi, y_psi);
+        if (dt - tof).abs() <= 1e-10 * tof {
+            let f = 1.0 - y_psi / r1_norm;
+            let g = a * (y_psi / mu).sqrt();
+            let g_dot = 1.0 - y_psi / r2_norm;
+
+            let v1 = (r2 - f * r1) / g;
+            let v2 = (g_dot * r2 - r1) / g;
+            return Ok((v1.into(), v2.into()));
+        }
+        if dt < tof {
+            lo = psi;
+        } else {
+            hi = psi;
+        }
+    }
+
+    Err(CelestialError::NumericalPrecisionError {
+        reason: format!("Lambert solver did not converge for a {} s transfer", tof),
+    })
+}
+
+/// Stumpff functions `(c₂(ψ), c₃(ψ))` of the universal variable formulation.
+fn stumpff(psi: f64) -> (f64, f64) {
+    if psi > 1e-6 {
+        let s = psi.sqrt();
+        ((1.0 - s.cos()) / psi, (s - s.sin()) / (s * psi))
+    } else if psi < -1e-6 {
+        let s = (-psi).sqrt();
+        ((1.0 - s.cosh()) / psi, (s.sinh() - s) / (s * -psi))
+    } else {
+        // Series about ψ = 0
+        (1.0 / 2.0 - psi / 24.0 + psi * psi / 720.0, 1.0 / 6.0 - psi / 120.0 + psi * psi / 5040.0)
+    }
+}
+
+/// Launch azimuth that places a vehicle directly into an orbit of the given
+/// inclination, from `cos i = sin(az)·cos(lat)`.
+///
+/// Returns the northbound (ascending-pass) azimuth, measured clockwise from
+/// north: in (0°, 90°] for prograde orbits and [270°, 360°) for retrograde
+/// ones. The southbound alternative is `180° - az`. Earth rotation is not
+/// accounted for, so this is the inertial azimuth.
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` if the inclination is below
+/// the site latitude (or above its supplement), which no direct ascent can
+/// reach.
+pub fn launch_azimuth(site_lat: Angle, target_inclination: Angle) -> CelestialResult<Angle> {
+    let sin_az = target_inclination.get::<radian>().cos() / site_lat.get::<radian>().cos();
+    if !(-1.0..=1.0).contains(&sin_az) {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!(
+                "inclination {:.3}° is unreachable from latitude {:.3}°",
+                target_inclination.get::<radian>().to_degrees(),
+                site_lat.get::<radian>().to_degrees()
+            ),
+        });
+    }
+
+    Ok(Angle::new::<radian>(sin_az.asin().rem_euclid(std::f64::consts::TAU)))
+}
+
+/// Keplerian and nodal periods of an orbit under J2.
+///
+/// The Keplerian period `2π·√(a³/μ)` is what a single state vector implies.
+/// The nodal period, between successive ascending-node crossings, also
+/// includes the secular J2 drift of the argument of periapsis and mean
+/// anomaly. It is what TLE mean motions and pass timings reflect, and for LEO
+/// differs from the Keplerian value by several seconds.
+///
+/// # Arguments
+///
+/// * `elements` - Orbit (the semi-major axis is treated as the mean value)
+/// * `j2` - Second zonal harmonic of the central body
+/// * `r_body` - Equatorial radius of the central body (m)
+///
+/// # Returns
+///
+/// `(keplerian, nodal)` periods.
+#[must_use]
+pub fn period_diagnostic(elements: &KeplerianElements, j2: f64, r_body: f64) -> (Time, Time) {
+    use uom::si::length::meter;
+    use uom::si::time::second;
+
+    let a = elements.semi_major_axis.get::<meter>();
+    let e = elements.eccentricity;
+    let cos_i = elements.inclination.get::<radian>().cos();
+
+    let n = (elements.mu / a.powi(3)).sqrt();
+    let p = a * (1.0 - e * e);
+    let k = 0.75 * n * j2 * (r_body / p).powi(2);
+
+    let argp_rate = k * (5.0 * cos_i * cos_i - 1.0);
+    let mean_anomaly_rate = n + k * (1.0 - e * e).sqrt() * (3.0 * cos_i * cos_i - 1.0);
+
+    (
+        elements.period(),
+        Time::new::<second>(std::f64::consts::TAU / (argp_rate + mean_anomaly_rate)),
+    )
+}
+
+/// True anomaly (radians) for a mean anomaly on an elliptical orbit, via the
+/// eccentric anomaly from [`solve_kepler`].
+pub(crate) fn true_anomaly_from_mean(mean_anomaly: f64, e: f64, config: SolverConfig) -> f64 {
+    eccentric_to_true(solve_kepler(mean_anomaly, e, config), e)
+}
+
+/// Eccentric anomaly (radians) for a mean anomaly, solving Kepler's equation
+/// `M = E - e·sin(E)` by Newton-Raphson with [`SolverConfig::default`].
+///
+/// # Errors
+///
+/// Returns `CelestialError::InvalidCoordinates` unless `0 ≤ e < 1`, and
+/// `CelestialError::NumericalPrecisionError` if the iteration does not
+/// converge.
+pub fn mean_to_eccentric(m: f64, e: f64) -> CelestialResult<f64> {
+    if !(0.0..1.0).contains(&e) {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Kepler's equation requires 0 <= e < 1, got e = {e}"),
+        });
+    }
+
+    let config = SolverConfig::default();
+    match iterate_kepler(m, e, config) {
+        (e_anom, true) => Ok(e_anom),
+        (_, false) => Err(CelestialError::NumericalPrecisionError {
+            reason: format!("Kepler's equation did not converge within {} iterations (M = {m}, e = {e})", config.max_iter),
+        }),
+    }
+}
+
+/// True anomaly (radians, in `[0, 2π)`) from the eccentric anomaly.
+#[must_use]
+pub fn eccentric_to_true(ea: f64, e: f64) -> f64 {
+    let (sin_half, cos_half) = (ea / 2.0).sin_cos();
+    (2.0 * ((1.0 + e).sqrt() * sin_half).atan2((1.0 - e).sqrt() * cos_half)).rem_euclid(std::f64::consts::TAU)
+}
+
+/// Eccentric anomaly (radians, in `[0, 2π)`) from the true anomaly.
+#[must_use]
+pub fn true_to_eccentric(nu: f64, e: f64) -> f64 {
+    let (sin_half, cos_half) = (nu / 2.0).sin_cos();
+    (2.0 * ((1.0 - e).sqrt() * sin_half).atan2((1.0 + e).sqrt() * cos_half)).rem_euclid(std::f64::consts::TAU)
+}
+
+/// Solve Kepler's equation `M = E - e·sin(E)` for the eccentric anomaly (Newton-Raphson).
+fn solve_kepler(mean_anomaly: f64, e: f64, config: SolverConfig) -> f64 {
+    iterate_kepler(mean_anomaly, e, config).0
+}
+
+/// Newton-Raphson iteration for [`solve_kepler`], returning the last iterate
+/// and whether it met the tolerance within `config.max_iter` iterations.
+fn iterate_kepler(mean_anomaly: f64, e: f64, config: SolverConfig) -> (f64, bool) {
+    use std::f64::consts::{PI, TAU};
+
+    // Solve for M wrapped into [-π, π) and add the whole revolutions back,
+    // so long propagations converge as fast as the first orbit
+    let m = (mean_anomaly + PI).rem_euclid(TAU) - PI;
+    let revolutions = mean_anomaly - m;
+
+    // Starting from ±π avoids overshooting near periapsis for high
+    // eccentricity: Newton then approaches the root from the convex side
+    let mut e_anom = if e > 0.8 { PI.copysign(m) } else { m + e * m.sin() };
+    for _ in 0..config.max_iter {
+        let delta = (e_anom - e * e_anom.sin() - m) / (1.0 - e * e_anom.cos());
+        e_anom -= delta;
+        if config.converged(delta, e_anom) {
+            return (e_anom + revolutions, true);
         }
     }
+    (e_anom + revolutions, false)
+}
+
+/// Elapsed seconds from `from` to `to`, preserving sub-second precision.
+pub(crate) fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    let dt = to - from;
+    match dt.num_nanoseconds() {
+        Some(ns) => ns as f64 * 1e-9,
+        None => dt.num_milliseconds() as f64 * 1e-3,
+    }
 }
 
 #[cfg(test)]
@@ -228,6 +1164,137 @@ mod tests {
         assert!((distance.get::<kilometer>() - 7000.0).abs() < 0.1);
     }
 
+    #[test]
+    fn equatorial_ground_track_stays_on_equator() {
+        use chrono::TimeZone;
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let equatorial = KeplerianElements::circular_at_altitude(
+            Length::new::<kilometer>(500.0),
+            Angle::new::<degree>(0.0),
+            Length::new::<kilometer>(6378.137),
+            MU_EARTH,
+        );
+
+        let end = epoch + chrono::Duration::hours(3);
+        let track = ground_track(&equatorial, epoch, epoch, end, chrono::Duration::minutes(10));
+        assert_eq!(track.len(), 19);
+        assert_eq!(track.last().unwrap().0, end);
+        // The ICRS equator is tilted from the equator of date by precession only
+        for (_, latitude, longitude) in &track {
+            assert!(latitude.get::<degree>().abs() < 0.2);
+            assert!(longitude.get::<degree>().abs() <= 180.0);
+        }
+
+        assert!(ground_track(&equatorial, epoch, epoch, end, chrono::Duration::zero()).is_empty());
+    }
+
+    #[test]
+    fn leo_passes_over_mid_latitude_site_daily() {
+        use chrono::TimeZone;
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let leo = KeplerianElements::circular_at_altitude(
+            Length::new::<kilometer>(420.0),
+            Angle::new::<degree>(51.6),
+            Length::new::<kilometer>(6378.137),
+            MU_EARTH,
+        );
+        let site = crate::Observer::new(
+            Angle::new::<degree>(45.0),
+            Angle::new::<degree>(7.0),
+            Length::new::<kilometer>(0.3),
+        );
+        let mask = Angle::new::<degree>(10.0);
+
+        let end = epoch + chrono::Duration::days(1);
+        let passes = predict_passes(&site, &leo, epoch, epoch, end, mask);
+        assert!(!passes.is_empty());
+
+        for pass in &passes {
+            assert!(pass.aos < pass.max_elevation_time && pass.max_elevation_time < pass.los);
+            assert!(pass.los - pass.aos < chrono::Duration::minutes(15));
+            assert!(pass.max_elevation >= mask);
+        }
+        for pair in passes.windows(2) {
+            assert!(pair[0].los < pair[1].aos);
+        }
+
+        // A mask above any reachable elevation leaves no passes
+        assert!(predict_passes(&site, &leo, epoch, epoch, end, Angle::new::<degree>(90.0)).is_empty());
+
+        // A window ending at the last representable instant is searched without
+        // overflowing: with the mask at the nadir the whole window is one pass
+        let last = DateTime::<Utc>::MAX_UTC;
+        let first = last - chrono::Duration::minutes(10);
+        let always = predict_passes(&site, &leo, epoch, first, last, Angle::new::<degree>(-90.0));
+        assert_eq!(always.len(), 1);
+        assert_eq!((always[0].aos, always[0].los), (first, last));
+    }
+
+    #[test]
+    fn lambert_reproduces_vallado_example() {
+        use uom::si::length::kilometer;
+        use uom::si::time::{minute, second};
+
+        #[allow(deprecated)]
+        let km = |x: f64, y: f64| {
+            Coordinate::<Icrs>::from_cartesian(
+                Length::new::<kilometer>(x),
+                Length::new::<kilometer>(y),
+                Length::new::<kilometer>(0.0),
+            )
+        };
+        let (r1, r2) = (km(15_945.34, 0.0), km(12_214.838_99, 10_249.467_31));
+
+        // Vallado, example 7-5: 76 min short-way transfer
+        let (v1, v2) = lambert(r1, r2, Time::new::<minute>(76.0), MU_EARTH, true).unwrap();
+        let expected_v1 = [2_058.913, 2_915.965, 0.0];
+        let expected_v2 = [-3_451.565, 910.315, 0.0];
+        for i in 0..3 {
+            assert!((v1[i] - expected_v1[i]).abs() < 0.01, "v1 = {:?}", v1);
+            assert!((v2[i] - expected_v2[i]).abs() < 0.01, "v2 = {:?}", v2);
+        }
+
+        // Propagating the departure state must land on r2
+        let transfer = KeplerianElements::from_state_vectors(r1, v1, MU_EARTH).unwrap();
+        let (arrival, _) = transfer.propagate_by(76.0 * 60.0, None).to_state_vectors();
+        assert!(arrival.distance_from(&r2).get::<kilometer>() < 1e-3);
+
+        // The long way round is retrograde in the same plane
+        let (v1_long, _) = lambert(r1, r2, Time::new::<minute>(76.0), MU_EARTH, false).unwrap();
+        assert!(v1_long[1] < 0.0);
+
+        assert!(lambert(r1, r2, Time::new::<second>(0.0), MU_EARTH, true).is_err());
+        assert!(lambert(r1, km(-15_945.34, 0.0), Time::new::<minute>(76.0), MU_EARTH, true).is_err());
+    }
+
+    #[test]
+    fn sun_synchronous_orbit_is_retrograde() {
+        use uom::si::angle::degree;
+        use uom::si::length::kilometer;
+
+        let sso = KeplerianElements::circular_at_altitude(
+            Length::new::<kilometer>(700.0),
+            Angle::new::<degree>(98.0),
+            Length::new::<kilometer>(6378.137),
+            MU_EARTH,
+        );
+        assert!(sso.is_retrograde());
+        assert!(!KeplerianElements { inclination: Angle::new::<degree>(51.6), ..sso }.is_retrograde());
+
+        let at = |u: f64| KeplerianElements { true_anomaly: Angle::new::<degree>(u), ..sso };
+        assert_eq!(at(10.0).ground_track_direction(), TrackDirection::Ascending);
+        assert_eq!(at(180.0).ground_track_direction(), TrackDirection::Descending);
+        assert_eq!(at(-30.0).ground_track_direction(), TrackDirection::Ascending);
+        let equatorial = KeplerianElements { inclination: Angle::new::<degree>(0.0), ..at(10.0) };
+        assert_eq!(equatorial.ground_track_direction(), TrackDirection::Stationary);
+    }
+
     #[test]
     fn orbit_propagation_changes_true_anomaly() {
         let elements = KeplerianElements::default();
@@ -239,4 +1306,421 @@ mod tests {
         // True anomaly should have changed
         assert!(propagated.true_anomaly.get::<radian>() != elements.true_anomaly.get::<radian>());
     }
+
+    #[test]
+    fn closest_approach_between_crossing_circular_orbits() {
+        use chrono::TimeZone;
+        use uom::si::angle::degree;
+
+        // Equatorial and polar circular orbits of equal radius, with the polar
+        // object leading by φ. The separation is R·√(2 − 2·cos(nt)·cos(nt + φ)),
+        // minimized at nt = π − φ/2 with a miss distance of R·√2·sin(φ/2).
+        let radius = 7000.0;
+        let phi = 10.0_f64.to_radians();
+        let equatorial = KeplerianElements::default();
+        let polar = KeplerianElements {
+            inclination: Angle::new::<degree>(90.0),
+            true_anomaly: Angle::new::<radian>(phi),
+            ..KeplerianElements::default()
+        };
+
+        let n = (MU_EARTH / (radius * 1000.0_f64).powi(3)).sqrt();
+        let expected_t = (std::f64::consts::PI - phi / 2.0) / n;
+        let expected_miss = radius * 2.0_f64.sqrt() * (phi / 2.0).sin();
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let centre = epoch + chrono::Duration::milliseconds((expected_t * 1000.0) as i64);
+        let bracket = (
+            centre - chrono::Duration::minutes(10),
+            centre + chrono::Duration::minutes(10),
+        );
+
+        let (tca, miss) = closest_approach(&equatorial, &polar, epoch, bracket);
+
+        assert!((seconds_between(epoch, tca) - expected_t).abs() < 0.01);
+        assert!((miss.get::<kilometer>() - expected_miss).abs() < 1e-3);
+    }
+
+    #[test]
+    fn state_vector_roundtrip_covers_degenerate_orbits() {
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        let base = KeplerianElements {
+            semi_major_axis: Length::new::<kilometer>(7_000.0),
+            eccentricity: 0.1,
+            inclination: Angle::new::<degree>(63.4),
+            raan: Angle::new::<degree>(40.0),
+            argument_of_periapsis: Angle::new::<degree>(270.0),
+            true_anomaly: Angle::new::<degree>(135.0),
+            mu: MU_EARTH,
+        };
+        let cases = [
+            base,
+            KeplerianElements { eccentricity: 0.0, ..base },
+            KeplerianElements { inclination: Angle::new::<degree>(0.0), ..base },
+            KeplerianElements { inclination: Angle::new::<degree>(180.0), ..base },
+            KeplerianElements { eccentricity: 0.0, inclination: Angle::new::<degree>(0.0), ..base },
+        ];
+
+        for elements in cases {
+            let (position, velocity) = elements.to_state_vectors();
+            let recovered = KeplerianElements::from_state_vectors(position, velocity, MU_EARTH).unwrap();
+            let (position2, velocity2) = recovered.to_state_vectors();
+
+            let r = position.to_cartesian().map(|c| c.get::<meter>());
+            let r2 = position2.to_cartesian().map(|c| c.get::<meter>());
+            for i in 0..3 {
+                assert!((r[i] - r2[i]).abs() < 1e-6, "{elements:?}");
+                assert!((velocity[i] - velocity2[i]).abs() < 1e-9, "{elements:?}");
+            }
+        }
+
+        // Equatorial prograde: RAAN folds into the longitude of periapsis
+        let (position, velocity) = cases[2].to_state_vectors();
+        let recovered = KeplerianElements::from_state_vectors(position, velocity, MU_EARTH).unwrap();
+        assert_eq!(recovered.raan.get::<degree>(), 0.0);
+        assert!((recovered.argument_of_periapsis.get::<degree>() - 310.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn launch_azimuth_from_equator_and_cape() {
+        use uom::si::angle::degree;
+
+        let due_east = launch_azimuth(Angle::new::<degree>(0.0), Angle::new::<degree>(0.0)).unwrap();
+        assert!((due_east.get::<degree>() - 90.0).abs() < 1e-12);
+
+        // Cape Canaveral to the ISS
+        let cape = Angle::new::<degree>(28.5);
+        let iss = launch_azimuth(cape, Angle::new::<degree>(51.6)).unwrap().get::<degree>();
+        assert!((iss - 44.9).abs() < 0.1);
+        let sso = launch_azimuth(cape, Angle::new::<degree>(98.0)).unwrap().get::<degree>();
+        assert!(sso > 270.0 && sso < 360.0);
+
+        assert!(matches!(
+            launch_azimuth(cape, Angle::new::<degree>(20.0)),
+            Err(CelestialError::InvalidCoordinates { .. })
+        ));
+    }
+
+    #[test]
+    fn try_propagate_rejects_open_orbits_and_reports_divergence() {
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let target = epoch + chrono::Duration::minutes(20);
+
+        let elliptical = KeplerianElements {
+            eccentricity: 0.95,
+            ..KeplerianElements::default()
+        };
+        assert_eq!(
+            elliptical.try_propagate_to(target, epoch).unwrap(),
+            elliptical.propagate_to(target, epoch)
+        );
+
+        let hyperbolic = KeplerianElements { eccentricity: 1.2, ..elliptical };
+        assert!(matches!(
+            hyperbolic.try_propagate_to(target, epoch),
+            Err(CelestialError::Unsupported { .. })
+        ));
+        let negative = KeplerianElements { eccentricity: -0.1, ..elliptical };
+        assert!(matches!(
+            negative.try_propagate_to(target, epoch),
+            Err(CelestialError::InvalidCoordinates { .. })
+        ));
+
+        let starved = Some(SolverConfig::new(1, 1e-15));
+        assert!(matches!(
+            elliptical.try_propagate_to_with_config(target, epoch, starved),
+            Err(CelestialError::NumericalPrecisionError { .. })
+        ));
+    }
+
+    #[test]
+    fn anomaly_conversions_roundtrip() {
+        for e in [0.0, 0.01, 0.3, 0.7, 0.9] {
+            for i in 0..24 {
+                let m = i as f64 * std::f64::consts::TAU / 24.0;
+                let ea = mean_to_eccentric(m, e).unwrap();
+                assert!((ea - e * ea.sin() - m).abs() < 1e-12);
+
+                let nu = eccentric_to_true(ea, e);
+                let back = true_to_eccentric(nu, e);
+                let m_back = back - e * back.sin();
+                let diff = (m_back - m + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI;
+                assert!(diff.abs() < 1e-12, "e = {e}, M = {m}");
+            }
+        }
+
+        assert!(matches!(mean_to_eccentric(1.0, 1.0), Err(CelestialError::InvalidCoordinates { .. })));
+    }
+
+    #[test]
+    fn kepler_converges_for_many_revolutions() {
+        for (m, e) in [(1_000.0, 0.99), (1e4, 0.99), (1e5, 0.9), (1e5, 0.99), (-1e5, 0.5)] {
+            let ea = mean_to_eccentric(m, e).unwrap();
+            assert!((ea - e * ea.sin() - m).abs() < 1e-9, "M = {m}, e = {e}");
+            assert!((ea - m).abs() <= e + 1e-9);
+        }
+    }
+
+    #[test]
+    fn tighter_tolerance_reduces_kepler_residual() {
+        let (m, e) = (0.3, 0.9);
+        let residual = |ea: f64| (ea - e * ea.sin() - m).abs();
+
+        let loose = solve_kepler(m, e, SolverConfig::new(50, 1e-3));
+        let tight = solve_kepler(m, e, SolverConfig::new(50, 1e-15));
+
+        assert!(residual(tight) < residual(loose));
+        assert!(residual(tight) < 1e-14);
+    }
+
+    #[test]
+    fn bplane_of_equatorial_flyby_lies_along_t_axis() {
+        use chrono::TimeZone;
+        use uom::si::f64::Velocity;
+        use uom::si::length::meter;
+        use uom::si::velocity::meter_per_second;
+
+        // Equatorial hyperbola at periapsis: the B-vector is b·T̂ with
+        // b = |a|·√(e² − 1), so B·R vanishes.
+        let (a, e) = (-20_000_000.0_f64, 1.5_f64);
+        let rp = a * (1.0 - e);
+        let vp = (MU_EARTH * (2.0 / rp - 1.0 / a)).sqrt();
+
+        #[allow(deprecated)]
+        let position = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<meter>(rp),
+            Length::new::<meter>(0.0),
+            Length::new::<meter>(0.0),
+        );
+        #[allow(deprecated)]
+        let velocity = sguaba::Vector::<Icrs, typenum::N1>::from_cartesian(
+            Velocity::new::<meter_per_second>(0.0),
+            Velocity::new::<meter_per_second>(vp),
+            Velocity::new::<meter_per_second>(0.0),
+        );
+        let state = EphemerisState::new(position, velocity, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+
+        let (b_t, b_r, b_mag) = bplane(&state, MU_EARTH).unwrap();
+        let expected = a.abs() * (e * e - 1.0).sqrt();
+
+        assert!((b_mag.get::<meter>() - expected).abs() < 1e-3);
+        assert!((b_t.get::<meter>() - expected).abs() < 1e-3);
+        assert!(b_r.get::<meter>().abs() < 1e-3);
+    }
+
+    #[test]
+    fn bplane_rejects_bound_orbit() {
+        use chrono::TimeZone;
+        use uom::si::f64::Velocity;
+        use uom::si::velocity::meter_per_second;
+
+        let (position, [vx, vy, vz]) = KeplerianElements::default().to_state_vectors();
+        #[allow(deprecated)]
+        let velocity = sguaba::Vector::<Icrs, typenum::N1>::from_cartesian(
+            Velocity::new::<meter_per_second>(vx),
+            Velocity::new::<meter_per_second>(vy),
+            Velocity::new::<meter_per_second>(vz),
+        );
+        let state = EphemerisState::new(position, velocity, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+
+        assert!(bplane(&state, MU_EARTH).is_err());
+    }
+
+    #[test]
+    fn j2_propagated_leo_shows_monotonic_raan_drift() {
+        use chrono::TimeZone;
+        use crate::astrodynamics::{integrate_rk4, j2_acceleration, two_body_acceleration};
+        use crate::constants::{EARTH_RADIUS_EQUATORIAL, J2_EARTH};
+        use crate::timed::velocity_from_components;
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+
+        let initial = KeplerianElements {
+            semi_major_axis: Length::new::<kilometer>(6_778.0),
+            eccentricity: 0.001,
+            inclination: Angle::new::<degree>(51.6),
+            raan: Angle::new::<degree>(30.0),
+            argument_of_periapsis: Angle::new::<degree>(45.0),
+            ..KeplerianElements::default()
+        };
+        let (position, velocity) = initial.to_state_vectors();
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let state = EphemerisState::new(position, velocity_from_components(velocity), epoch);
+
+        let states = integrate_rk4(&state, epoch + chrono::Duration::hours(12), 30.0, |_, r, _| {
+            let [ax, ay, az] = two_body_acceleration(r, MU_EARTH);
+            let [jx, jy, jz] = j2_acceleration(r, MU_EARTH, J2_EARTH, EARTH_RADIUS_EQUATORIAL);
+            [ax + jx, ay + jy, az + jz]
+        })
+        .unwrap();
+        let history = element_history(&states, MU_EARTH).unwrap();
+        assert_eq!(history.len(), states.len());
+
+        // A radial state has no orbit plane; the failure is reported, not skipped
+        let radial = EphemerisState::new(position, velocity_from_components([0.0; 3]), epoch);
+        assert!(matches!(
+            element_history(&[state, radial], MU_EARTH),
+            Err(CelestialError::NumericalPrecisionError { .. })
+        ));
+
+        // dΩ/dt ∝ -cos(i)·sin²(u) for J2, so a prograde orbit's node only regresses
+        let raan: Vec<f64> = history.iter().map(|(_, el)| el.raan.get::<radian>()).collect();
+        assert!(raan.windows(2).all(|w| w[1] - w[0] < 1e-9));
+
+        // Total drift matches the secular rate -1.5·n·J2·(R/p)²·cos(i)
+        let a = initial.semi_major_axis.get::<meter>();
+        let p = a * (1.0 - initial.eccentricity.powi(2));
+        let n = (MU_EARTH / a.powi(3)).sqrt();
+        let rate = -1.5 * n * J2_EARTH * (EARTH_RADIUS_EQUATORIAL / p).powi(2)
+            * initial.inclination.get::<radian>().cos();
+        let drift = raan[raan.len() - 1] - raan[0];
+        assert!((drift / (rate * 12.0 * 3600.0) - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn nodal_period_of_inclined_leo_differs_by_seconds() {
+        use crate::constants::{EARTH_RADIUS_EQUATORIAL, J2_EARTH};
+        use uom::si::angle::degree;
+        use uom::si::time::second;
+
+        let elements = KeplerianElements {
+            semi_major_axis: Length::new::<kilometer>(6_778.0),
+            inclination: Angle::new::<degree>(51.6),
+            ..KeplerianElements::default()
+        };
+        let (keplerian, nodal) = period_diagnostic(&elements, J2_EARTH, EARTH_RADIUS_EQUATORIAL);
+
+        let a = elements.semi_major_axis.get::<uom::si::length::meter>();
+        let expected = 2.0 * std::f64::consts::PI * (a.powi(3) / MU_EARTH).sqrt();
+        assert!((keplerian.get::<second>() - expected).abs() < 1e-9);
+
+        let difference = keplerian.get::<second>() - nodal.get::<second>();
+        assert!(difference > 1.0 && difference < 10.0);
+
+        let (k0, n0) = period_diagnostic(&elements, 0.0, EARTH_RADIUS_EQUATORIAL);
+        assert!((k0.get::<second>() - n0.get::<second>()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn circular_600_km_orbit_has_97_minute_period() {
+        use crate::constants::EARTH_RADIUS_EQUATORIAL;
+        use uom::si::angle::degree;
+        use uom::si::length::meter;
+        use uom::si::time::minute;
+
+        let elements = KeplerianElements::circular_at_altitude(
+            Length::new::<kilometer>(600.0),
+            Angle::new::<degree>(97.8),
+            Length::new::<meter>(EARTH_RADIUS_EQUATORIAL),
+            MU_EARTH,
+        );
+        assert!((elements.semi_major_axis.get::<kilometer>() - 6_978.137).abs() < 1e-9);
+        assert_eq!(elements.eccentricity, 0.0);
+
+        let (period, _) = period_diagnostic(&elements, 0.0, EARTH_RADIUS_EQUATORIAL);
+        assert!((period.get::<minute>() - 96.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn ephemeris_state_matches_state_vectors() {
+        use chrono::TimeZone;
+
+        let elements = KeplerianElements {
+            eccentricity: 0.2,
+            true_anomaly: Angle::new::<radian>(1.0),
+            ..KeplerianElements::default()
+        };
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let state = elements.to_ephemeris_state(epoch);
+        let (position, velocity) = elements.to_state_vectors();
+        assert_eq!(state.epoch(), epoch);
+        assert_eq!(state.position(), &position);
+        assert_eq!(velocity_components(state.velocity()), velocity);
+    }
+
+    #[test]
+    fn escape_check_and_c3_follow_orbit_energy() {
+        let leo = KeplerianElements::default();
+        assert!(!leo.is_escape());
+        assert!(leo.characteristic_energy() < 0.0);
+
+        // v∞ = 3 km/s departure hyperbola: a = -μ/v∞²
+        let v_inf = 3_000.0;
+        let departure = KeplerianElements {
+            semi_major_axis: Length::new::<uom::si::length::meter>(-MU_EARTH / (v_inf * v_inf)),
+            eccentricity: 1.5,
+            ..leo
+        };
+        assert!(departure.is_escape());
+        assert!((departure.characteristic_energy() - v_inf * v_inf).abs() < 1e-6);
+    }
+
+    #[test]
+    fn default_leo_period_and_apsides() {
+        use uom::si::time::minute;
+
+        let leo = KeplerianElements::default();
+        assert!((leo.period().get::<minute>() - 97.1).abs() < 0.1);
+        assert_eq!(leo.apoapsis_radius(), leo.semi_major_axis);
+        assert_eq!(leo.periapsis_radius(), leo.semi_major_axis);
+
+        let elliptical = KeplerianElements { eccentricity: 0.25, ..leo };
+        assert!((elliptical.apoapsis_radius().get::<kilometer>() - 8_750.0).abs() < 1e-9);
+        assert!((elliptical.periapsis_radius().get::<kilometer>() - 5_250.0).abs() < 1e-9);
+        assert_eq!(elliptical.period(), leo.period());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_uses_explicit_units_and_roundtrips() {
+        use uom::si::angle::degree;
+
+        let elements = KeplerianElements {
+            semi_major_axis: Length::new::<kilometer>(6_778.0),
+            eccentricity: 0.001,
+            inclination: Angle::new::<degree>(51.6),
+            raan: Angle::new::<degree>(30.0),
+            ..KeplerianElements::default()
+        };
+
+        let json = elements.to_json();
+        assert!(json.contains("\"inclination_deg\":51.6"));
+        assert!(json.contains("\"semi_major_axis_km\":6778"));
+        assert!(json.contains("\"frame\":\"ICRS\""));
+
+        let back = KeplerianElements::from_json(&json).unwrap();
+        assert!((back.inclination.get::<degree>() - 51.6).abs() < 1e-12);
+        assert!((back.semi_major_axis.get::<kilometer>() - 6_778.0).abs() < 1e-9);
+        assert_eq!(back.eccentricity, elements.eccentricity);
+        assert_eq!(back.mu, elements.mu);
+
+        let other_frame = json.replace("ICRS", "EME2000");
+        assert!(matches!(
+            KeplerianElements::from_json(&other_frame),
+            Err(CelestialError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn propagate_to_timed_tags_target_epoch() {
+        use chrono::TimeZone;
+
+        let elements = KeplerianElements {
+            eccentricity: 0.05,
+            ..KeplerianElements::default()
+        };
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let target = epoch + chrono::Duration::minutes(37);
+
+        let timed = elements.propagate_to_timed(target, epoch);
+        let (expected, _) = elements.propagate_to(target, epoch).to_state_vectors();
+
+        assert_eq!(timed.epoch(), target);
+        assert_eq!(timed.position(), &expected);
+    }
 }
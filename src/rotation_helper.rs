@@ -3,7 +3,7 @@
 //! This module provides a workaround for constructing Rotation<From, To> instances
 //! from nalgebra quaternions when we cannot access the private fields of sguaba::math::Rotation.
 
-use nalgebra::{Quaternion, Unit};
+use nalgebra::{Quaternion, Unit, Vector3};
 use sguaba::math::Rotation;
 
 type UnitQuaternion = Unit<Quaternion<f64>>;
@@ -13,23 +13,116 @@ type UnitQuaternion = Unit<Quaternion<f64>>;
 /// This is a workaround for the fact that Rotation's fields are private and there's no
 /// public constructor that takes a quaternion directly.
 ///
+/// The angles come from [`tait_bryan_angles`], which stays well conditioned at
+/// pitch ±90° (e.g. polar pointing), so the rebuilt rotation matches `quat` to
+/// a few 1e-16 rad everywhere. Extracting them with `UnitQuaternion::euler_angles`
+/// instead loses ~1e-8 rad near the poles, because pitch comes from an `asin`.
+///
 /// # Safety
 ///
 /// This has the same safety requirements as Rotation itself - you must ensure that
 /// the quaternion represents the correct rotation from From to To.
 pub unsafe fn rotation_from_quaternion<From, To>(quat: UnitQuaternion) -> Rotation<From, To> {
-    // Extract Euler angles from the quaternion
-    let (roll, pitch, yaw) = quat.euler_angles();
-    
-    // Use the Tait-Bryan builder to reconstruct the rotation
-    // Note: euler_angles() returns in (roll, pitch, yaw) order
-    // but tait_bryan_builder expects (yaw, pitch, roll)
-    use uom::si::f64::Angle;
     use uom::si::angle::radian;
-    
+    use uom::si::f64::Angle;
+
+    let (yaw, pitch, roll) = tait_bryan_angles(&quat);
+
     Rotation::tait_bryan_builder()
         .yaw(Angle::new::<radian>(yaw))
         .pitch(Angle::new::<radian>(pitch))
         .roll(Angle::new::<radian>(roll))
         .build()
 }
+
+/// Intrinsic Z-Y-X angles `(yaw, pitch, roll)` with `quat = Rz(yaw)·Ry(pitch)·Rx(roll)`.
+///
+/// Uses half-angle sums rather than matrix entries: with `a = yaw + roll`,
+/// `b = yaw - roll`, `C = cos(pitch/2)` and `S = sin(pitch/2)`,
+///
+/// - `w - y = (C - S)·cos(a/2)`, `z + x = (C - S)·sin(a/2)`
+/// - `w + y = (C + S)·cos(b/2)`, `z - x = (C + S)·sin(b/2)`
+///
+/// Every angle is then an `atan2`. At pitch = +90° (−90°) `a` (`b`) becomes
+/// arbitrary, but it only enters the rotation through the vanishing
+/// `C - S` (`C + S`) factor, so the rotation itself stays exact.
+fn tait_bryan_angles(quat: &UnitQuaternion) -> (f64, f64, f64) {
+    let (w, x, y, z) = (quat.w, quat.i, quat.j, quat.k);
+
+    let plus = (w + y).hypot(z - x); // C + S
+    let minus = (w - y).hypot(z + x); // C - S
+    let pitch = ((plus * plus - minus * minus) / 2.0).atan2(plus * minus);
+
+    let a = 2.0 * (z + x).atan2(w - y);
+    let b = 2.0 * (z - x).atan2(w + y);
+
+    ((a + b) / 2.0, pitch, (a - b) / 2.0)
+}
+
+/// Angle between two vectors (radians), well conditioned at small and near-π angles.
+pub(crate) fn angle_between(a: &Vector3<f64>, b: &Vector3<f64>) -> f64 {
+    a.cross(b).norm().atan2(a.dot(b))
+}
+
+/// Spherical linear interpolation between two unit vectors.
+///
+/// Returns the unit vector a fraction `t` of the way along the great circle from
+/// `a` to `b`. Falls back to normalized linear interpolation when the vectors
+/// are nearly parallel. Antipodal inputs have no unique great circle.
+pub(crate) fn slerp(a: &Vector3<f64>, b: &Vector3<f64>, t: f64) -> Vector3<f64> {
+    let omega = angle_between(a, b);
+    let sin_omega = omega.sin();
+
+    if sin_omega.abs() < 1e-12 {
+        return (a * (1.0 - t) + b * t).normalize();
+    }
+
+    (a * ((1.0 - t) * omega).sin() + b * (t * omega).sin()) / sin_omega
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sguaba::math::RigidBodyTransform;
+    use sguaba::{Coordinate, Vector};
+    use uom::si::f64::Length;
+    use uom::si::length::meter;
+
+    use crate::frames::{Icrs, Mci};
+
+    /// Largest angular error of a rotation rebuilt through the Tait-Bryan builder.
+    fn rebuild_error(quat: UnitQuaternion) -> f64 {
+        let transform: RigidBodyTransform<Icrs, Mci> =
+            unsafe { RigidBodyTransform::new(Vector::zero(), rotation_from_quaternion(quat)) };
+
+        [Vector3::x(), Vector3::y(), Vector3::z(), Vector3::new(0.3, -0.2, 0.93).normalize()]
+            .iter()
+            .map(|v| {
+                #[allow(deprecated)]
+                let c = Coordinate::<Icrs>::from_cartesian(
+                    Length::new::<meter>(v.x),
+                    Length::new::<meter>(v.y),
+                    Length::new::<meter>(v.z),
+                );
+                let got = Vector3::from(transform.transform(c).to_cartesian().map(|l| l.get::<meter>()));
+                angle_between(&got, &quat.inverse_transform_vector(v))
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn rotations_near_gimbal_lock_survive_the_euler_round_trip() {
+        use std::f64::consts::FRAC_PI_2;
+
+        for pitch in [FRAC_PI_2, FRAC_PI_2 - 1e-9, FRAC_PI_2 - 1e-5, -FRAC_PI_2, -FRAC_PI_2 + 1e-7, 0.3] {
+            for (yaw, roll) in [(0.0, 0.0), (1.2, -0.4), (-2.9, 3.1)] {
+                let quat = UnitQuaternion::from_euler_angles(roll, pitch, yaw);
+                let error = rebuild_error(quat);
+                assert!(error < 1e-14, "pitch {pitch}: error {error} rad");
+            }
+        }
+
+        let error = rebuild_error(*crate::constants::mci_to_icrs_rotation());
+        assert!(error < 1e-14, "MCI: error {error} rad");
+    }
+}
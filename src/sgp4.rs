@@ -0,0 +1,1013 @@
+//! SGP4/SDP4 analytical propagation of TLE mean elements.
+//!
+//! Follows the reference implementation of Vallado et al., "Revisiting
+//! Spacetrack Report #3" (AIAA 2006-6753), with WGS-72 constants as used to
+//! generate the element sets. Orbits with periods of 225 minutes or more use
+//! the deep-space (SDP4) lunar-solar and resonance terms. Outputs are in the
+//! TEME frame (km, km/s).
+
+use std::f64::consts::TAU;
+
+use super::errors::{CelestialError, CelestialResult};
+
+/// WGS-72 gravitational parameter (km³/s²).
+const MU: f64 = 398_600.8;
+
+/// WGS-72 equatorial radius (km).
+const RADIUS_EARTH_KM: f64 = 6378.135;
+
+/// WGS-72 zonal harmonics.
+const J2: f64 = 0.001_082_616;
+const J3: f64 = -0.000_002_538_81;
+const J4: f64 = -0.000_001_655_97;
+const J3OJ2: f64 = J3 / J2;
+
+/// Orbital period (minutes) above which the deep-space (SDP4) theory applies.
+pub(crate) const DEEP_SPACE_PERIOD_MIN: f64 = 225.0;
+
+const X2O3: f64 = 2.0 / 3.0;
+
+/// `sqrt(μ)` in Earth radii³ per minute², the SGP4 time unit scaling.
+fn xke() -> f64 {
+    60.0 / (RADIUS_EARTH_KM.powi(3) / MU).sqrt()
+}
+
+/// Mean elements as read from a TLE, in SGP4 units.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MeanElements {
+    /// Kozai mean motion (rad/min)
+    pub no_kozai: f64,
+    /// Eccentricity
+    pub ecco: f64,
+    /// Inclination (rad)
+    pub inclo: f64,
+    /// Right ascension of ascending node (rad)
+    pub nodeo: f64,
+    /// Argument of perigee (rad)
+    pub argpo: f64,
+    /// Mean anomaly (rad)
+    pub mo: f64,
+    /// Drag term (1/Earth radii)
+    pub bstar: f64,
+    /// Julian Date of the element set epoch (UTC, used as UT1)
+    pub epoch_jd: f64,
+}
+
+impl MeanElements {
+    /// Brouwer mean motion (rad/min), recovered from the Kozai value in the TLE.
+    pub(crate) fn no_unkozai(&self) -> f64 {
+        let cosio = self.inclo.cos();
+        let omeosq = 1.0 - self.ecco * self.ecco;
+        let ak = (xke() / self.no_kozai).powf(X2O3);
+        let d1 = 0.75 * J2 * (3.0 * cosio * cosio - 1.0) / (omeosq.sqrt() * omeosq);
+        let del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        let del = d1 / (adel * adel);
+        self.no_kozai / (1.0 + del)
+    }
+
+    /// Whether the orbit needs the deep-space (SDP4) theory.
+    pub(crate) fn is_deep_space(&self) -> bool {
+        TAU / self.no_unkozai() >= DEEP_SPACE_PERIOD_MIN
+    }
+}
+
+/// Initialized SGP4 propagator for one element set.
+#[derive(Clone, Debug)]
+pub(crate) struct Sgp4 {
+    elements: MeanElements,
+    no_unkozai: f64,
+    isimp: bool,
+    con41: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    eta: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    sinmao: f64,
+    mdot: f64,
+    argpdot: f64,
+    nodedot: f64,
+    nodecf: f64,
+    omgcof: f64,
+    xmcof: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    xlcof: f64,
+    aycof: f64,
+    deep_space: Option<DeepSpace>,
+}
+
+impl Sgp4 {
+    /// Initialize the secular and drag coefficients (`sgp4init`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` for non-elliptic or
+    /// non-positive mean motion elements.
+    pub(crate) fn new(elements: MeanElements) -> CelestialResult<Self> {
+        let MeanElements { ecco, inclo, argpo, mo, bstar, .. } = elements;
+
+        if !(0.0..1.0).contains(&ecco) || elements.no_kozai <= 0.0 {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: format!("invalid SGP4 mean elements (e = {ecco}, n = {})", elements.no_kozai),
+            });
+        }
+        let xke = xke();
+        let no_unkozai = elements.no_unkozai();
+
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let (sinio, cosio) = inclo.sin_cos();
+        let cosio2 = cosio * cosio;
+
+        let ao = (xke / no_unkozai).powf(X2O3);
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+
+        // Perigees below 220 km, and deep-space orbits, use the simplified drag model
+        let deep = elements.is_deep_space();
+        let isimp = deep || rp < 220.0 / RADIUS_EARTH_KM + 1.0;
+
+        // Atmospheric density reference altitude, lowered for low perigees
+        let mut sfour = 78.0 / RADIUS_EARTH_KM + 1.0;
+        let mut qzms24 = ((120.0 - 78.0) / RADIUS_EARTH_KM).powi(4);
+        let perigee = (rp - 1.0) * RADIUS_EARTH_KM;
+        if perigee < 156.0 {
+            sfour = if perigee < 98.0 { 20.0 } else { perigee - 78.0 };
+            qzms24 = ((120.0 - sfour) / RADIUS_EARTH_KM).powi(4);
+            sfour = sfour / RADIUS_EARTH_KM + 1.0;
+        }
+
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+
+        let cc2 = coef1
+            * no_unkozai
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = bstar * cc2;
+        let cc3 = if ecco > 1.0e-4 {
+            -2.0 * coef * tsi * J3OJ2 * no_unkozai * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no_unkozai
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75 * x1mth2 * (2.0 * etasq - eeta * (1.0 + etasq)) * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Secular rates from J2 and J4
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no_unkozai;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no_unkozai;
+        let mdot = no_unkozai
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1 + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+
+        let omgcof = bstar * cc3 * argpo.cos();
+        let xmcof = if ecco > 1.0e-4 { -X2O3 * coef * bstar / eeta } else { 0.0 };
+        let nodecf = 3.5 * omeosq * xhdot1 * cc1;
+        let t2cof = 1.5 * cc1;
+
+        // Guard the 1/(1 + cos i) singularity for retrograde equatorial orbits
+        let one_plus_cosio = if (cosio + 1.0).abs() > 1.5e-12 { 1.0 + cosio } else { 1.5e-12 };
+        let xlcof = -0.25 * J3OJ2 * sinio * (3.0 + 5.0 * cosio) / one_plus_cosio;
+        let aycof = -0.5 * J3OJ2 * sinio;
+        let delmo = (1.0 + eta * mo.cos()).powi(3);
+        let sinmao = mo.sin();
+        let x7thm1 = 7.0 * cosio2 - 1.0;
+
+        let deep_space = deep.then(|| {
+            DeepSpace::new(&elements, no_unkozai, &SecularRates { mdot, argpdot, nodedot })
+        });
+
+        let (mut d2, mut d3, mut d4) = (0.0, 0.0, 0.0);
+        let (mut t3cof, mut t4cof, mut t5cof) = (0.0, 0.0, 0.0);
+        if !isimp {
+            let cc1sq = cc1 * cc1;
+            d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            d3 = (17.0 * ao + sfour) * temp;
+            d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            t3cof = d2 + 2.0 * cc1sq;
+            t4cof = 0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq));
+            t5cof = 0.2 * (3.0 * d4 + 12.0 * cc1 * d3 + 6.0 * d2 * d2 + 15.0 * cc1sq * (2.0 * d2 + cc1sq));
+        }
+
+        Ok(Self {
+            elements,
+            no_unkozai,
+            isimp,
+            con41,
+            x1mth2,
+            x7thm1,
+            eta,
+            cc1,
+            cc4,
+            cc5,
+            d2,
+            d3,
+            d4,
+            delmo,
+            sinmao,
+            mdot,
+            argpdot,
+            nodedot,
+            nodecf,
+            omgcof,
+            xmcof,
+            t2cof,
+            t3cof,
+            t4cof,
+            t5cof,
+            xlcof,
+            aycof,
+            deep_space,
+        })
+    }
+
+    /// Position (km) and velocity (km/s) in TEME, `tsince` minutes from the TLE epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::NumericalPrecisionError` when the perturbed
+    /// elements become invalid (eccentricity outside [0, 1), negative
+    /// semi-latus rectum) or the satellite has decayed below the surface.
+    pub(crate) fn propagate(&self, tsince: f64) -> CelestialResult<([f64; 3], [f64; 3])> {
+        let MeanElements { ecco, inclo, nodeo, argpo, mo, bstar, .. } = self.elements;
+        let xke = xke();
+        let fail = |reason: &str| {
+            Err(CelestialError::NumericalPrecisionError {
+                reason: format!("SGP4 at {tsince:.3} min: {reason}"),
+            })
+        };
+
+        // Secular gravity and atmospheric drag
+        let xmdf = mo + self.mdot * tsince;
+        let argpdf = argpo + self.argpdot * tsince;
+        let nodedf = nodeo + self.nodedot * tsince;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let t2 = tsince * tsince;
+        let mut nodem = nodedf + self.nodecf * t2;
+        let mut tempa = 1.0 - self.cc1 * tsince;
+        let mut tempe = bstar * self.cc4 * tsince;
+        let mut templ = self.t2cof * t2;
+
+        if !self.isimp {
+            let delomg = self.omgcof * tsince;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            let temp = delomg + delm;
+            mm = xmdf + temp;
+            argpm = argpdf - temp;
+            let t3 = t2 * tsince;
+            let t4 = t3 * tsince;
+            tempa = tempa - self.d2 * t2 - self.d3 * t3 - self.d4 * t4;
+            tempe += bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + tsince * self.t5cof);
+        }
+
+        let mut nm = self.no_unkozai;
+        let mut em = ecco;
+        let mut inclm = inclo;
+        if let Some(deep) = &self.deep_space {
+            nm = deep.secular(
+                tsince,
+                self.no_unkozai,
+                argpo,
+                self.argpdot,
+                &mut em,
+                &mut argpm,
+                &mut inclm,
+                &mut mm,
+                &mut nodem,
+            );
+        }
+        if nm <= 0.0 {
+            return fail("non-positive mean motion");
+        }
+
+        let am = (xke / nm).powf(X2O3) * tempa * tempa;
+        let nm = xke / am.powf(1.5);
+        em -= tempe;
+        if !(-0.001..1.0).contains(&em) {
+            return fail("eccentricity out of range");
+        }
+        em = em.max(1.0e-6);
+
+        mm += self.no_unkozai * templ;
+        let xlm = (mm + argpm + nodem) % TAU;
+        nodem %= TAU;
+        argpm %= TAU;
+        mm = (xlm - argpm - nodem) % TAU;
+
+        // Lunar-solar periodics
+        let (mut ep, mut xincp, mut argpp, mut nodep, mut mp) = (em, inclm, argpm, nodem, mm);
+        let (mut aycof, mut xlcof) = (self.aycof, self.xlcof);
+        if let Some(deep) = &self.deep_space {
+            deep.periodics(tsince, &mut ep, &mut xincp, &mut nodep, &mut argpp, &mut mp);
+            if xincp < 0.0 {
+                xincp = -xincp;
+                nodep += std::f64::consts::PI;
+                argpp -= std::f64::consts::PI;
+            }
+            if !(0.0..=1.0).contains(&ep) {
+                return fail("eccentricity out of range after lunar-solar periodics");
+            }
+
+            let (sinip, cosip) = xincp.sin_cos();
+            let one_plus_cosip = if (cosip + 1.0).abs() > 1.5e-12 { 1.0 + cosip } else { 1.5e-12 };
+            aycof = -0.5 * J3OJ2 * sinip;
+            xlcof = -0.25 * J3OJ2 * sinip * (3.0 + 5.0 * cosip) / one_plus_cosip;
+        }
+
+        // Long-period periodics
+        let (sinip, cosip) = xincp.sin_cos();
+        let axnl = ep * argpp.cos();
+        let temp = 1.0 / (am * (1.0 - ep * ep));
+        let aynl = ep * argpp.sin() + temp * aycof;
+        let xl = mp + argpp + nodep + temp * xlcof * axnl;
+
+        // Kepler's equation in (u, axnl, aynl)
+        let u = (xl - nodep) % TAU;
+        let mut eo1 = u;
+        let mut iterations = 0;
+        let (sineo1, coseo1) = loop {
+            let (sin_e, cos_e) = eo1.sin_cos();
+            let delta = (u - aynl * cos_e + axnl * sin_e - eo1) / (1.0 - cos_e * axnl - sin_e * aynl);
+            eo1 += delta.clamp(-0.95, 0.95);
+            iterations += 1;
+            if delta.abs() < 1.0e-12 || iterations == 10 {
+                break (sin_e, cos_e);
+            }
+        };
+
+        // Short-period periodics
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return fail("negative semi-latus rectum");
+        }
+
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * J2 * temp;
+        let temp2 = temp1 * temp;
+
+        let (con41, x1mth2, x7thm1) = if self.deep_space.is_some() {
+            let cosisq = cosip * cosip;
+            (3.0 * cosisq - 1.0, 1.0 - cosisq, 7.0 * cosisq - 1.0)
+        } else {
+            (self.con41, self.x1mth2, self.x7thm1)
+        };
+
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * con41) + 0.5 * temp1 * x1mth2 * cos2u;
+        let su = su - 0.25 * temp2 * x7thm1 * sin2u;
+        let xnode = nodep + 1.5 * temp2 * cosip * sin2u;
+        let xinc = xincp + 1.5 * temp2 * cosip * sinip * cos2u;
+        let mvt = rdotl - nm * temp1 * x1mth2 * sin2u / xke;
+        let rvdot = rvdotl + nm * temp1 * (x1mth2 * cos2u + 1.5 * con41) / xke;
+
+        // Orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let ux = [xmx * sinsu + cnod * cossu, xmy * sinsu + snod * cossu, sini * sinsu];
+        let vx = [xmx * cossu - cnod * sinsu, xmy * cossu - snod * sinsu, sini * cossu];
+
+        if mrt < 1.0 {
+            return fail("satellite has decayed");
+        }
+
+        let vkmpersec = RADIUS_EARTH_KM * xke / 60.0;
+        let position = ux.map(|c| mrt * c * RADIUS_EARTH_KM);
+        let velocity = [0, 1, 2].map(|i| (mvt * ux[i] + rvdot * vx[i]) * vkmpersec);
+
+        Ok((position, velocity))
+    }
+}
+
+// =======================================================================================
+// DEEP SPACE (SDP4)
+// =======================================================================================
+
+/// Earth rotation rate used by the resonance terms (rad/min).
+const RPTIM: f64 = 4.375_269_088_011_3e-3;
+
+/// Solar and lunar mean motions (rad/min) and orbital eccentricities.
+const ZNS: f64 = 1.194_59e-5;
+const ZES: f64 = 0.016_75;
+const ZNL: f64 = 1.583_521_8e-4;
+const ZEL: f64 = 0.054_90;
+
+/// Greenwich mean sidereal time (IAU 1982) at a UT1 Julian Date, in radians.
+fn gstime(jd_ut1: f64) -> f64 {
+    let tut1 = (jd_ut1 - 2_451_545.0) / 36525.0;
+    let seconds = -6.2e-6 * tut1.powi(3)
+        + 0.093_104 * tut1 * tut1
+        + (876_600.0 * 3600.0 + 8_640_184.812_866) * tut1
+        + 67_310.548_41;
+    (seconds.to_radians() / 240.0).rem_euclid(TAU)
+}
+
+/// Sines and cosines of the orbit orientation shared by the third-body terms.
+struct OrbitGeometry {
+    sinim: f64,
+    cosim: f64,
+    sinomm: f64,
+    cosomm: f64,
+    em: f64,
+    emsq: f64,
+}
+
+/// Expansion coefficients of one perturbing body's potential (`dscom` loop body).
+#[derive(Clone, Copy, Debug)]
+struct ThirdBody {
+    s1: f64,
+    s2: f64,
+    s3: f64,
+    s4: f64,
+    s5: f64,
+    s6: f64,
+    s7: f64,
+    z1: f64,
+    z2: f64,
+    z3: f64,
+    z11: f64,
+    z12: f64,
+    z13: f64,
+    z21: f64,
+    z22: f64,
+    z23: f64,
+    z31: f64,
+    z32: f64,
+    z33: f64,
+}
+
+impl ThirdBody {
+    /// Coefficients for a body whose orbit has perigee angle `g`, inclination
+    /// `i` and node `h` relative to the satellite's reference plane, and
+    /// strength `cc`.
+    fn new(
+        (zsing, zcosg): (f64, f64),
+        (zsini, zcosi): (f64, f64),
+        (zsinh, zcosh): (f64, f64),
+        cc: f64,
+        xnoi: f64,
+        orbit: &OrbitGeometry,
+    ) -> Self {
+        let OrbitGeometry { sinim, cosim, sinomm, cosomm, em, emsq } = *orbit;
+        let betasq = 1.0 - emsq;
+        let rtemsq = betasq.sqrt();
+
+        let a1 = zcosg * zcosh + zsing * zcosi * zsinh;
+        let a3 = -zsing * zcosh + zcosg * zcosi * zsinh;
+        let a7 = -zcosg * zsinh + zsing * zcosi * zcosh;
+        let a8 = zsing * zsini;
+        let a9 = zsing * zsinh + zcosg * zcosi * zcosh;
+        let a10 = zcosg * zsini;
+        let a2 = cosim * a7 + sinim * a8;
+        let a4 = cosim * a9 + sinim * a10;
+        let a5 = -sinim * a7 + cosim * a8;
+        let a6 = -sinim * a9 + cosim * a10;
+
+        let x1 = a1 * cosomm + a2 * sinomm;
+        let x2 = a3 * cosomm + a4 * sinomm;
+        let x3 = -a1 * sinomm + a2 * cosomm;
+        let x4 = -a3 * sinomm + a4 * cosomm;
+        let x5 = a5 * sinomm;
+        let x6 = a6 * sinomm;
+        let x7 = a5 * cosomm;
+        let x8 = a6 * cosomm;
+
+        let z31 = 12.0 * x1 * x1 - 3.0 * x3 * x3;
+        let z32 = 24.0 * x1 * x2 - 6.0 * x3 * x4;
+        let z33 = 12.0 * x2 * x2 - 3.0 * x4 * x4;
+        let z1 = 3.0 * (a1 * a1 + a2 * a2) + z31 * emsq;
+        let z2 = 6.0 * (a1 * a3 + a2 * a4) + z32 * emsq;
+        let z3 = 3.0 * (a3 * a3 + a4 * a4) + z33 * emsq;
+        let z11 = -6.0 * a1 * a5 + emsq * (-24.0 * x1 * x7 - 6.0 * x3 * x5);
+        let z12 = -6.0 * (a1 * a6 + a3 * a5)
+            + emsq * (-24.0 * (x2 * x7 + x1 * x8) - 6.0 * (x3 * x6 + x4 * x5));
+        let z13 = -6.0 * a3 * a6 + emsq * (-24.0 * x2 * x8 - 6.0 * x4 * x6);
+        let z21 = 6.0 * a2 * a5 + emsq * (24.0 * x1 * x5 - 6.0 * x3 * x7);
+        let z22 = 6.0 * (a4 * a5 + a2 * a6)
+            + emsq * (24.0 * (x2 * x5 + x1 * x6) - 6.0 * (x4 * x7 + x3 * x8));
+        let z23 = 6.0 * a4 * a6 + emsq * (24.0 * x2 * x6 - 6.0 * x4 * x8);
+
+        let s3 = cc * xnoi;
+        let s4 = s3 * rtemsq;
+
+        Self {
+            s1: -15.0 * em * s4,
+            s2: -0.5 * s3 / rtemsq,
+            s3,
+            s4,
+            s5: x1 * x3 + x2 * x4,
+            s6: x2 * x3 + x1 * x4,
+            s7: x2 * x4 - x1 * x3,
+            z1: z1 + z1 + betasq * z31,
+            z2: z2 + z2 + betasq * z32,
+            z3: z3 + z3 + betasq * z33,
+            z11,
+            z12,
+            z13,
+            z21,
+            z22,
+            z23,
+            z31,
+            z32,
+            z33,
+        }
+    }
+
+    /// Long-period periodic coefficients for a body of orbital eccentricity `ze`.
+    fn periodics(&self, emsq: f64, ze: f64, zm0: f64, zn: f64) -> BodyPeriodics {
+        BodyPeriodics {
+            e2: 2.0 * self.s1 * self.s6,
+            e3: 2.0 * self.s1 * self.s7,
+            i2: 2.0 * self.s2 * self.z12,
+            i3: 2.0 * self.s2 * (self.z13 - self.z11),
+            l2: -2.0 * self.s3 * self.z2,
+            l3: -2.0 * self.s3 * (self.z3 - self.z1),
+            l4: -2.0 * self.s3 * (-21.0 - 9.0 * emsq) * ze,
+            gh2: 2.0 * self.s4 * self.z32,
+            gh3: 2.0 * self.s4 * (self.z33 - self.z31),
+            gh4: -18.0 * self.s4 * ze,
+            h2: -2.0 * self.s2 * self.z22,
+            h3: -2.0 * self.s2 * (self.z23 - self.z21),
+            zm0,
+            zn,
+            ze,
+        }
+    }
+
+    /// Secular rates `(de, di, dl, dgh, dh)` per minute from a body with mean motion `zn`.
+    fn secular_rates(&self, emsq: f64, zn: f64) -> [f64; 5] {
+        [
+            self.s1 * zn * self.s5,
+            self.s2 * zn * (self.z11 + self.z13),
+            -zn * self.s3 * (self.z1 + self.z3 - 14.0 - 6.0 * emsq),
+            self.s4 * zn * (self.z31 + self.z33 - 6.0),
+            -zn * self.s2 * (self.z21 + self.z23),
+        ]
+    }
+}
+
+/// Lunar or solar long-period periodic terms (`dpper` coefficients).
+#[derive(Clone, Copy, Debug)]
+struct BodyPeriodics {
+    e2: f64,
+    e3: f64,
+    i2: f64,
+    i3: f64,
+    l2: f64,
+    l3: f64,
+    l4: f64,
+    gh2: f64,
+    gh3: f64,
+    gh4: f64,
+    h2: f64,
+    h3: f64,
+    /// Mean anomaly of the body at epoch (rad)
+    zm0: f64,
+    /// Mean motion of the body (rad/min)
+    zn: f64,
+    /// Eccentricity of the body's orbit
+    ze: f64,
+}
+
+impl BodyPeriodics {
+    /// Perturbations `[e, i, l, gh, h]` at `t` minutes from epoch.
+    fn at(&self, t: f64) -> [f64; 5] {
+        let zm = self.zm0 + self.zn * t;
+        let zf = zm + 2.0 * self.ze * zm.sin();
+        let sinzf = zf.sin();
+        let f2 = 0.5 * sinzf * sinzf - 0.25;
+        let f3 = -0.5 * sinzf * zf.cos();
+
+        [
+            self.e2 * f2 + self.e3 * f3,
+            self.i2 * f2 + self.i3 * f3,
+            self.l2 * f2 + self.l3 * f3 + self.l4 * sinzf,
+            self.gh2 * f2 + self.gh3 * f3 + self.gh4 * sinzf,
+            self.h2 * f2 + self.h3 * f3,
+        ]
+    }
+}
+
+/// Geopotential resonance for periods near one day or half a day (`dsinit`).
+#[derive(Clone, Copy, Debug)]
+enum Resonance {
+    None,
+    /// Geosynchronous (period near one sidereal day)
+    Synchronous { del1: f64, del2: f64, del3: f64 },
+    /// Half-day, highly eccentric (Molniya-type)
+    HalfDay { d: [f64; 10] },
+}
+
+/// Deep-space secular rates, periodics and resonance state.
+#[derive(Clone, Debug)]
+struct DeepSpace {
+    solar: BodyPeriodics,
+    lunar: BodyPeriodics,
+    resonance: Resonance,
+    gsto: f64,
+    dedt: f64,
+    didt: f64,
+    dmdt: f64,
+    dnodt: f64,
+    domdt: f64,
+    xfact: f64,
+    xlamo: f64,
+}
+
+/// Secular rates of the elements that the deep-space terms adjust.
+struct SecularRates {
+    mdot: f64,
+    argpdot: f64,
+    nodedot: f64,
+}
+
+impl DeepSpace {
+    /// Initialize lunar-solar and resonance terms (`dscom` and `dsinit`).
+    fn new(elements: &MeanElements, no_unkozai: f64, rates: &SecularRates) -> Self {
+        let MeanElements { ecco, inclo, nodeo, argpo, mo, epoch_jd, .. } = *elements;
+
+        // Days since 1950 Jan 0.0, as used by the original theory
+        let epoch = epoch_jd - 2_433_281.5;
+        let gsto = gstime(epoch_jd);
+
+        let (snodm, cnodm) = nodeo.sin_cos();
+        let (sinim, cosim) = inclo.sin_cos();
+        let (sinomm, cosomm) = argpo.sin_cos();
+        let emsq = ecco * ecco;
+        let orbit = OrbitGeometry { sinim, cosim, sinomm, cosomm, em: ecco, emsq };
+
+        // Lunar orbit orientation at epoch
+        let day = epoch + 18_261.5;
+        let xnodce = (4.523_602_0 - 9.242_202_9e-4 * day) % TAU;
+        let (stem, ctem) = xnodce.sin_cos();
+        let zcosil = 0.913_751_64 - 0.035_680_96 * ctem;
+        let zsinil = (1.0 - zcosil * zcosil).sqrt();
+        let zsinhl = 0.089_683_511 * stem / zsinil;
+        let zcoshl = (1.0 - zsinhl * zsinhl).sqrt();
+        let gam = 5.835_151_4 + 0.001_944_368_0 * day;
+        let zx = (0.397_854_16 * stem / zsinil).atan2(zcoshl * ctem + 0.917_448_67 * zsinhl * stem);
+        let zx = gam + zx - xnodce;
+
+        let xnoi = 1.0 / no_unkozai;
+        let sun = ThirdBody::new(
+            (-0.980_884_58, 0.194_590_5),
+            (0.397_854_16, 0.917_448_67),
+            (snodm, cnodm),
+            2.986_479_7e-6,
+            xnoi,
+            &orbit,
+        );
+        let moon = ThirdBody::new(
+            zx.sin_cos(),
+            (zsinil, zcosil),
+            (snodm * zcoshl - cnodm * zsinhl, zcoshl * cnodm + zsinhl * snodm),
+            4.796_806_5e-7,
+            xnoi,
+            &orbit,
+        );
+
+        let zmol = (4.719_967_2 + 0.229_971_50 * day - gam) % TAU;
+        let zmos = (6.256_583_7 + 0.017_201_977 * day) % TAU;
+        let solar = sun.periodics(emsq, ZES, zmos, ZNS);
+        let lunar = moon.periodics(emsq, ZEL, zmol, ZNL);
+
+        // Secular lunar-solar rates
+        let [ses, sis, sls, sghs, shs] = sun.secular_rates(emsq, ZNS);
+        let [sel, sil, sll, sghl, shl] = moon.secular_rates(emsq, ZNL);
+        // Within 3° of the equator the node is undefined and its rate is dropped
+        let near_equatorial = !(5.235_987_7e-2..=std::f64::consts::PI - 5.235_987_7e-2).contains(&inclo);
+        let dh = if near_equatorial { 0.0 } else { shs + shl };
+        let (domdt, dnodt) = if sinim != 0.0 {
+            (sghs + sghl - cosim / sinim * dh, dh / sinim)
+        } else {
+            (sghs + sghl, dh)
+        };
+        let (dedt, didt, dmdt) = (ses + sel, sis + sil, sls + sll);
+
+        // Resonance
+        let theta = gsto % TAU;
+        let aonv = (no_unkozai / xke()).powf(X2O3);
+        let mut resonance = Resonance::None;
+        let mut xfact = 0.0;
+        let mut xlamo = 0.0;
+
+        if (8.26e-3..=9.24e-3).contains(&no_unkozai) && ecco >= 0.5 {
+            let d = half_day_coefficients(no_unkozai, ecco, sinim, cosim, aonv);
+            resonance = Resonance::HalfDay { d };
+            xlamo = (mo + nodeo + nodeo - theta - theta) % TAU;
+            xfact = rates.mdot + dmdt + 2.0 * (rates.nodedot + dnodt - RPTIM) - no_unkozai;
+        } else if no_unkozai > 0.003_490_658_5 && no_unkozai < 0.005_235_987_7 {
+            let g200 = 1.0 + emsq * (-2.5 + 0.8125 * emsq);
+            let g310 = 1.0 + 2.0 * emsq;
+            let g300 = 1.0 + emsq * (-6.0 + 6.609_37 * emsq);
+            let f220 = 0.75 * (1.0 + cosim) * (1.0 + cosim);
+            let f311 = 0.9375 * sinim * sinim * (1.0 + 3.0 * cosim) - 0.75 * (1.0 + cosim);
+            let f330 = 1.875 * (1.0 + cosim).powi(3);
+            let del1 = 3.0 * no_unkozai * no_unkozai * aonv * aonv;
+
+            resonance = Resonance::Synchronous {
+                del1: del1 * f311 * g310 * 2.146_074_8e-6 * aonv,
+                del2: 2.0 * del1 * f220 * g200 * 1.789_167_9e-6,
+                del3: 3.0 * del1 * f330 * g300 * 2.212_301_5e-7 * aonv,
+            };
+            xlamo = (mo + nodeo + argpo - theta) % TAU;
+            xfact = rates.mdot + rates.argpdot + rates.nodedot - RPTIM + dmdt + domdt + dnodt - no_unkozai;
+        }
+
+        Self { solar, lunar, resonance, gsto, dedt, didt, dmdt, dnodt, domdt, xfact, xlamo }
+    }
+
+    /// Apply secular lunar-solar rates and integrate the resonance (`dspace`).
+    ///
+    /// Updates the mean `(em, argpm, inclm, mm, nodem)` in place and returns the
+    /// mean motion. The resonance integration always restarts from epoch, so
+    /// results do not depend on the order of calls.
+    #[allow(clippy::too_many_arguments)]
+    fn secular(
+        &self,
+        t: f64,
+        no_unkozai: f64,
+        argpo: f64,
+        argpdot: f64,
+        em: &mut f64,
+        argpm: &mut f64,
+        inclm: &mut f64,
+        mm: &mut f64,
+        nodem: &mut f64,
+    ) -> f64 {
+        const STEP: f64 = 720.0;
+        const STEP2: f64 = 259_200.0;
+
+        *em += self.dedt * t;
+        *inclm += self.didt * t;
+        *argpm += self.domdt * t;
+        *nodem += self.dnodt * t;
+        *mm += self.dmdt * t;
+
+        if let Resonance::None = self.resonance {
+            return no_unkozai;
+        }
+
+        let theta = (self.gsto + t * RPTIM) % TAU;
+        let delt = if t > 0.0 { STEP } else { -STEP };
+        let mut atime = 0.0;
+        let mut xni = no_unkozai;
+        let mut xli = self.xlamo;
+
+        let (xndt, xldot, xnddt, ft) = loop {
+            let (xndt, xnddt) = self.resonance_rates(xli, argpo + argpdot * atime);
+            let xldot = xni + self.xfact;
+            let xnddt = xnddt * xldot;
+
+            if (t - atime).abs() < STEP {
+                break (xndt, xldot, xnddt, t - atime);
+            }
+
+            xli += xldot * delt + xndt * STEP2;
+            xni += xndt * delt + xnddt * STEP2;
+            atime += delt;
+        };
+
+        let nm = xni + xndt * ft + xnddt * ft * ft * 0.5;
+        let xl = xli + xldot * ft + xndt * ft * ft * 0.5;
+        *mm = match self.resonance {
+            Resonance::Synchronous { .. } => xl - *nodem - *argpm + theta,
+            _ => xl - 2.0 * *nodem + 2.0 * theta,
+        };
+
+        nm
+    }
+
+    /// Resonance contributions to the mean-motion rate and its derivative
+    /// (before multiplication by the mean longitude rate).
+    fn resonance_rates(&self, xli: f64, xomi: f64) -> (f64, f64) {
+        const FASX2: f64 = 0.131_309_08;
+        const FASX4: f64 = 2.884_319_8;
+        const FASX6: f64 = 0.374_480_87;
+        const G22: f64 = 5.768_639_6;
+        const G32: f64 = 0.952_408_98;
+        const G44: f64 = 1.801_499_8;
+        const G52: f64 = 1.050_833_0;
+        const G54: f64 = 4.410_889_8;
+
+        match self.resonance {
+            Resonance::None => (0.0, 0.0),
+            Resonance::Synchronous { del1, del2, del3 } => (
+                del1 * (xli - FASX2).sin()
+                    + del2 * (2.0 * (xli - FASX4)).sin()
+                    + del3 * (3.0 * (xli - FASX6)).sin(),
+                del1 * (xli - FASX2).cos()
+                    + 2.0 * del2 * (2.0 * (xli - FASX4)).cos()
+                    + 3.0 * del3 * (3.0 * (xli - FASX6)).cos(),
+            ),
+            Resonance::HalfDay { d } => {
+                let [d2201, d2211, d3210, d3222, d4410, d4422, d5220, d5232, d5421, d5433] = d;
+                let x2omi = xomi + xomi;
+                let x2li = xli + xli;
+                (
+                    d2201 * (x2omi + xli - G22).sin()
+                        + d2211 * (xli - G22).sin()
+                        + d3210 * (xomi + xli - G32).sin()
+                        + d3222 * (-xomi + xli - G32).sin()
+                        + d4410 * (x2omi + x2li - G44).sin()
+                        + d4422 * (x2li - G44).sin()
+                        + d5220 * (xomi + xli - G52).sin()
+                        + d5232 * (-xomi + xli - G52).sin()
+                        + d5421 * (xomi + x2li - G54).sin()
+                        + d5433 * (-xomi + x2li - G54).sin(),
+                    d2201 * (x2omi + xli - G22).cos()
+                        + d2211 * (xli - G22).cos()
+                        + d3210 * (xomi + xli - G32).cos()
+                        + d3222 * (-xomi + xli - G32).cos()
+                        + d5220 * (xomi + xli - G52).cos()
+                        + d5232 * (-xomi + xli - G52).cos()
+                        + 2.0
+                            * (d4410 * (x2omi + x2li - G44).cos()
+                                + d4422 * (x2li - G44).cos()
+                                + d5421 * (xomi + x2li - G54).cos()
+                                + d5433 * (-xomi + x2li - G54).cos()),
+                )
+            }
+        }
+    }
+
+    /// Add lunar-solar long-period periodics to the mean elements (`dpper`).
+    fn periodics(&self, t: f64, ep: &mut f64, inclp: &mut f64, nodep: &mut f64, argpp: &mut f64, mp: &mut f64) {
+        let solar = self.solar.at(t);
+        let lunar = self.lunar.at(t);
+        let [pe, pinc, pl, mut pgh, mut ph] = [0, 1, 2, 3, 4].map(|i| solar[i] + lunar[i]);
+
+        *inclp += pinc;
+        *ep += pe;
+        let (sinip, cosip) = inclp.sin_cos();
+
+        if *inclp >= 0.2 {
+            ph /= sinip;
+            pgh -= cosip * ph;
+            *argpp += pgh;
+            *nodep += ph;
+            *mp += pl;
+        } else {
+            // Lyddane modification for low inclinations
+            let (sinop, cosop) = nodep.sin_cos();
+            let alfdp = sinip * sinop + (ph * cosop + pinc * cosip * sinop);
+            let betdp = sinip * cosop + (-ph * sinop + pinc * cosip * cosop);
+            *nodep %= TAU;
+            let xls = *mp + *argpp + cosip * *nodep + pl + pgh - pinc * *nodep * sinip;
+            let xnoh = *nodep;
+            *nodep = alfdp.atan2(betdp);
+            if (xnoh - *nodep).abs() > std::f64::consts::PI {
+                if *nodep < xnoh {
+                    *nodep += TAU;
+                } else {
+                    *nodep -= TAU;
+                }
+            }
+            *mp += pl;
+            *argpp = xls - *mp - cosip * *nodep;
+        }
+    }
+}
+
+/// Half-day resonance coefficients `d2201 ... d5433`.
+fn half_day_coefficients(nm: f64, em: f64, sinim: f64, cosim: f64, aonv: f64) -> [f64; 10] {
+    const ROOT22: f64 = 1.789_167_9e-6;
+    const ROOT32: f64 = 3.739_379_2e-7;
+    const ROOT44: f64 = 7.363_695_3e-9;
+    const ROOT52: f64 = 1.142_863_9e-7;
+    const ROOT54: f64 = 2.176_580_3e-9;
+
+    let cosisq = cosim * cosim;
+    let emsq = em * em;
+    let eoc = em * emsq;
+
+    let g201 = -0.306 - (em - 0.64) * 0.440;
+    let (g211, g310, g322, g410, g422, g520) = if em <= 0.65 {
+        (
+            3.616 - 13.2470 * em + 16.2900 * emsq,
+            -19.302 + 117.3900 * em - 228.4190 * emsq + 156.5910 * eoc,
+            -18.9068 + 109.7927 * em - 214.6334 * emsq + 146.5816 * eoc,
+            -41.122 + 242.6940 * em - 471.0940 * emsq + 313.9530 * eoc,
+            -146.407 + 841.8800 * em - 1629.014 * emsq + 1083.4350 * eoc,
+            -532.114 + 3017.977 * em - 5740.032 * emsq + 3708.2760 * eoc,
+        )
+    } else {
+        (
+            -72.099 + 331.819 * em - 508.738 * emsq + 266.724 * eoc,
+            -346.844 + 1582.851 * em - 2415.925 * emsq + 1246.113 * eoc,
+            -342.585 + 1554.908 * em - 2366.899 * emsq + 1215.972 * eoc,
+            -1052.797 + 4758.686 * em - 7193.992 * emsq + 3651.957 * eoc,
+            -3581.690 + 16178.110 * em - 24462.770 * emsq + 12422.520 * eoc,
+            if em > 0.715 {
+                -5149.66 + 29936.92 * em - 54087.36 * emsq + 31324.56 * eoc
+            } else {
+                1464.74 - 4664.75 * em + 3763.64 * emsq
+            },
+        )
+    };
+    let (g533, g521, g532) = if em < 0.7 {
+        (
+            -919.22770 + 4988.6100 * em - 9064.7700 * emsq + 5542.21 * eoc,
+            -822.71072 + 4568.6173 * em - 8491.4146 * emsq + 5337.524 * eoc,
+            -853.66600 + 4690.2500 * em - 8624.7700 * emsq + 5341.4 * eoc,
+        )
+    } else {
+        (
+            -37995.780 + 161616.52 * em - 229838.20 * emsq + 109377.94 * eoc,
+            -51752.104 + 218913.95 * em - 309468.16 * emsq + 146349.42 * eoc,
+            -40023.880 + 170470.89 * em - 242699.48 * emsq + 115605.82 * eoc,
+        )
+    };
+
+    let sini2 = sinim * sinim;
+    let f220 = 0.75 * (1.0 + 2.0 * cosim + cosisq);
+    let f221 = 1.5 * sini2;
+    let f321 = 1.875 * sinim * (1.0 - 2.0 * cosim - 3.0 * cosisq);
+    let f322 = -1.875 * sinim * (1.0 + 2.0 * cosim - 3.0 * cosisq);
+    let f441 = 35.0 * sini2 * f220;
+    let f442 = 39.3750 * sini2 * sini2;
+    let f522 = 9.84375
+        * sinim
+        * (sini2 * (1.0 - 2.0 * cosim - 5.0 * cosisq) + 0.333_333_33 * (-2.0 + 4.0 * cosim + 6.0 * cosisq));
+    let f523 = sinim
+        * (4.921_875_12 * sini2 * (-2.0 - 4.0 * cosim + 10.0 * cosisq)
+            + 6.562_500_12 * (1.0 + 2.0 * cosim - 3.0 * cosisq));
+    let f542 = 29.53125 * sinim * (2.0 - 8.0 * cosim + cosisq * (-12.0 + 8.0 * cosim + 10.0 * cosisq));
+    let f543 = 29.53125 * sinim * (-2.0 - 8.0 * cosim + cosisq * (12.0 + 8.0 * cosim - 10.0 * cosisq));
+
+    let xno2 = nm * nm;
+    let ainv2 = aonv * aonv;
+    let temp1 = 3.0 * xno2 * ainv2;
+    let temp = temp1 * ROOT22;
+    let d2201 = temp * f220 * g201;
+    let d2211 = temp * f221 * g211;
+    let temp1 = temp1 * aonv;
+    let temp = temp1 * ROOT32;
+    let d3210 = temp * f321 * g310;
+    let d3222 = temp * f322 * g322;
+    let temp1 = temp1 * aonv;
+    let temp = 2.0 * temp1 * ROOT44;
+    let d4410 = temp * f441 * g410;
+    let d4422 = temp * f442 * g422;
+    let temp1 = temp1 * aonv;
+    let temp = temp1 * ROOT52;
+    let d5220 = temp * f522 * g520;
+    let d5232 = temp * f523 * g532;
+    let temp = 2.0 * temp1 * ROOT54;
+    let d5421 = temp * f542 * g521;
+    let d5433 = temp * f543 * g533;
+
+    [d2201, d2211, d3210, d3222, d4410, d4422, d5220, d5232, d5421, d5433]
+}
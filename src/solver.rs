@@ -0,0 +1,41 @@
+//! Convergence settings for iterative numerical solvers.
+//!
+//! Iterative routines (e.g. the Kepler equation solver used for orbit
+//! propagation) accept an `Option<SolverConfig>`; `None` selects
+//! [`SolverConfig::default`].
+
+/// Iteration limit and tolerance for an iterative solver.
+///
+/// A solver stops once the relative size of its last correction drops below
+/// `rel_tol`, or after `max_iter` iterations, whichever comes first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverConfig {
+    /// Maximum number of iterations
+    pub max_iter: usize,
+    /// Relative convergence tolerance on the last correction
+    pub rel_tol: f64,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            max_iter: 50,
+            rel_tol: 1e-12,
+        }
+    }
+}
+
+impl SolverConfig {
+    /// Create a solver configuration.
+    #[must_use]
+    pub const fn new(max_iter: usize, rel_tol: f64) -> Self {
+        Self { max_iter, rel_tol }
+    }
+
+    /// Whether a correction `delta` to `value` is small enough to stop iterating.
+    #[must_use]
+    pub(crate) fn converged(&self, delta: f64, value: f64) -> bool {
+        delta.abs() <= self.rel_tol * value.abs().max(1.0)
+    }
+}
@@ -7,10 +7,18 @@
 //! - UT1 (Universal Time)
 //! - TDB (Barycentric Dynamical Time)
 
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use nalgebra::Vector3;
+use sguaba::Coordinate;
+use uom::si::length::meter;
 
-use super::constants::SECONDS_PER_DAY;
+use super::astrodynamics::sun_position_icrs;
+use super::constants::{
+    earth_rotation_angle, mean_obliquity, nutation_angles, utc_to_julian_date_split, ARCSEC_TO_RAD, DAYS_PER_CENTURY,
+    J2000_JD, SECONDS_PER_DAY, SPEED_OF_LIGHT,
+};
 use super::errors::{CelestialError, CelestialResult};
+use super::frames::Icrs;
 
 /// Minimum valid epoch year for celestial calculations.
 const MIN_VALID_YEAR: i32 = 1900;
@@ -18,10 +26,6 @@ const MIN_VALID_YEAR: i32 = 1900;
 /// Maximum valid epoch year for celestial calculations.
 const MAX_VALID_YEAR: i32 = 2100;
 
-/// Current estimated leap seconds (approximate for 2025).
-/// In production, this should be updated from IERS Bulletin C.
-const CURRENT_LEAP_SECONDS: f64 = 37.0;
-
 /// TT - TAI offset in seconds (defined constant).
 const TT_MINUS_TAI: f64 = 32.184;
 
@@ -63,18 +67,115 @@ pub fn validate_epoch(epoch: DateTime<Utc>) -> CelestialResult<()> {
     Ok(())
 }
 
+/// Add a duration to an epoch without panicking on overflow.
+///
+/// chrono's `DateTime + Duration` panics when the result leaves the
+/// representable range, which for long propagation windows would abort deep
+/// inside a loop. Use this in long-horizon code paths instead.
+///
+/// # Errors
+///
+/// Returns `CelestialError::TimeScaleConversionFailed` if the result is not
+/// representable.
+pub fn checked_add(epoch: DateTime<Utc>, duration: Duration) -> CelestialResult<DateTime<Utc>> {
+    epoch
+        .checked_add_signed(duration)
+        .ok_or_else(|| CelestialError::TimeScaleConversionFailed {
+            reason: format!("{} + {} overflows the representable date range", epoch, duration),
+        })
+}
+
+/// Add a (possibly fractional) number of seconds to an epoch, see [`checked_add`].
+pub(crate) fn checked_add_seconds(epoch: DateTime<Utc>, seconds: f64) -> CelestialResult<DateTime<Utc>> {
+    let whole = seconds.floor();
+    let nanos = (((seconds - whole) * 1e9).round() as u32).min(999_999_999);
+    let duration = if whole.abs() < i64::MAX as f64 {
+        Duration::new(whole as i64, nanos)
+    } else {
+        None
+    };
+
+    match duration {
+        Some(duration) => checked_add(epoch, duration),
+        None => Err(CelestialError::TimeScaleConversionFailed {
+            reason: format!("offset of {} s is not representable as a duration", seconds),
+        }),
+    }
+}
+
+/// TAI - UTC (seconds) from each leap-second step onward, as `(year, month, offset)`.
+///
+/// Steps take effect at 00:00 UTC on the first of the month. Source: IERS
+/// Bulletin C; no leap second has been announced after 2017-01-01.
+const LEAP_SECOND_TABLE: [(i32, u32, f64); 28] = [
+    (1972, 1, 10.0),
+    (1972, 7, 11.0),
+    (1973, 1, 12.0),
+    (1974, 1, 13.0),
+    (1975, 1, 14.0),
+    (1976, 1, 15.0),
+    (1977, 1, 16.0),
+    (1978, 1, 17.0),
+    (1979, 1, 18.0),
+    (1980, 1, 19.0),
+    (1981, 7, 20.0),
+    (1982, 7, 21.0),
+    (1983, 7, 22.0),
+    (1985, 7, 23.0),
+    (1988, 1, 24.0),
+    (1990, 1, 25.0),
+    (1991, 1, 26.0),
+    (1992, 7, 27.0),
+    (1993, 7, 28.0),
+    (1994, 7, 29.0),
+    (1996, 1, 30.0),
+    (1997, 7, 31.0),
+    (1999, 1, 32.0),
+    (2006, 1, 33.0),
+    (2009, 1, 34.0),
+    (2012, 7, 35.0),
+    (2015, 7, 36.0),
+    (2017, 1, 37.0),
+];
+
+/// Accumulated leap seconds (TAI - UTC) in effect at a UTC epoch.
+///
+/// # Note
+///
+/// Before 1972 UTC was steered by rate offsets rather than whole leap
+/// seconds; those epochs return the 1972-01-01 value of 10 s. Epochs after
+/// the last table entry return the latest value.
+#[must_use]
+pub fn leap_seconds_at(utc: DateTime<Utc>) -> f64 {
+    let month = (utc.year(), utc.month());
+    LEAP_SECOND_TABLE
+        .iter()
+        .rev()
+        .find(|(y, m, _)| (*y, *m) <= month)
+        .map_or(LEAP_SECOND_TABLE[0].2, |entry| entry.2)
+}
+
+/// Leap seconds inserted between two UTC epochs.
+///
+/// Add this to a naive UTC difference `b - a` to get elapsed SI seconds. The
+/// result is negative when `b` precedes `a`.
+#[must_use]
+pub fn leap_seconds_between(a: DateTime<Utc>, b: DateTime<Utc>) -> f64 {
+    leap_seconds_at(b) - leap_seconds_at(a)
+}
+
 /// Convert UTC to TAI (International Atomic Time).
 ///
 /// TAI = UTC + leap_seconds
 ///
 /// # Note
 ///
-/// This uses a simplified leap second count. For production applications,
-/// query IERS Bulletin C for the exact leap second count at the given date.
+/// The leap second count comes from [`leap_seconds_at`]; pre-1972 epochs use
+/// the 1972 value of 10 s.
 #[must_use]
 pub fn utc_to_tai(utc: DateTime<Utc>) -> f64 {
     let jd_utc = utc.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5;
-    jd_utc + (CURRENT_LEAP_SECONDS / SECONDS_PER_DAY)
+    jd_utc + (leap_seconds_at(utc) / SECONDS_PER_DAY)
 }
 
 /// Convert UTC to TT (Terrestrial Time).
@@ -85,7 +186,7 @@ pub fn utc_to_tai(utc: DateTime<Utc>) -> f64 {
 #[must_use]
 pub fn utc_to_tt(utc: DateTime<Utc>) -> f64 {
     let jd_utc = utc.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5;
-    jd_utc + ((CURRENT_LEAP_SECONDS + TT_MINUS_TAI) / SECONDS_PER_DAY)
+    jd_utc + ((leap_seconds_at(utc) + TT_MINUS_TAI) / SECONDS_PER_DAY)
 }
 
 /// Convert UTC to UT1 (Universal Time).
@@ -117,24 +218,112 @@ pub fn utc_to_ut1(utc: DateTime<Utc>) -> f64 {
 #[must_use]
 pub fn utc_to_tdb(utc: DateTime<Utc>) -> f64 {
     let tt = utc_to_tt(utc);
-    let t = tt - 2451545.0; // Days from J2000
-    
+    tt + tdb_minus_tt_periodic(tt) / SECONDS_PER_DAY
+}
+
+/// Periodic (geocentric) part of TDB - TT in seconds, used by [`utc_to_tdb`].
+fn tdb_minus_tt_periodic(jd_tt: f64) -> f64 {
+    let t = jd_tt - 2451545.0; // Days from J2000
+
     // Mean anomaly of Earth's orbit
-    let g_deg = 357.53 + 0.9856003 * t;
-    let g = g_deg.to_radians();
-    
-    // Periodic term (seconds)
-    let periodic = 0.001658 * g.sin() + 0.000014 * (2.0 * g).sin();
-    
-    tt + (periodic / SECONDS_PER_DAY)
+    let g = (357.53 + 0.9856003 * t).to_radians();
+
+    0.001658 * g.sin() + 0.000014 * (2.0 * g).sin()
+}
+
+/// Convert UTC to a two-part TDB Julian Date for an observer away from the
+/// geocenter.
+///
+/// Adds [`tdb_topocentric_correction`] to the geocentric TDB of
+/// [`utc_to_tdb`]. The term is at most ~2 µs, below the ~40 µs resolution of
+/// a single `f64` Julian Date, so the result is split like
+/// [`utc_to_julian_date_split`](crate::constants::utc_to_julian_date_split):
+/// `jd1` is the whole UTC Julian day number and `jd2` the rest, including
+/// TDB - UTC; only `jd1 + jd2` is meaningful, and `jd2` keeps the
+/// correction to ~1e-11 s.
+///
+/// # Arguments
+///
+/// * `utc` - Observation time
+/// * `observer_icrs` - Geocentric position of the observer in ICRS
+#[must_use]
+pub fn utc_to_tdb_at(utc: DateTime<Utc>, observer_icrs: &Coordinate<Icrs>) -> (f64, f64) {
+    let (jd1, jd2) = utc_to_julian_date_split(utc);
+    let tdb_minus_utc = leap_seconds_at(utc)
+        + TT_MINUS_TAI
+        + tdb_minus_tt_periodic(utc_to_tt(utc))
+        + tdb_topocentric_correction(utc, observer_icrs);
+    (jd1, jd2 + tdb_minus_utc / SECONDS_PER_DAY)
+}
+
+/// Position-dependent part of TDB - TT for an observer, in seconds.
+///
+/// This is the (v·r)/c² term, with v the barycentric velocity of the Earth and
+/// r the geocentric position of the observer. The Earth's velocity is taken
+/// from the low-precision solar ephemeris, neglecting the Sun's own ~13 m/s
+/// barycentric motion.
+#[must_use]
+pub fn tdb_topocentric_correction(utc: DateTime<Utc>, observer_icrs: &Coordinate<Icrs>) -> f64 {
+    let half_span = 60.0;
+    let sun = |offset: Duration| {
+        Vector3::from(sun_position_icrs(utc + offset).to_cartesian().map(|c| c.get::<meter>()))
+    };
+    let offset = Duration::seconds(half_span as i64);
+    let earth_velocity = (sun(-offset) - sun(offset)) / (2.0 * half_span);
+
+    let r = Vector3::from(observer_icrs.to_cartesian().map(|c| c.get::<meter>()));
+    earth_velocity.dot(&r) / (SPEED_OF_LIGHT * SPEED_OF_LIGHT)
+}
+
+/// Greenwich Mean Sidereal Time (radians, in [0, 2π)).
+///
+/// IAU 2006 expression: the Earth Rotation Angle plus the accumulated
+/// precession in right ascension. The polynomial is evaluated with UT1 in
+/// place of TT, which changes the result by less than 1 µas.
+///
+/// # Reference
+///
+/// IERS Conventions 2010, Chapter 5
+#[must_use]
+pub fn gmst(jd_ut1: f64) -> f64 {
+    let t = (jd_ut1 - J2000_JD) / DAYS_PER_CENTURY;
+    let precession = (0.014_506
+        + t * (4_612.156_534
+            + t * (1.391_581_7 + t * (-0.000_000_44 + t * (-0.000_029_956 - t * 0.000_000_036_8)))))
+        * ARCSEC_TO_RAD;
+
+    (earth_rotation_angle(jd_ut1) + precession).rem_euclid(2.0 * std::f64::consts::PI)
+}
+
+/// Greenwich Apparent Sidereal Time (radians, in [0, 2π)).
+///
+/// GMST plus the equation of the equinoxes `Δψ·cos(ε_A)` and its two largest
+/// complementary terms. Nutation comes from the truncated series of
+/// [`nutation_matrix`](crate::constants::nutation_matrix), which limits the
+/// accuracy to roughly 0.01 s of time.
+#[must_use]
+pub fn gast(jd_ut1: f64, jd_tt: f64) -> f64 {
+    let (dpsi, _) = nutation_angles(jd_tt);
+    let t = (jd_tt - J2000_JD) / DAYS_PER_CENTURY;
+    let omega = (125.044_555_01 - (6_962_890.543_1 * t + 7.472_2 * t * t) / 3600.0).to_radians();
+
+    let complementary = (0.002_640_96 * omega.sin() + 0.000_063_52 * (2.0 * omega).sin()) * ARCSEC_TO_RAD;
+    let equation_of_equinoxes = dpsi * mean_obliquity(jd_tt).cos() + complementary;
+
+    (gmst(jd_ut1) + equation_of_equinoxes).rem_euclid(2.0 * std::f64::consts::PI)
 }
 
 /// Convert TT to UTC (approximate inverse).
 ///
-/// This is an approximation since leap seconds make the conversion non-trivial.
+/// This is an approximation since leap seconds make the conversion non-trivial:
+/// the leap second count is looked up at the TT instant, so results within
+/// ~69 s after a leap-second step may be off by one second.
 #[must_use]
 pub fn tt_to_utc_approx(tt_jd: f64) -> f64 {
-    tt_jd - ((CURRENT_LEAP_SECONDS + TT_MINUS_TAI) / SECONDS_PER_DAY)
+    let seconds = ((tt_jd - 2440587.5) * SECONDS_PER_DAY).floor() as i64;
+    let leap_seconds = DateTime::<Utc>::from_timestamp(seconds, 0)
+        .map_or(LEAP_SECOND_TABLE[LEAP_SECOND_TABLE.len() - 1].2, leap_seconds_at);
+    tt_jd - ((leap_seconds + TT_MINUS_TAI) / SECONDS_PER_DAY)
 }
 
 #[cfg(test)]
@@ -159,7 +348,7 @@ mod tests {
         let utc_jd = utc.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5;
         
         let offset_seconds = (tt - utc_jd) * SECONDS_PER_DAY;
-        let expected = CURRENT_LEAP_SECONDS + TT_MINUS_TAI;
+        let expected = leap_seconds_at(utc) + TT_MINUS_TAI;
         
         assert!((offset_seconds - expected).abs() < 0.1);
     }
@@ -185,4 +374,97 @@ mod tests {
         let diff_seconds = (tdb - tt).abs() * SECONDS_PER_DAY;
         assert!(diff_seconds < 0.002);
     }
+
+    #[test]
+    fn checked_add_reports_overflow_instead_of_panicking() {
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let century = Duration::days(36_525);
+        assert_eq!(checked_add(epoch, century).unwrap(), epoch + century);
+
+        let near_end = DateTime::<Utc>::MAX_UTC - Duration::days(365);
+        assert!(matches!(
+            checked_add(near_end, century),
+            Err(CelestialError::TimeScaleConversionFailed { .. })
+        ));
+        assert!(checked_add_seconds(epoch, 1e30).is_err());
+        assert_eq!(checked_add_seconds(epoch, 1.5).unwrap(), epoch + Duration::milliseconds(1500));
+    }
+
+    #[test]
+    fn topocentric_tdb_correction_is_microseconds() {
+        use chrono::TimeZone;
+        use uom::si::f64::Length;
+
+        let utc = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        let surface = |x: f64, y: f64| {
+            #[allow(deprecated)]
+            Coordinate::<Icrs>::from_cartesian(
+                Length::new::<meter>(x * 6_378_137.0),
+                Length::new::<meter>(y * 6_378_137.0),
+                Length::new::<meter>(0.0),
+            )
+        };
+
+        // Near the March equinox the Sun is along +x, so the Earth moves along -y
+        let leading = tdb_topocentric_correction(utc, &surface(0.0, -1.0));
+        let trailing = tdb_topocentric_correction(utc, &surface(0.0, 1.0));
+        assert!(leading > 1.5e-6 && leading < 2.2e-6);
+        assert!((leading + trailing).abs() < 1e-12);
+        assert!(tdb_topocentric_correction(utc, &surface(0.0, 0.0)) == 0.0);
+
+        // The split Julian Date carries the correction with its sign
+        let seconds = |(jd1, jd2): (f64, f64)| ((jd1 - 2_460_754.0) + jd2) * SECONDS_PER_DAY;
+        let at_geocenter = seconds(utc_to_tdb_at(utc, &surface(0.0, 0.0)));
+        let shift = seconds(utc_to_tdb_at(utc, &surface(0.0, -1.0))) - at_geocenter;
+        assert!((shift - leading).abs() < 1e-9, "{shift} s vs {leading} s");
+        assert!((at_geocenter - seconds((utc_to_tdb(utc), 0.0))).abs() < 1e-4);
+    }
+
+    #[test]
+    fn utc_to_tai_uses_historical_leap_seconds() {
+        use chrono::TimeZone;
+
+        let cases = [
+            (Utc.with_ymd_and_hms(1999, 1, 1, 0, 0, 0).unwrap(), 32.0),
+            (Utc.with_ymd_and_hms(2015, 7, 1, 0, 0, 0).unwrap(), 36.0),
+            (Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap(), 10.0),
+        ];
+        for (utc, leap_seconds) in cases {
+            let utc_jd = utc.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5;
+            assert_eq!(leap_seconds_at(utc), leap_seconds);
+            assert!(((utc_to_tai(utc) - utc_jd) * SECONDS_PER_DAY - leap_seconds).abs() < 1e-3);
+            let tt = utc_to_tt(utc);
+            assert!(((tt - utc_jd) * SECONDS_PER_DAY - leap_seconds - TT_MINUS_TAI).abs() < 1e-3);
+            assert!((tt_to_utc_approx(tt) - utc_jd).abs() * SECONDS_PER_DAY < 1e-3);
+        }
+    }
+
+    #[test]
+    fn leap_second_count_across_2016_insertion() {
+        use chrono::TimeZone;
+
+        let before = Utc.with_ymd_and_hms(2016, 12, 31, 12, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(leap_seconds_at(before), 36.0);
+        assert_eq!(leap_seconds_between(before, after), 1.0);
+        assert_eq!(leap_seconds_between(after, before), -1.0);
+        assert_eq!(leap_seconds_between(after, after + Duration::days(365)), 0.0);
+    }
+
+    #[test]
+    fn gmst_at_j2000_is_18h41m50s() {
+        // 18h41m50.54841s at 2000-01-01 12:00 UT1
+        let expected = (18.0 + 41.0 / 60.0 + 50.548_41 / 3600.0) * 15.0_f64.to_radians();
+        let mean = gmst(J2000_JD);
+        assert!((mean - expected).abs() * 86_400.0 / (2.0 * std::f64::consts::PI) < 0.01);
+
+        // Equation of the equinoxes stays within ±1.2 s of time
+        let ee = gast(J2000_JD, J2000_JD + 64.0 / SECONDS_PER_DAY) - mean;
+        assert!(ee.abs() * 86_400.0 / (2.0 * std::f64::consts::PI) < 1.2);
+        assert!(ee != 0.0);
+        assert!((0.0..2.0 * std::f64::consts::PI).contains(&gmst(J2000_JD - 1_000.3)));
+    }
 }
@@ -1,12 +1,42 @@
 //! Time-tagged coordinate types.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use chrono::{DateTime, Utc};
+use nalgebra::Vector3;
 
+use sguaba::math::RigidBodyTransform;
+use sguaba::systems::Ecef;
 use sguaba::{Coordinate, CoordinateSystem, Vector};
+use uom::si::f64::Velocity;
+use uom::si::length::meter;
+use uom::si::velocity::meter_per_second;
+
+use crate::astrodynamics::AngularVelocityVector;
+use crate::constants::SECONDS_PER_DAY;
+use crate::errors::{CelestialError, CelestialResult};
+use crate::frames::Icrs;
+use crate::orbital::seconds_between;
 
 /// Velocity vector type (meters per second)
+///
+/// sguaba's `Vector` already implements the arithmetic needed to compose
+/// velocities, e.g. adding a burn to a state velocity: `v * 2.0`, `v / 2.0`,
+/// `a + b`, `a - b`, `-v`, and `+=`/`-=` between vectors in the same frame.
 pub type VelocityVector<S> = Vector<S, typenum::N1>;
 
+/// Cartesian components of a velocity vector in m/s.
+pub(crate) fn velocity_components<S>(velocity: &VelocityVector<S>) -> [f64; 3] {
+    velocity.to_cartesian().map(|v| v.get::<meter_per_second>())
+}
+
+/// Build a velocity vector from Cartesian components in m/s.
+pub(crate) fn velocity_from_components<S: CoordinateSystem>(components: [f64; 3]) -> VelocityVector<S> {
+    let [x, y, z] = components.map(Velocity::new::<meter_per_second>);
+    #[allow(deprecated)]
+    Vector::from_cartesian(x, y, z)
+}
+
 /// A coordinate with an associated timestamp (epoch).
 ///
 /// This type represents a position at a specific moment in time, which is
@@ -86,6 +116,30 @@ impl<S: CoordinateSystem> TimedCoordinate<S> {
     }
 }
 
+impl TimedCoordinate<Icrs> {
+    /// Transform to ECEF at the coordinate's own epoch.
+    ///
+    /// Uses [`icrs_to_ecef_at`](crate::transforms::icrs_to_ecef_at) at
+    /// [`epoch`](Self::epoch), so the rotation can never be evaluated at the
+    /// wrong time. The epoch is kept.
+    #[must_use]
+    pub fn to_ecef(&self) -> TimedCoordinate<Ecef> {
+        let tf = crate::transforms::icrs_to_ecef_at(self.epoch);
+        TimedCoordinate::new(tf.transform(self.position), self.epoch)
+    }
+}
+
+impl TimedCoordinate<Ecef> {
+    /// Transform to ICRS at the coordinate's own epoch.
+    ///
+    /// The inverse of [`TimedCoordinate::<Icrs>::to_ecef`](TimedCoordinate::to_ecef).
+    #[must_use]
+    pub fn to_icrs(&self) -> TimedCoordinate<Icrs> {
+        let tf = crate::transforms::ecef_to_icrs_at(self.epoch);
+        TimedCoordinate::new(tf.transform(self.position), self.epoch)
+    }
+}
+
 /// A complete ephemeris state: position, velocity, and epoch.
 ///
 /// This type represents a full state vector for orbital mechanics,
@@ -176,12 +230,222 @@ impl<S: CoordinateSystem> EphemerisState<S> {
     pub const fn with_epoch(self, epoch: DateTime<Utc>) -> Self {
         Self { position: self.position, velocity: self.velocity, epoch }
     }
+
+    /// Move the state into another frame.
+    ///
+    /// `omega` is the target frame's angular velocity relative to this one, in
+    /// rad/s and this frame's axes; the velocity picks up the `-ω × r`
+    /// transport term (see [`transform_state`](crate::transforms::transform_state)).
+    /// Pass a zero vector for frames that do not rotate relative to each
+    /// other. `xf` should be evaluated at the state's epoch, which is kept.
+    ///
+    /// For ICRS → ECEF use [`icrs_to_ecef_at`](crate::transforms::icrs_to_ecef_at)
+    /// at the epoch and Earth's rotation axis scaled by
+    /// [`EARTH_ROTATION_RATE`](crate::constants::EARTH_ROTATION_RATE).
+    #[must_use]
+    pub fn transform_to<To: CoordinateSystem>(
+        self,
+        xf: &RigidBodyTransform<S, To>,
+        omega: AngularVelocityVector<S>,
+    ) -> EphemerisState<To> {
+        use crate::ext::VelocityTransformExt;
+
+        // A pure rotation of the axis, independent of where it is applied
+        let omega = xf.transform_velocity(self.position, velocity_components(&omega));
+        crate::transforms::transform_state(xf, &self, Some(omega))
+    }
+}
+
+impl EphemerisState<Icrs> {
+    /// Apply an impulsive maneuver, adding `dv` (m/s) to the velocity.
+    ///
+    /// Position and epoch are unchanged, so burns can be chained between
+    /// propagation segments.
+    #[must_use]
+    pub fn apply_impulse(&self, dv: [f64; 3]) -> Self {
+        let [vx, vy, vz] = velocity_components(&self.velocity);
+        let velocity = velocity_from_components([vx + dv[0], vy + dv[1], vz + dv[2]]);
+        self.with_velocity(velocity)
+    }
+
+    /// Apply an impulsive maneuver specified in the local orbital frame (m/s).
+    ///
+    /// The components are taken along [`prograde`](Self::prograde),
+    /// [`radial`](Self::radial) and [`cross_track`](Self::cross_track) at the
+    /// current state, converted to ICRS and passed to
+    /// [`apply_impulse`](Self::apply_impulse).
+    #[must_use]
+    pub fn apply_impulse_lvlh(&self, prograde: f64, radial: f64, cross: f64) -> Self {
+        let dv = Vector3::from(self.prograde()) * prograde
+            + Vector3::from(self.radial()) * radial
+            + Vector3::from(self.cross_track()) * cross;
+        self.apply_impulse(dv.into())
+    }
+
+    /// Unit vector along the position (the radial axis of the RIC/LVLH frame).
+    #[must_use]
+    pub fn radial(&self) -> [f64; 3] {
+        self.position_vector().normalize().into()
+    }
+
+    /// Unit vector along the orbit normal `r × v` (the cross-track axis).
+    ///
+    /// Undefined (NaN) for rectilinear motion, where `r` and `v` are parallel.
+    #[must_use]
+    pub fn cross_track(&self) -> [f64; 3] {
+        let v = Vector3::from(velocity_components(&self.velocity));
+        self.position_vector().cross(&v).normalize().into()
+    }
+
+    /// In-plane unit vector completing the right-handed RIC/LVLH basis.
+    ///
+    /// This is `cross_track × radial`: perpendicular to the position and
+    /// pointing in the direction of motion. It coincides with the velocity
+    /// direction only on circular orbits or at apsides.
+    #[must_use]
+    pub fn prograde(&self) -> [f64; 3] {
+        Vector3::from(self.cross_track()).cross(&Vector3::from(self.radial())).into()
+    }
+
+    /// Position in meters.
+    fn position_vector(&self) -> Vector3<f64> {
+        Vector3::from(self.position.to_cartesian().map(|c| c.get::<meter>()))
+    }
+}
+
+/// A time-ordered table of positions that can be sampled at any epoch inside it.
+///
+/// Positions between entries are linearly interpolated. The bracket used for
+/// the last query is cached, so sweeping forward through the table in small
+/// steps costs O(1) per query instead of a binary search. The cache is only a
+/// hint, so a table can be shared between threads; concurrent sweeps just
+/// fall back to the binary search more often.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "celestial")] {
+/// use crate::{icrs_from_km, EphemerisTable, TimedCoordinate};
+/// use chrono::{Duration, Utc};
+///
+/// let t0 = Utc::now();
+/// let table = EphemerisTable::new(vec![
+///     TimedCoordinate::new(icrs_from_km(7000.0, 0.0, 0.0), t0),
+///     TimedCoordinate::new(icrs_from_km(0.0, 7000.0, 0.0), t0 + Duration::minutes(24)),
+/// ])?;
+///
+/// let midway = table.position_at(t0 + Duration::minutes(12))?;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EphemerisTable<S: CoordinateSystem> {
+    entries: Vec<TimedCoordinate<S>>,
+    /// Index of the entry starting the most recently used bracket
+    bracket: AtomicUsize,
+}
+
+impl<S: CoordinateSystem> Clone for EphemerisTable<S>
+where
+    TimedCoordinate<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            bracket: AtomicUsize::new(self.bracket.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<S: CoordinateSystem> EphemerisTable<S> {
+    /// Build a table from time-tagged positions.
+    ///
+    /// Entries are sorted by epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` if fewer than two entries
+    /// are given or two entries share an epoch.
+    pub fn new(mut entries: Vec<TimedCoordinate<S>>) -> CelestialResult<Self> {
+        if entries.len() < 2 {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: "an ephemeris table needs at least two entries".to_string(),
+            });
+        }
+
+        entries.sort_by_key(TimedCoordinate::epoch);
+        if let Some(pair) = entries.windows(2).find(|w| w[0].epoch() == w[1].epoch()) {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: format!("duplicate ephemeris epoch {}", pair[0].epoch()),
+            });
+        }
+
+        Ok(Self { entries, bracket: AtomicUsize::new(0) })
+    }
+
+    /// The entries, sorted by epoch.
+    #[must_use]
+    pub fn entries(&self) -> &[TimedCoordinate<S>] {
+        &self.entries
+    }
+
+    /// First and last epochs covered by the table.
+    #[must_use]
+    pub fn span(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (self.entries[0].epoch(), self.entries[self.entries.len() - 1].epoch())
+    }
+
+    /// Interpolated position at `epoch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::EpochOutOfRange` if `epoch` lies outside the table.
+    pub fn position_at(&self, epoch: DateTime<Utc>) -> CelestialResult<Coordinate<S>> {
+        let (first, last) = self.span();
+        if epoch < first || epoch > last {
+            let jd = |t: DateTime<Utc>| t.timestamp() as f64 / SECONDS_PER_DAY + 2440587.5;
+            return Err(CelestialError::EpochOutOfRange {
+                epoch,
+                min_jd: jd(first),
+                max_jd: jd(last),
+            });
+        }
+
+        let i = self.bracket_index(epoch);
+        let (a, b) = (&self.entries[i], &self.entries[i + 1]);
+        let f = seconds_between(a.epoch(), epoch) / seconds_between(a.epoch(), b.epoch());
+
+        let pa = a.position().to_cartesian();
+        let pb = b.position().to_cartesian();
+        let [x, y, z] = [0, 1, 2].map(|k| pa[k] + (pb[k] - pa[k]) * f);
+        #[allow(deprecated)]
+        Ok(Coordinate::from_cartesian(x, y, z))
+    }
+
+    /// Index `i` such that `entries[i].epoch() <= epoch <= entries[i + 1].epoch()`.
+    fn bracket_index(&self, epoch: DateTime<Utc>) -> usize {
+        let contains = |i: usize| self.entries[i].epoch() <= epoch && epoch <= self.entries[i + 1].epoch();
+
+        // Only a hint: another thread may move it, but it is checked before use
+        let cached = self.bracket.load(Ordering::Relaxed);
+        if contains(cached) {
+            return cached;
+        }
+        // Sequential access usually just steps into the next bracket
+        if cached + 2 < self.entries.len() && contains(cached + 1) {
+            self.bracket.store(cached + 1, Ordering::Relaxed);
+            return cached + 1;
+        }
+
+        let after = self.entries.partition_point(|e| e.epoch() <= epoch);
+        let i = after.saturating_sub(1).min(self.entries.len() - 2);
+        self.bracket.store(i, Ordering::Relaxed);
+        i
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Icrs;
     use uom::si::f64::Length;
     use uom::si::length::meter;
 
@@ -225,4 +489,234 @@ mod tests {
         assert_eq!(updated.position(), &pos2);
         assert_eq!(updated.epoch(), time2);
     }
+
+    #[test]
+    fn transform_to_ecef_roundtrips_and_stops_ground_points() {
+        use crate::constants::EARTH_ROTATION_RATE;
+        use crate::ext::{ecef_to_icrs_velocity, VelocityTransformExt};
+        use crate::transforms::icrs_to_ecef_at;
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let to_ecef = icrs_to_ecef_at(epoch);
+        let to_icrs = to_ecef.inverse();
+
+        // Earth's axis in each frame; ICRS spins at -ω as seen from ECEF
+        let spin_ecef = [0.0, 0.0, EARTH_ROTATION_RATE];
+        let spin_icrs = to_icrs.transform_velocity(Coordinate::origin(), spin_ecef);
+
+        #[allow(deprecated)]
+        let ground = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<meter>(4.0e6),
+            Length::new::<meter>(3.0e6),
+            Length::new::<meter>(3.8e6),
+        );
+        let inertial = EphemerisState::<Icrs>::new(
+            to_icrs.transform(ground),
+            velocity_from_components(ecef_to_icrs_velocity(ground, [0.0; 3], epoch)),
+            epoch,
+        );
+
+        let fixed = inertial.transform_to(&to_ecef, velocity_from_components(spin_icrs));
+        assert_eq!(fixed.epoch(), epoch);
+        assert!(velocity_components(fixed.velocity()).iter().all(|v| v.abs() < 1e-6));
+
+        let back = fixed.transform_to(&to_icrs, velocity_from_components(spin_ecef.map(|w| -w)));
+        let offset = nalgebra::Vector3::from(back.position().to_cartesian().map(|c| c.get::<meter>()))
+            - nalgebra::Vector3::from(inertial.position().to_cartesian().map(|c| c.get::<meter>()));
+        assert!(offset.norm() < 1e-6);
+        for (a, b) in velocity_components(back.velocity()).iter().zip(velocity_components(inertial.velocity())) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn ecef_conversion_uses_and_keeps_own_epoch() {
+        use chrono::TimeZone;
+
+        let epoch = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        #[allow(deprecated)]
+        let position = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<meter>(7.0e6),
+            Length::new::<meter>(1.0e6),
+            Length::new::<meter>(-2.0e6),
+        );
+        let timed = TimedCoordinate::new(position, epoch);
+
+        let ecef = timed.to_ecef();
+        assert_eq!(ecef.epoch(), epoch);
+        assert_eq!(ecef.position(), &crate::transforms::icrs_to_ecef_at(epoch).transform(position));
+
+        let back = ecef.to_icrs();
+        assert_eq!(back.epoch(), epoch);
+        let [x, y, z] = back.position().to_cartesian();
+        let [x0, y0, z0] = position.to_cartesian();
+        for delta in [x - x0, y - y0, z - z0] {
+            assert!(delta.get::<meter>().abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn reversed_impulse_restores_state() {
+        use chrono::TimeZone;
+
+        let state = EphemerisState::<Icrs>::new(
+            #[allow(deprecated)]
+            Coordinate::from_cartesian(
+                Length::new::<meter>(7.0e6),
+                Length::new::<meter>(0.0),
+                Length::new::<meter>(0.0),
+            ),
+            velocity_from_components([0.0, 7546.0, 0.0]),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let dv = [12.5, -3.0, 0.75];
+
+        let burned = state.apply_impulse(dv);
+        let restored = burned.apply_impulse(dv.map(|c| -c));
+
+        assert_eq!(burned.position(), state.position());
+        assert_eq!(burned.epoch(), state.epoch());
+        assert!((velocity_components(burned.velocity())[0] - 12.5).abs() < 1e-12);
+        for (a, b) in velocity_components(restored.velocity())
+            .iter()
+            .zip(velocity_components(state.velocity()))
+        {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn ephemeris_table_interpolates_with_cached_bracket() {
+        use chrono::TimeZone;
+        use uom::si::length::kilometer;
+
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let entries = (0..5)
+            .map(|i| {
+                #[allow(deprecated)]
+                let position = Coordinate::<Icrs>::from_cartesian(
+                    Length::new::<kilometer>(f64::from(i) * 10.0),
+                    Length::new::<kilometer>(0.0),
+                    Length::new::<kilometer>(0.0),
+                );
+                TimedCoordinate::new(position, t0 + chrono::Duration::minutes(i64::from(i)))
+            })
+            .rev()
+            .collect();
+        let table = EphemerisTable::new(entries).unwrap();
+
+        // Sweep forward in 15 s steps; each query reuses or advances the bracket
+        for step in 0..=16 {
+            let t = t0 + chrono::Duration::seconds(15 * step);
+            let before = table.bracket.load(Ordering::Relaxed);
+            let x = table.position_at(t).unwrap().to_cartesian()[0].get::<kilometer>();
+            assert!((x - 2.5 * step as f64).abs() < 1e-9);
+            assert!(table.bracket.load(Ordering::Relaxed) - before <= 1);
+        }
+        assert_eq!(table.bracket.load(Ordering::Relaxed), 3);
+
+        let late = t0 + chrono::Duration::minutes(5);
+        assert!(matches!(table.position_at(late), Err(CelestialError::EpochOutOfRange { .. })));
+        assert!(table.position_at(t0 - chrono::Duration::seconds(1)).is_err());
+    }
+
+    #[test]
+    fn ephemeris_table_is_shared_across_threads() {
+        use chrono::TimeZone;
+        use uom::si::length::kilometer;
+
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let entries = (0..60)
+            .map(|i| {
+                #[allow(deprecated)]
+                let position = Coordinate::<Icrs>::from_cartesian(
+                    Length::new::<kilometer>(f64::from(i)),
+                    Length::new::<kilometer>(0.0),
+                    Length::new::<kilometer>(0.0),
+                );
+                TimedCoordinate::new(position, t0 + chrono::Duration::minutes(i64::from(i)))
+            })
+            .collect();
+        let table = EphemerisTable::new(entries).unwrap();
+
+        // One thread sweeps forward, the other backward, fighting over the hint
+        std::thread::scope(|scope| {
+            for reverse in [false, true] {
+                let table = &table;
+                scope.spawn(move || {
+                    for step in 0..=590 {
+                        let seconds = if reverse { 5 * (590 - step) } else { 5 * step };
+                        let t = t0 + chrono::Duration::seconds(seconds);
+                        let x = table.position_at(t).unwrap().to_cartesian()[0].get::<kilometer>();
+                        assert!((x - seconds as f64 / 60.0).abs() < 1e-9);
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn lvlh_basis_is_orthonormal() {
+        use chrono::TimeZone;
+
+        let state = EphemerisState::<Icrs>::new(
+            #[allow(deprecated)]
+            Coordinate::from_cartesian(
+                Length::new::<meter>(7.0e6),
+                Length::new::<meter>(1.2e6),
+                Length::new::<meter>(-0.4e6),
+            ),
+            velocity_from_components([-900.0, 6800.0, 3100.0]),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let basis = [state.radial(), state.prograde(), state.cross_track()].map(Vector3::from);
+
+        for (i, a) in basis.iter().enumerate() {
+            assert!((a.norm() - 1.0).abs() < 1e-12);
+            for b in &basis[i + 1..] {
+                assert!(a.dot(b).abs() < 1e-12);
+            }
+        }
+        // Right-handed, with prograde along the motion
+        assert!((basis[0].cross(&basis[1]) - basis[2]).norm() < 1e-12);
+        assert!(basis[1].dot(&Vector3::from(velocity_components(state.velocity()))) > 0.0);
+    }
+
+    #[test]
+    fn prograde_impulse_adds_exactly_to_speed() {
+        use chrono::TimeZone;
+
+        let state = EphemerisState::<Icrs>::new(
+            #[allow(deprecated)]
+            Coordinate::from_cartesian(
+                Length::new::<meter>(0.0),
+                Length::new::<meter>(5.0e6),
+                Length::new::<meter>(5.0e6),
+            ),
+            velocity_from_components([-7500.0, 0.0, 0.0]),
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let speed = |s: &EphemerisState<Icrs>| Vector3::from(velocity_components(s.velocity())).norm();
+
+        // Velocity is perpendicular to position, so prograde is along the velocity
+        let burned = state.apply_impulse_lvlh(12.5, 0.0, 0.0);
+        assert!((speed(&burned) - speed(&state) - 12.5).abs() < 1e-9);
+        assert_eq!(burned.position(), state.position());
+
+        // A cross-track burn acts purely along the orbit normal
+        let tilted = state.apply_impulse_lvlh(0.0, 0.0, 30.0);
+        let dv = Vector3::from(velocity_components(tilted.velocity()))
+            - Vector3::from(velocity_components(state.velocity()));
+        assert!((dv - Vector3::from(state.cross_track()) * 30.0).norm() < 1e-9);
+    }
+
+    #[test]
+    fn velocity_scaling_and_addition() {
+        let v = velocity_from_components::<Icrs>([1.0, -2.5, 7546.0]);
+
+        assert_eq!(velocity_components(&(v * 2.0)), [2.0, -5.0, 15092.0]);
+        assert_eq!(velocity_components(&(v + v * -1.0)), [0.0, 0.0, 0.0]);
+        assert_eq!(velocity_components(&(v - v)), velocity_components(&(v + -v)));
+    }
 }
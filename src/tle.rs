@@ -5,10 +5,14 @@
 
 use super::errors::{CelestialError, CelestialResult};
 use super::frames::Icrs;
-use super::orbital::KeplerianElements;
+use super::orbital::{seconds_between, true_anomaly_from_mean, true_to_eccentric, KeplerianElements};
+use super::solver::SolverConfig;
+use super::sgp4::{MeanElements, Sgp4};
+use super::timed::{velocity_from_components, EphemerisState};
 use sguaba::Coordinate;
-use chrono::{DateTime, Duration, TimeZone, Utc};
-use uom::si::angle::degree;
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use std::fmt;
+use uom::si::angle::{degree, radian};
 use uom::si::f64::{Angle, Length};
 use uom::si::length::kilometer;
 
@@ -21,8 +25,8 @@ use uom::si::length::kilometer;
 ///
 /// ```text
 /// ISS (ZARYA)
-/// 1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9992
-/// 2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236008
+/// 1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997
+/// 2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000
 /// ```
 ///
 /// # Example
@@ -31,8 +35,8 @@ use uom::si::length::kilometer;
 /// # #[cfg(feature = "celestial")] {
 /// use sguaba::celestial::TleElements;
 ///
-/// let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9992";
-/// let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236008";
+/// let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+/// let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
 ///
 /// match TleElements::from_lines(line1, line2) {
 ///     Ok(tle) => {
@@ -44,22 +48,40 @@ use uom::si::length::kilometer;
 /// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct TleElements {
+    /// Satellite name from a three-line set
+    pub(crate) name: Option<String>,
     /// Satellite catalog number
-    catalog_number: u32,
+    pub(crate) catalog_number: u32,
     /// Epoch (time of element set)
-    epoch: DateTime<Utc>,
+    pub(crate) epoch: DateTime<Utc>,
     /// Inclination (degrees)
-    inclination: Angle,
+    pub(crate) inclination: Angle,
     /// Right ascension of ascending node (degrees)
-    raan: Angle,
+    pub(crate) raan: Angle,
     /// Eccentricity
-    eccentricity: f64,
+    pub(crate) eccentricity: f64,
     /// Argument of perigee (degrees)
-    arg_perigee: Angle,
+    pub(crate) arg_perigee: Angle,
     /// Mean anomaly (degrees)
-    mean_anomaly: Angle,
+    pub(crate) mean_anomaly: Angle,
     /// Mean motion (revolutions per day)
-    mean_motion: f64,
+    pub(crate) mean_motion: f64,
+    /// Security classification (`U`, `C` or `S`)
+    pub(crate) classification: char,
+    /// International designator (launch year, launch number and piece)
+    pub(crate) intl_designator: String,
+    /// Ephemeris type (0 = SGP4/SDP4 chosen by period)
+    pub(crate) ephemeris_type: u8,
+    /// First derivative of mean motion divided by 2 (revolutions per day²)
+    pub(crate) ndot: f64,
+    /// Second derivative of mean motion divided by 6 (revolutions per day³)
+    pub(crate) nddot: f64,
+    /// B* drag term (1/Earth radii)
+    pub(crate) bstar: f64,
+    /// Element set number
+    pub(crate) element_set_number: u32,
+    /// Revolution number at epoch
+    pub(crate) rev_number: u32,
 }
 
 impl TleElements {
@@ -72,8 +94,36 @@ impl TleElements {
     ///
     /// # Errors
     ///
-    /// Returns `CelestialError::InvalidCoordinates` if the TLE format is invalid.
+    /// Returns `CelestialError::InvalidCoordinates` if the TLE format is
+    /// invalid or the modulo-10 checksum in column 69 of either line does not
+    /// match.
     pub fn from_lines(line1: &str, line2: &str) -> CelestialResult<Self> {
+        let tle = Self::from_lines_unchecked(line1, line2)?;
+        for (number, line) in [(1, line1), (2, line2)] {
+            let stated = line[68..69].parse::<u32>().ok();
+            let computed = checksum(&line[..68]);
+            if stated != Some(computed) {
+                return Err(CelestialError::InvalidCoordinates {
+                    reason: format!(
+                        "TLE line {number} checksum mismatch: column 69 is {:?}, computed {computed}",
+                        &line[68..69]
+                    ),
+                });
+            }
+        }
+        Ok(tle)
+    }
+
+    /// Parse a TLE from two lines without verifying the checksums.
+    ///
+    /// For data known to carry bad checksums, such as hand-edited element
+    /// sets. Prefer [`from_lines`](Self::from_lines), which catches single
+    /// mistyped digits (the digit-sum checksum cannot detect transpositions).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` if the TLE format is invalid.
+    pub fn from_lines_unchecked(line1: &str, line2: &str) -> CelestialResult<Self> {
         if line1.len() < 69 || line2.len() < 69 {
             return Err(CelestialError::InvalidCoordinates {
                 reason: "TLE lines must be 69 characters".into(),
@@ -88,87 +138,59 @@ impl TleElements {
         }
 
         // Parse catalog number (columns 3-7)
-        let catalog_number = line1[2..7]
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| CelestialError::InvalidCoordinates { 
-                reason: "Invalid catalog number".into() 
-            })?;
+        let catalog_number = parse_field::<u32>(&line1[2..7], "catalog number")?;
+
+        // Classification (column 8)
+        let classification = line1[7..8].chars().next().unwrap_or('U');
+
+        // International designator (columns 10-17)
+        let intl_designator = line1[9..17].trim().to_string();
 
         // Parse epoch (columns 19-32 of line 1)
-        let epoch_year = line1[18..20]
-            .parse::<i32>()
-            .map_err(|_| CelestialError::InvalidCoordinates { 
-                reason: "Invalid epoch year".into() 
-            })?;
+        let epoch_year = parse_field::<i32>(&line1[18..20], "epoch year")?;
         let epoch_year = if epoch_year < 57 {
             2000 + epoch_year
         } else {
             1900 + epoch_year
         };
 
-        let epoch_day = line1[20..32]
-            .parse::<f64>()
-            .map_err(|_| CelestialError::InvalidCoordinates { 
-                reason: "Invalid epoch day".into() 
-            })?;
+        let epoch_day = parse_field::<f64>(&line1[20..32], "epoch day")?;
 
         let epoch = tle_epoch_to_datetime(epoch_year, epoch_day)?;
 
-        // Parse orbital elements from line 2
-        let inclination = Angle::new::<degree>(
-            line2[8..16]
-                .trim()
-                .parse::<f64>()
-                .map_err(|_| CelestialError::InvalidCoordinates { 
-                    reason: "Invalid inclination".into() 
-                })?,
-        );
+        // Mean motion derivatives (columns 34-43 and 45-52)
+        let ndot = parse_field::<f64>(&line1[33..43], "first derivative of mean motion")?;
+        let nddot = parse_assumed_decimal(&line1[44..52]).ok_or_else(|| {
+            CelestialError::InvalidCoordinates { reason: "Invalid second derivative of mean motion".into() }
+        })?;
 
-        let raan = Angle::new::<degree>(
-            line2[17..25]
-                .trim()
-                .parse::<f64>()
-                .map_err(|_| CelestialError::InvalidCoordinates { 
-                    reason: "Invalid RAAN".into() 
-                })?,
-        );
+        // B* drag term (columns 54-61) and ephemeris type (column 63)
+        let bstar = parse_assumed_decimal(&line1[53..61]).ok_or_else(|| {
+            CelestialError::InvalidCoordinates { reason: "Invalid B* drag term".into() }
+        })?;
 
-        let eccentricity = {
-            let ecc_str = format!("0.{}", &line2[26..33]);
-            ecc_str
-                .parse::<f64>()
-                .map_err(|_| CelestialError::InvalidCoordinates { 
-                    reason: "Invalid eccentricity".into() 
-                })?
+        let ephemeris_type = match line1[62..63].trim() {
+            "" => 0,
+            field => field.parse::<u8>().map_err(|_| CelestialError::InvalidCoordinates {
+                reason: "Invalid ephemeris type".into(),
+            })?,
         };
 
-        let arg_perigee = Angle::new::<degree>(
-            line2[34..42]
-                .trim()
-                .parse::<f64>()
-                .map_err(|_| CelestialError::InvalidCoordinates { 
-                    reason: "Invalid argument of perigee".into() 
-                })?,
-        );
-
-        let mean_anomaly = Angle::new::<degree>(
-            line2[43..51]
-                .trim()
-                .parse::<f64>()
-                .map_err(|_| CelestialError::InvalidCoordinates { 
-                    reason: "Invalid mean anomaly".into() 
-                })?,
-        );
+        // Bookkeeping counters (line 1 columns 65-68, line 2 columns 64-68);
+        // informational only, so blank or garbled values read as zero
+        let element_set_number = line1[64..68].trim().parse::<u32>().unwrap_or(0);
+        let rev_number = line2[63..68].trim().parse::<u32>().unwrap_or(0);
 
-        let mean_motion = line2[52..63]
-            .trim()
-            .parse::<f64>()
-            .map_err(|_| CelestialError::InvalidCoordinates { 
-                reason: "Invalid mean motion".into() 
-            })?;
+        // Parse orbital elements from line 2
+        let inclination = Angle::new::<degree>(parse_field(&line2[8..16], "inclination")?);
+        let raan = Angle::new::<degree>(parse_field(&line2[17..25], "RAAN")?);
+        let eccentricity = parse_eccentricity(&line2[26..33])?;
+        let arg_perigee = Angle::new::<degree>(parse_field(&line2[34..42], "argument of perigee")?);
+        let mean_anomaly = Angle::new::<degree>(parse_field(&line2[43..51], "mean anomaly")?);
+        let mean_motion = parse_field::<f64>(&line2[52..63], "mean motion")?;
 
         Ok(Self {
+            name: None,
             catalog_number,
             epoch,
             inclination,
@@ -177,9 +199,125 @@ impl TleElements {
             arg_perigee,
             mean_anomaly,
             mean_motion,
+            classification,
+            intl_designator,
+            ephemeris_type,
+            ndot,
+            nddot,
+            bstar,
+            element_set_number,
+            rev_number,
+        })
+    }
+
+    /// Parse a named (three-line) TLE set.
+    ///
+    /// The name line may carry the `0 ` prefix used by Space-Track's 3LE
+    /// format; it and surrounding whitespace are stripped.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`from_lines`](Self::from_lines).
+    pub fn from_3lines(name: &str, line1: &str, line2: &str) -> CelestialResult<Self> {
+        let name = name.trim();
+        let name = name.strip_prefix("0 ").unwrap_or(name).trim();
+        Ok(Self {
+            name: (!name.is_empty()).then(|| name.to_string()),
+            ..Self::from_lines(line1, line2)?
         })
     }
 
+    /// Build an element set from Keplerian elements and a B* drag term, for
+    /// exporting internally generated orbits with [`to_lines`](Self::to_lines).
+    ///
+    /// The mean motion follows from the semi-major axis and the elements'
+    /// `mu`, and the mean anomaly from the true anomaly. SGP4 expects mean
+    /// (Kozai) elements, so an osculating state converted this way is only a
+    /// starting point: it carries the short-period J2 terms (several km for
+    /// LEO) into the element set. The classification is `U` and the
+    /// designator, derivative terms and counters are left empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` unless `0 ≤ e < 1` and the
+    /// semi-major axis is positive.
+    pub fn from_keplerian(
+        catalog_number: u32,
+        elements: &KeplerianElements,
+        epoch: DateTime<Utc>,
+        bstar: f64,
+    ) -> CelestialResult<Self> {
+        use uom::si::length::meter;
+
+        let e = elements.eccentricity;
+        let a = elements.semi_major_axis.get::<meter>();
+        if !(0.0..1.0).contains(&e) || a <= 0.0 {
+            return Err(CelestialError::InvalidCoordinates {
+                reason: format!("TLE elements need a closed orbit, got a = {a} m, e = {e}"),
+            });
+        }
+
+        let mean_motion = (elements.mu / a.powi(3)).sqrt() * 86400.0 / std::f64::consts::TAU;
+        let ea = true_to_eccentric(elements.true_anomaly.get::<radian>(), e);
+
+        Ok(Self {
+            name: None,
+            catalog_number,
+            epoch,
+            inclination: elements.inclination,
+            raan: elements.raan,
+            eccentricity: e,
+            arg_perigee: elements.argument_of_periapsis,
+            mean_anomaly: Angle::new::<radian>(ea - e * ea.sin()),
+            mean_motion,
+            classification: 'U',
+            intl_designator: String::new(),
+            ephemeris_type: 0,
+            ndot: 0.0,
+            nddot: 0.0,
+            bstar,
+            element_set_number: 0,
+            rev_number: 0,
+        })
+    }
+
+    /// Parse every element set in a multi-satellite catalog.
+    ///
+    /// Accepts two-line sets and named three-line sets, mixed freely, one
+    /// result per satellite in file order. Blank lines are skipped. A set
+    /// whose lines do not line up (e.g. a name line followed by something
+    /// other than lines `1` and `2`) yields an error and parsing resumes at
+    /// the next line.
+    #[must_use]
+    pub fn parse_catalog(text: &str) -> Vec<CelestialResult<Self>> {
+        let lines: Vec<&str> = text.lines().map(str::trim_end).filter(|l| !l.is_empty()).collect();
+        let is_line = |i: usize, n: char| lines.get(i).is_some_and(|l| l.starts_with(n) && l.get(1..2) == Some(" "));
+
+        let mut results = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            if is_line(i, '1') && is_line(i + 1, '2') {
+                results.push(Self::from_lines(lines[i], lines[i + 1]));
+                i += 2;
+            } else if is_line(i + 1, '1') && is_line(i + 2, '2') {
+                results.push(Self::from_3lines(lines[i], lines[i + 1], lines[i + 2]));
+                i += 3;
+            } else {
+                results.push(Err(CelestialError::InvalidCoordinates {
+                    reason: format!("Unexpected TLE catalog line: {:?}", lines[i]),
+                }));
+                i += 1;
+            }
+        }
+        results
+    }
+
+    /// Get the satellite name, if parsed from a three-line set.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Get the satellite catalog number.
     #[must_use]
     pub fn catalog_number(&self) -> u32 {
@@ -204,10 +342,241 @@ impl TleElements {
         self.eccentricity
     }
 
+    /// Get the security classification (`U` unclassified, `C` classified, `S` secret).
+    #[must_use]
+    pub fn classification(&self) -> char {
+        self.classification
+    }
+
+    /// Get the ephemeris type.
+    ///
+    /// Published element sets use 0, meaning SGP4 or SDP4 is selected by the
+    /// orbital period (see [`is_deep_space`](Self::is_deep_space)).
+    #[must_use]
+    pub fn ephemeris_type(&self) -> u8 {
+        self.ephemeris_type
+    }
+
+    /// Get the first derivative of mean motion divided by 2, in revolutions
+    /// per day², as published in the TLE.
+    ///
+    /// Used by the legacy SGP model only; SGP4 ignores it.
+    #[must_use]
+    pub fn ndot(&self) -> f64 {
+        self.ndot
+    }
+
+    /// Get the second derivative of mean motion divided by 6, in revolutions
+    /// per day³, as published in the TLE (usually zero).
+    #[must_use]
+    pub fn nddot(&self) -> f64 {
+        self.nddot
+    }
+
+    /// Get the B* drag term in 1/Earth radii.
+    #[must_use]
+    pub fn bstar(&self) -> f64 {
+        self.bstar
+    }
+
+    /// Get the international designator, e.g. `98067A`.
+    #[must_use]
+    pub fn intl_designator(&self) -> &str {
+        &self.intl_designator
+    }
+
+    /// Get the element set number.
+    #[must_use]
+    pub fn element_set_number(&self) -> u32 {
+        self.element_set_number
+    }
+
+    /// Get the revolution number at epoch.
+    #[must_use]
+    pub fn rev_number(&self) -> u32 {
+        self.rev_number
+    }
+
+    /// Format the element set as its two 69-column lines.
+    ///
+    /// Fields are written at the format's fixed precision (e.g. four decimals
+    /// for angles, eight for the epoch day) with freshly computed checksums,
+    /// so [`from_lines`](Self::from_lines) reproduces the elements to that
+    /// precision. The name of a three-line set is not included. The element
+    /// set and revolution counters wrap at the column width like published
+    /// sets do, and drag terms too small for a one-digit exponent (below
+    /// 1e-10) are written as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::InvalidCoordinates` if a field does not fit
+    /// its columns: a catalog number above 99999 (e.g. from an OMM), an
+    /// epoch outside 1957-2056, an eccentricity that rounds to 1 or more,
+    /// a mean motion of 100 rev/day or more, a designator longer than eight
+    /// characters, or `ndot`, `nddot` or B* too large for the format.
+    pub fn to_lines(&self) -> CelestialResult<(String, String)> {
+        let invalid = |reason: String| CelestialError::InvalidCoordinates { reason };
+
+        if self.catalog_number > 99_999 {
+            return Err(invalid(format!(
+                "Catalog number {} does not fit the five-digit TLE field",
+                self.catalog_number
+            )));
+        }
+        if self.intl_designator.len() > 8 || !self.intl_designator.is_ascii() {
+            return Err(invalid(format!(
+                "International designator {:?} does not fit the eight-column TLE field",
+                self.intl_designator
+            )));
+        }
+        if !self.classification.is_ascii_alphanumeric() || self.ephemeris_type > 9 {
+            return Err(invalid("Classification and ephemeris type must be single characters".into()));
+        }
+
+        let (year, epoch_day) = format_epoch(self.epoch)?;
+
+        let eccentricity = (self.eccentricity * 1e7).round();
+        if !(0.0..1e7).contains(&eccentricity) {
+            return Err(invalid(format!(
+                "Eccentricity {} does not fit the seven-digit TLE field",
+                self.eccentricity
+            )));
+        }
+        if !(self.mean_motion > 0.0 && self.mean_motion < 100.0) {
+            return Err(invalid(format!(
+                "Mean motion {} rev/day does not fit the TLE field",
+                self.mean_motion
+            )));
+        }
+        if !(0.0..=180.0).contains(&self.inclination.get::<degree>()) {
+            return Err(invalid(format!(
+                "Inclination {}° is outside [0°, 180°]",
+                self.inclination.get::<degree>()
+            )));
+        }
+
+        let ndot = format!("{:.8}", self.ndot.abs());
+        let ndot = match ndot.strip_prefix('0') {
+            Some(fraction) => fraction,
+            None => {
+                return Err(invalid(format!("ndot {} does not fit the TLE field", self.ndot)));
+            }
+        };
+        let ndot_sign = if self.ndot < 0.0 { '-' } else { ' ' };
+
+        let mut line1 = format!(
+            "1 {:05}{} {:<8} {:02}{} {}{} {} {} {} {:4}",
+            self.catalog_number,
+            self.classification,
+            self.intl_designator,
+            year.rem_euclid(100),
+            epoch_day,
+            ndot_sign,
+            ndot,
+            format_assumed_decimal(self.nddot, "nddot")?,
+            format_assumed_decimal(self.bstar, "B*")?,
+            self.ephemeris_type,
+            self.element_set_number % 10_000,
+        );
+        let mut line2 = format!(
+            "2 {:05} {:8.4} {} {:07} {} {} {:11.8}{:5}",
+            self.catalog_number,
+            self.inclination.get::<degree>(),
+            format_wrapped_degrees(self.raan),
+            eccentricity as u32,
+            format_wrapped_degrees(self.arg_perigee),
+            format_wrapped_degrees(self.mean_anomaly),
+            self.mean_motion,
+            self.rev_number % 100_000,
+        );
+
+        for line in [&mut line1, &mut line2] {
+            let sum = checksum(line);
+            line.push(char::from_digit(sum, 10).unwrap_or('0'));
+        }
+        Ok((line1, line2))
+    }
+
+    /// Approximate ballistic coefficient `B = C_d·A/m` in m²/kg implied by B*.
+    ///
+    /// B* folds a reference atmospheric density into the drag term,
+    /// `B* = ρ₀·B/2`, where ρ₀ = 0.15696615 kg/m² per Earth radius is the
+    /// SGP4 reference density expressed in B*'s units. So
+    /// `B = 2·B*/ρ₀ ≈ 12.74·B*`. B* is a fitted parameter that also absorbs
+    /// other unmodelled along-track effects, so treat the result as an order
+    /// of magnitude; it is negative whenever B* is.
+    #[must_use]
+    pub fn ballistic_coefficient(&self) -> f64 {
+        /// SGP4 reference density ρ₀, in kg/m² per Earth radius.
+        const RHO_0: f64 = 0.156_966_15;
+
+        2.0 * self.bstar / RHO_0
+    }
+
+    /// Whether the element set needs the deep-space (SDP4) theory.
+    ///
+    /// True when the orbital period, from the Brouwer mean motion, is at
+    /// least 225 minutes (GEO, GTO, Molniya and similar orbits).
+    #[must_use]
+    pub fn is_deep_space(&self) -> bool {
+        self.mean_elements().is_deep_space()
+    }
+
+    /// Propagate the TLE to a target epoch with SGP4, or SDP4 for deep-space orbits.
+    ///
+    /// Applies the TLE's B* drag term and the J2-J4 secular and periodic terms
+    /// to the mean elements, which is what the element sets are fitted for.
+    /// When [`is_deep_space`](Self::is_deep_space) holds, the lunar-solar
+    /// secular and periodic terms and the 12 h / 24 h geopotential resonance
+    /// are added as well.
+    ///
+    /// # Frame
+    ///
+    /// SGP4 outputs are in TEME (True Equator, Mean Equinox) of date. They are
+    /// returned tagged as [`Icrs`] without a frame rotation, so expect an
+    /// offset of up to ~0.4° (precession since J2000) against true ICRS.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CelestialError::NumericalPrecisionError` if the perturbed
+    /// elements become invalid or the satellite has decayed.
+    pub fn propagate_sgp4(&self, target_epoch: DateTime<Utc>) -> CelestialResult<EphemerisState<Icrs>> {
+        use uom::si::length::meter;
+
+        let sgp4 = Sgp4::new(self.mean_elements())?;
+        let tsince = seconds_between(self.epoch, target_epoch) / 60.0;
+        let (r, v) = sgp4.propagate(tsince)?;
+
+        #[allow(deprecated)]
+        let position = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<meter>(r[0] * 1000.0),
+            Length::new::<meter>(r[1] * 1000.0),
+            Length::new::<meter>(r[2] * 1000.0),
+        );
+        let velocity = velocity_from_components(v.map(|c| c * 1000.0));
+
+        Ok(EphemerisState::new(position, velocity, target_epoch))
+    }
+
+    /// Mean elements in SGP4 units.
+    fn mean_elements(&self) -> MeanElements {
+        MeanElements {
+            no_kozai: self.mean_motion * std::f64::consts::TAU / 1440.0,
+            ecco: self.eccentricity,
+            inclo: self.inclination.get::<radian>(),
+            nodeo: self.raan.get::<radian>(),
+            argpo: self.arg_perigee.get::<radian>(),
+            mo: self.mean_anomaly.get::<radian>(),
+            bstar: self.bstar,
+            epoch_jd: 2_440_587.5 + seconds_between(DateTime::UNIX_EPOCH, self.epoch) / 86400.0,
+        }
+    }
+
     /// Convert TLE to Keplerian elements.
     ///
     /// This conversion computes the semi-major axis from the mean motion
-    /// using Earth's gravitational parameter.
+    /// using Earth's gravitational parameter, and the true anomaly from the
+    /// TLE mean anomaly by solving Kepler's equation.
     #[must_use]
     pub fn to_keplerian(&self) -> KeplerianElements {
         // Convert mean motion (rev/day) to rad/s
@@ -217,20 +586,46 @@ impl TleElements {
         let mu = super::constants::MU_EARTH;
         let a = (mu / (n * n)).powf(1.0 / 3.0);
 
+        let true_anomaly = true_anomaly_from_mean(
+            self.mean_anomaly.get::<radian>(),
+            self.eccentricity,
+            SolverConfig::default(),
+        )
+        .rem_euclid(2.0 * std::f64::consts::PI);
+
         KeplerianElements::new(
             Length::new::<kilometer>(a / 1000.0),
             self.eccentricity,
             self.inclination,
             self.raan,
             self.arg_perigee,
-            self.mean_anomaly,
+            Angle::new::<radian>(true_anomaly),
         )
     }
 
+    /// Residual between the mean motion implied by [`to_keplerian`](Self::to_keplerian)
+    /// and the mean motion stated in the TLE (revolutions per day).
+    ///
+    /// Recomputes `n = √(μ/a³)` from the derived semi-major axis and the
+    /// elements' `mu`. A non-negligible residual indicates inconsistent
+    /// gravitational parameters between data sources.
+    #[must_use]
+    pub fn verify_mean_motion(&self) -> f64 {
+        use uom::si::length::meter;
+
+        let elements = self.to_keplerian();
+        let a = elements.semi_major_axis.get::<meter>();
+        let n = (elements.mu / a.powi(3)).sqrt(); // rad/s
+        let rev_per_day = n * 86400.0 / (2.0 * std::f64::consts::PI);
+
+        rev_per_day - self.mean_motion
+    }
+
     /// Propagate the TLE to a future epoch using simplified two-body dynamics.
     ///
-    /// **Note**: This is a simplified propagation. For accurate satellite tracking,
-    /// use a proper SGP4/SDP4 implementation that accounts for perturbations.
+    /// **Note**: This is a simplified propagation that ignores J2, drag and the
+    /// TLE's mean-element definition, so it drifts by kilometers within an
+    /// orbit. For satellite tracking use [`propagate_sgp4`](Self::propagate_sgp4).
     ///
     /// # Errors
     ///
@@ -243,6 +638,161 @@ impl TleElements {
     }
 }
 
+impl fmt::Display for TleElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let a = self.to_keplerian().semi_major_axis.get::<kilometer>();
+        let period_min = 1440.0 / self.mean_motion;
+
+        write!(
+            f,
+            "TLE {:05} @ {}: i = {:.4}°, e = {:.7}, a = {:.1} km, period = {:.2} min",
+            self.catalog_number,
+            self.epoch.format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            self.inclination.get::<degree>(),
+            self.eccentricity,
+            a,
+            period_min
+        )
+    }
+}
+
+/// Parse a fixed-column numeric field, tolerating whitespace padding.
+fn parse_field<T: std::str::FromStr>(field: &str, name: &str) -> CelestialResult<T> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Missing {}", name),
+        });
+    }
+
+    field.parse().map_err(|_| CelestialError::InvalidCoordinates {
+        reason: format!("Invalid {}", name),
+    })
+}
+
+/// Parse the eccentricity field (columns 27-33, leading decimal point assumed).
+///
+/// Some providers right-justify the digits with spaces instead of zeros, so
+/// leading blanks count as zeros and trailing blanks are dropped.
+fn parse_eccentricity(field: &str) -> CelestialResult<f64> {
+    let trimmed = field.trim_end();
+    let digits: String = trimmed
+        .chars()
+        .map(|c| if c == ' ' { '0' } else { c })
+        .collect();
+
+    if trimmed.trim_start().is_empty() {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: "Missing eccentricity".into(),
+        });
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: "Invalid eccentricity".into(),
+        });
+    }
+
+    format!("0.{}", digits)
+        .parse::<f64>()
+        .map_err(|_| CelestialError::InvalidCoordinates {
+            reason: "Invalid eccentricity".into(),
+        })
+}
+
+/// TLE checksum: the sum of all digits, with each minus sign counting as 1, modulo 10.
+fn checksum(line: &str) -> u32 {
+    line.chars()
+        .map(|c| match c {
+            '-' => 1,
+            _ => c.to_digit(10).unwrap_or(0),
+        })
+        .sum::<u32>()
+        % 10
+}
+
+/// Format a value as a TLE "assumed decimal point" field, the inverse of
+/// [`parse_assumed_decimal`] (0.33518e-4 → ` 33518-4`).
+///
+/// Values whose exponent needs more than one digit are written as zero when
+/// tiny (below 1e-10) and rejected when large.
+fn format_assumed_decimal(value: f64, field: &str) -> CelestialResult<String> {
+    const ZERO: &str = " 00000-0";
+
+    if value == 0.0 {
+        return Ok(ZERO.to_string());
+    }
+
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    let mut exponent = value.abs().log10().floor() as i32 + 1;
+    let mut mantissa = (value.abs() / 10f64.powi(exponent) * 1e5).round() as u32;
+    if mantissa >= 100_000 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+
+    match exponent {
+        ..=-10 => Ok(ZERO.to_string()),
+        -9..=9 => {
+            let exponent_sign = if exponent < 0 { '-' } else { '+' };
+            Ok(format!("{sign}{mantissa:05}{exponent_sign}{}", exponent.abs()))
+        }
+        _ => Err(CelestialError::InvalidCoordinates {
+            reason: format!("{field} {value} does not fit the TLE exponent field"),
+        }),
+    }
+}
+
+/// Two-digit-year base and `DDD.DDDDDDDD` day of year for a TLE epoch.
+///
+/// The day is rounded to the field's 1e-8 day resolution before it is split,
+/// so an epoch a moment before midnight on 31 December rolls over into day
+/// 001 of the next year rather than printing day 366 or 367.
+fn format_epoch(epoch: DateTime<Utc>) -> CelestialResult<(i32, String)> {
+    const UNITS_PER_DAY: i64 = 100_000_000;
+
+    let jan1 = |year: i32| Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+    let mut year = epoch.year();
+    let mut units = (seconds_between(jan1(year), epoch) / 86400.0 * UNITS_PER_DAY as f64).round() as i64;
+
+    let days_in_year = (jan1(year + 1) - jan1(year)).num_days();
+    if units >= days_in_year * UNITS_PER_DAY {
+        units -= days_in_year * UNITS_PER_DAY;
+        year += 1;
+    }
+    if !(1957..=2056).contains(&year) {
+        return Err(CelestialError::InvalidCoordinates {
+            reason: format!("Epoch year {year} is outside the TLE range 1957-2056"),
+        });
+    }
+
+    let day = 1 + units / UNITS_PER_DAY;
+    Ok((year, format!("{day:03}.{:08}", units % UNITS_PER_DAY)))
+}
+
+/// An angle in degrees wrapped into `[0, 360)` at the TLE's four decimals.
+fn format_wrapped_degrees(angle: Angle) -> String {
+    let degrees = (angle.get::<degree>() * 1e4).round().rem_euclid(3_600_000.0) / 1e4;
+    format!("{degrees:8.4}")
+}
+
+/// Parse a TLE "assumed decimal point" field such as ` 33518-4` (= 0.33518e-4).
+fn parse_assumed_decimal(field: &str) -> Option<f64> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Some(0.0);
+    }
+
+    let (sign, digits) = match field.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, field.strip_prefix('+').unwrap_or(field)),
+    };
+    let split = digits.rfind(['-', '+']).filter(|&i| i > 0)?;
+    let mantissa = format!("0.{}", &digits[..split]).parse::<f64>().ok()?;
+    let exponent = digits[split..].parse::<i32>().ok()?;
+
+    Some(sign * mantissa * 10f64.powi(exponent))
+}
+
 /// Convert TLE epoch (year + day-of-year) to DateTime.
 fn tle_epoch_to_datetime(year: i32, day_of_year: f64) -> CelestialResult<DateTime<Utc>> {
     let jan1 = Utc
@@ -254,9 +804,10 @@ fn tle_epoch_to_datetime(year: i32, day_of_year: f64) -> CelestialResult<DateTim
 
     let whole_days = day_of_year.floor() as i64 - 1;
     let fractional_day = day_of_year - day_of_year.floor();
-    let seconds = (fractional_day * 86400.0).round() as i64;
+    // Keep sub-second precision: at orbital speeds, 1 s of epoch error is ~7 km
+    let nanoseconds = (fractional_day * 86400.0e9).round() as i64;
 
-    let epoch = jan1 + Duration::days(whole_days) + Duration::seconds(seconds);
+    let epoch = jan1 + Duration::days(whole_days) + Duration::nanoseconds(nanoseconds);
 
     Ok(epoch)
 }
@@ -268,8 +819,8 @@ mod tests {
 
     #[test]
     fn parse_iss_tle() {
-        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9992";
-        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236008";
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
 
         let tle = TleElements::from_lines(line1, line2).unwrap();
 
@@ -278,6 +829,127 @@ mod tests {
         assert!((tle.eccentricity() - 0.0001473).abs() < 0.000001);
     }
 
+    #[test]
+    fn catalog_with_named_sets_parses_each_satellite() {
+        let catalog = "ISS (ZARYA)
+1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997
+2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000
+
+0 VANGUARD 1
+1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667
+";
+        let sets: Vec<_> = TleElements::parse_catalog(catalog).into_iter().map(Result::unwrap).collect();
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].name(), Some("ISS (ZARYA)"));
+        assert_eq!(sets[0].catalog_number(), 25544);
+        assert_eq!(sets[1].name(), Some("VANGUARD 1"));
+        assert_eq!(sets[1].catalog_number(), 5);
+
+        let unnamed = TleElements::parse_catalog(catalog.split_once('\n').unwrap().1);
+        assert!(unnamed[0].as_ref().unwrap().name().is_none());
+
+        let broken = TleElements::parse_catalog("JUNK\nMORE JUNK\n");
+        assert_eq!(broken.len(), 2);
+        assert!(broken.iter().all(Result::is_err));
+    }
+
+    #[test]
+    fn checksums_are_verified() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        assert!(TleElements::from_lines(line1, line2).is_ok());
+
+        // Transposed inclination digits (51.6461 -> 51.6416) keep the digit sum...
+        let swapped = "2 25544  51.6416 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        assert!(TleElements::from_lines(line1, swapped).is_ok());
+
+        // ...but a mistyped digit does not
+        let corrupted = "2 25544  51.6462 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        assert!(matches!(
+            TleElements::from_lines(line1, corrupted),
+            Err(CelestialError::InvalidCoordinates { reason }) if reason.contains("line 2 checksum")
+        ));
+
+        let unchecked = TleElements::from_lines_unchecked(line1, corrupted).unwrap();
+        assert!((unchecked.inclination().get::<degree>() - 51.6462).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_lines_roundtrips_iss() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+
+        let (out1, out2) = tle.to_lines().unwrap();
+        assert_eq!(out1, line1);
+        assert_eq!(out2, line2);
+        assert_eq!(TleElements::from_lines(&out1, &out2).unwrap(), tle);
+
+        let negative = TleElements { bstar: -1.2345e-5, ndot: -2.5e-7, ..tle };
+        let (out1, out2) = negative.to_lines().unwrap();
+        assert_eq!(&out1[33..61], "-.00000025  00000-0 -12345-4");
+        let reparsed = TleElements::from_lines(&out1, &out2).unwrap();
+        assert!((reparsed.bstar() + 1.2345e-5).abs() < 1e-15);
+        assert!((reparsed.ndot() + 2.5e-7).abs() < 1e-15);
+    }
+
+    #[test]
+    fn to_lines_rejects_or_normalizes_out_of_range_fields() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        let invalid = |tle: TleElements| matches!(tle.to_lines(), Err(CelestialError::InvalidCoordinates { .. }));
+
+        assert!(invalid(TleElements { catalog_number: 270_000, ..tle.clone() }));
+        assert!(invalid(TleElements { eccentricity: 0.999_999_96, ..tle.clone() }));
+        assert!(invalid(TleElements { bstar: 1e12, ..tle.clone() }));
+
+        let tiny = TleElements { bstar: 1e-12, ..tle.clone() };
+        let (out1, _) = tiny.to_lines().unwrap();
+        assert_eq!(&out1[53..61], " 00000-0");
+
+        let new_year_eve = Utc.with_ymd_and_hms(2020, 12, 31, 23, 59, 59).unwrap() + Duration::nanoseconds(999_999_900);
+        let (out1, out2) = TleElements { epoch: new_year_eve, ..tle }.to_lines().unwrap();
+        assert_eq!(&out1[18..32], "21001.00000000");
+        let reparsed = TleElements::from_lines(&out1, &out2).unwrap();
+        assert_eq!(reparsed.epoch(), Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn from_keplerian_exports_through_to_lines() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+
+        let exported = TleElements::from_keplerian(25544, &tle.to_keplerian(), tle.epoch(), tle.bstar()).unwrap();
+        let (out1, out2) = exported.to_lines().unwrap();
+        assert_eq!(&out1[18..32], &line1[18..32]);
+        assert_eq!(&out1[53..61], &line1[53..61]);
+        assert_eq!(&out2[8..63], &line2[8..63]);
+        assert!(TleElements::from_lines(&out1, &out2).is_ok());
+    }
+
+    #[test]
+    fn space_padded_fields_parse_like_canonical() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+        let padded1 = "1 25544U 98067A   20206.185396    .00001406  00000-0  33518-4 0  9997";
+        let padded2 = "2 25544  51.6461 339.8014    1473  94.8340 265.2864 15.49309432236000";
+
+        let canonical = TleElements::from_lines(line1, line2).unwrap();
+        let padded = TleElements::from_lines(padded1, padded2).unwrap();
+
+        assert_eq!(padded.eccentricity(), canonical.eccentricity());
+        assert_eq!(padded.epoch(), canonical.epoch());
+
+        let blank = "2 25544  51.6461 339.8014          94.8340 265.2864 15.49309432236005";
+        assert!(matches!(
+            TleElements::from_lines(line1, blank),
+            Err(CelestialError::InvalidCoordinates { reason }) if reason == "Missing eccentricity"
+        ));
+    }
+
     #[test]
     fn tle_epoch_conversion() {
         let dt = tle_epoch_to_datetime(2020, 206.18539600).unwrap();
@@ -290,8 +962,8 @@ mod tests {
 
     #[test]
     fn tle_to_keplerian() {
-        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9992";
-        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236008";
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
 
         let tle = TleElements::from_lines(line1, line2).unwrap();
         let kep = tle.to_keplerian();
@@ -300,4 +972,194 @@ mod tests {
         let a_km = kep.semi_major_axis.get::<kilometer>();
         assert!(a_km > 6700.0 && a_km < 6900.0);
     }
+
+    #[test]
+    fn tle_mean_anomaly_is_converted_to_true_anomaly() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        let kep = tle.to_keplerian();
+
+        // Equation of center: ν - M ≈ 2e·sin(M) + (5/4)e²·sin(2M)
+        let m = tle.mean_anomaly.get::<radian>();
+        let e = tle.eccentricity();
+        let expected = 2.0 * e * m.sin() + 1.25 * e * e * (2.0 * m).sin();
+        let actual = kep.true_anomaly.get::<radian>() - m;
+        assert!(expected.abs() > 2e-4);
+        assert!((actual - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mean_motion_derivatives_and_bstar_are_parsed() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        assert!((tle.bstar() - 0.33518e-4).abs() < 1e-15);
+        assert!((tle.ndot() - 0.00001406).abs() < 1e-15);
+        assert_eq!(tle.nddot(), 0.0);
+
+        assert!((parse_assumed_decimal("-11606-4").unwrap() + 0.11606e-4).abs() < 1e-18);
+        assert!((parse_assumed_decimal(" 12345+1").unwrap() - 1.2345).abs() < 1e-15);
+        assert_eq!(parse_assumed_decimal("abc"), None);
+    }
+
+    #[test]
+    fn catalog_metadata_is_parsed() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        assert_eq!(tle.classification(), 'U');
+        assert_eq!(tle.intl_designator(), "98067A");
+        assert_eq!(tle.element_set_number(), 999);
+        assert_eq!(tle.rev_number(), 23600);
+    }
+
+    #[test]
+    fn ballistic_coefficient_grows_with_bstar() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        let draggier = TleElements { bstar: 3.0 * tle.bstar, ..tle.clone() };
+
+        assert!((tle.ballistic_coefficient() - 12.741_621 * 0.33518e-4).abs() < 1e-9);
+        assert!(draggier.ballistic_coefficient() > tle.ballistic_coefficient());
+    }
+
+    #[test]
+    fn iss_mean_motion_is_self_consistent() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+
+        assert!(tle.verify_mean_motion().abs() < 1e-9);
+    }
+
+    #[test]
+    fn display_summarizes_elements() {
+        let line1 = "1 25544U 98067A   20206.18539600  .00001406  00000-0  33518-4 0  9997";
+        let line2 = "2 25544  51.6461 339.8014 0001473  94.8340 265.2864 15.49309432236000";
+
+        let summary = TleElements::from_lines(line1, line2).unwrap().to_string();
+
+        assert!(summary.contains("25544"));
+        assert!(summary.contains("51.6461°"));
+        assert!(summary.contains("km"));
+    }
+
+    #[test]
+    fn sgp4_matches_reference_vectors_near_earth() {
+        use uom::si::length::meter;
+
+        // Vallado et al. (2006) verification case 00005
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        assert!(!tle.is_deep_space());
+
+        let check = |minutes: i64, r_km: [f64; 3], v_kms: [f64; 3]| {
+            let state = tle.propagate_sgp4(tle.epoch() + Duration::minutes(minutes)).unwrap();
+            let r = state.position().to_cartesian().map(|c| c.get::<meter>() / 1000.0);
+            let v = crate::timed::velocity_components(state.velocity()).map(|c| c / 1000.0);
+            for i in 0..3 {
+                assert!((r[i] - r_km[i]).abs() < 1e-3, "r[{i}] at {minutes} min: {}", r[i]);
+                assert!((v[i] - v_kms[i]).abs() < 1e-6, "v[{i}] at {minutes} min: {}", v[i]);
+            }
+        };
+
+        check(0, [7022.46529266, -1400.08296755, 0.03995155], [1.893841015, 6.405893759, 4.534807250]);
+        check(
+            360,
+            [-7154.03120202, -3783.17682504, -3536.19412294],
+            [4.741887409, -4.151817765, -2.093935425],
+        );
+    }
+
+    #[test]
+    fn sdp4_matches_reference_vector_deep_space() {
+        use uom::si::length::meter;
+
+        // Vallado et al. (2006) verification case 08195 (Molniya, 12 h resonance)
+        let line1 = "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813";
+        let line2 = "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+        assert!(tle.is_deep_space());
+
+        let state = tle.propagate_sgp4(tle.epoch()).unwrap();
+        let r = state.position().to_cartesian().map(|c| c.get::<meter>() / 1000.0);
+        let v = crate::timed::velocity_components(state.velocity()).map(|c| c / 1000.0);
+        let (r_ref, v_ref) = ([2349.89483350, -14785.93811562, 0.02119378], [2.721488096, -3.256811655, 4.498416672]);
+        for i in 0..3 {
+            assert!((r[i] - r_ref[i]).abs() < 1e-3, "r[{i}] = {}", r[i]);
+            assert!((v[i] - v_ref[i]).abs() < 1e-6, "v[{i}] = {}", v[i]);
+        }
+    }
+
+    #[test]
+    fn sdp4_resonance_and_periodics_hold_over_a_day() {
+        use uom::si::length::meter;
+
+        // Vallado et al. (2006) TEME vectors (km, km/s). 08195 is a Molniya
+        // orbit in the 12 h resonance; 23333 has e = 0.97 and no resonance,
+        // so it exercises the lunar-solar terms alone.
+        type Vectors = &'static [(i64, [f64; 3], [f64; 3])];
+        #[rustfmt::skip]
+        let cases: [(&str, &str, Vectors); 2] = [
+            (
+                "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813",
+                "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656",
+                &[
+                    (120, [15223.91713658, -17852.95881713, 25280.39558224], [1.079041732, 0.875187372, 2.485682813]),
+                    (720, [2622.13222207, -15125.15464924, 474.51048398], [2.688287199, -3.078426664, 4.494979530]),
+                    (1440, [2890.80638268, -15446.43952300, 948.77010176], [2.654407490, -2.909344895, 4.486437362]),
+                ],
+            ),
+            (
+                "1 23333U 94071A   94305.49999999 -.00172956  26967-3  10000-3 0    15",
+                "2 23333  28.7490   2.3720 9728298  30.4360   1.3500  0.07309491    70",
+                &[
+                    (120, [-44672.91239680, -6213.11996581, -1738.80131727], [-3.719475070, -1.336673022, -0.621888261]),
+                    (720, [-127965.80064891, -43363.32967165, -19809.90480432], [-1.789652016, -0.888278463, -0.441254468]),
+                    (1440, [-189427.87533074, -76155.54943344, -36279.19882816], [-1.260024473, -0.694896053, -0.351058133]),
+                ],
+            ),
+        ];
+
+        for (line1, line2, vectors) in cases {
+            let tle = TleElements::from_lines(line1, line2).unwrap();
+            assert!(tle.is_deep_space());
+            for &(minutes, r_ref, v_ref) in vectors {
+                let state = tle.propagate_sgp4(tle.epoch() + Duration::minutes(minutes)).unwrap();
+                let r = state.position().to_cartesian().map(|c| c.get::<meter>() / 1000.0);
+                let v = crate::timed::velocity_components(state.velocity()).map(|c| c / 1000.0);
+                for i in 0..3 {
+                    // 1 mm and 1 mm/s
+                    assert!((r[i] - r_ref[i]).abs() < 1e-6, "{line1}: r[{i}] = {} at {minutes} min", r[i]);
+                    assert!((v[i] - v_ref[i]).abs() < 1e-6, "{line1}: v[{i}] = {} at {minutes} min", v[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn geosynchronous_tle_selects_deep_space() {
+        let line1 = "1 28626U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2190";
+        let line2 = "2 28626   0.0019 286.9433 0000335  13.7918  55.6504  1.00270176  4891";
+        let tle = TleElements::from_lines(line1, line2).unwrap();
+
+        assert_eq!(tle.ephemeris_type(), 0);
+        assert_eq!(tle.classification(), 'U');
+        assert!(tle.is_deep_space());
+
+        // After one sidereal orbit the satellite returns close to its start
+        let period = Duration::milliseconds((86_400_000.0 / 1.00270176) as i64);
+        let start = tle.propagate_sgp4(tle.epoch()).unwrap();
+        let orbit = tle.propagate_sgp4(tle.epoch() + period).unwrap();
+        let drift = start.position().distance_from(orbit.position()).get::<kilometer>();
+        assert!(drift < 50.0, "drift over one orbit: {drift} km");
+    }
 }
@@ -9,9 +9,21 @@ use sguaba::math::RigidBodyTransform;
 use sguaba::systems::Ecef;
 use sguaba::Vector;
 
-use crate::constants::{icrs_to_ecef_rotation, mci_to_icrs_rotation};
+use crate::additional_frames::{
+    Ecliptic, EclipticOfDate, EclipticOrigin, Eme2000, Galactic, Obliquity, Observer, Topocentric,
+};
+use crate::astrodynamics::sun_position_icrs;
+use crate::constants::{
+    frame_bias_rotation, icrs_to_ecef_rotation, icrs_to_galactic_rotation, mci_to_icrs_rotation, mci_to_icrs_rotation_at,
+    bias_precession_nutation, j2000_ecliptic_rotation, mean_obliquity, nutation_angles, utc_to_julian_date,
+    PrecessionNutationModel,
+};
+use crate::eop::EopProvider;
+use crate::ext::VelocityTransformExt;
 use crate::frames::{Icrs, Mci};
 use crate::rotation_helper::rotation_from_quaternion;
+use crate::time_scales::utc_to_tt;
+use crate::timed::{velocity_components, velocity_from_components, EphemerisState};
 
 // =======================================================================================
 // TRANSFORM CONSTRUCTORS
@@ -24,7 +36,8 @@ use crate::rotation_helper::rotation_from_quaternion;
 ///
 /// # Accuracy
 ///
-/// < 30 milliarcseconds (2020-2050) using IAU 2006/2000A precession + ERA.
+/// Up to ~20 arcseconds: IAU 2006 precession, frame bias and ERA, without
+/// nutation or polar motion.
 #[must_use]
 pub fn icrs_to_ecef_at(time: DateTime<Utc>) -> RigidBodyTransform<Icrs, Ecef> {
     let quat = icrs_to_ecef_rotation(time);
@@ -34,6 +47,206 @@ pub fn icrs_to_ecef_at(time: DateTime<Utc>) -> RigidBodyTransform<Icrs, Ecef> {
     }
 }
 
+/// Constructs the transform from ICRS to ECEF at the specified time using
+/// Earth Orientation Parameters.
+///
+/// Includes nutation, and applies polar motion and UT1-UTC from `eop`; see
+/// [`icrs_to_ecef_rotation_with_eop`](crate::constants::icrs_to_ecef_rotation_with_eop).
+///
+/// # Accuracy
+///
+/// ~0.1-0.2 arcsecond, limited by the truncated nutation series.
+#[must_use]
+pub fn icrs_to_ecef_at_with_eop(time: DateTime<Utc>, eop: &dyn EopProvider) -> RigidBodyTransform<Icrs, Ecef> {
+    let quat = crate::constants::icrs_to_ecef_rotation_with_eop(time, true, Some(eop));
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the transform from ICRS to ECEF at the specified time with a
+/// selectable precession model.
+///
+/// Includes nutation; see
+/// [`icrs_to_ecef_rotation_with_model`](crate::constants::icrs_to_ecef_rotation_with_model).
+/// Pass the same `model` to [`active_model_info`] when recording results.
+#[must_use]
+pub fn icrs_to_ecef_at_with_model(time: DateTime<Utc>, model: PrecessionNutationModel) -> RigidBodyTransform<Icrs, Ecef> {
+    let quat = crate::constants::icrs_to_ecef_rotation_with_model(time, true, model);
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the transform from ICRS to ECEF at the specified time with
+/// polar motion from pole coordinates `xp`, `yp` (radians).
+///
+/// Includes nutation; UTC is treated as UT1. See
+/// [`icrs_to_ecef_rotation_with_polar_motion`](crate::constants::icrs_to_ecef_rotation_with_polar_motion),
+/// and [`icrs_to_ecef_at_with_eop`] to also apply UT1-UTC.
+#[must_use]
+pub fn icrs_to_ecef_at_with_polar_motion(time: DateTime<Utc>, xp: f64, yp: f64) -> RigidBodyTransform<Icrs, Ecef> {
+    let quat = crate::constants::icrs_to_ecef_rotation_with_polar_motion(time, true, xp, yp);
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Where the UT1-UTC offset used for Earth rotation comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dut1Source {
+    /// UTC is treated as UT1 (up to 0.9 s of Earth rotation error).
+    AssumedZero,
+    /// Taken from an [`EopProvider`].
+    Provider,
+}
+
+/// Models and corrections behind the ICRS ↔ ECEF transforms, for recording
+/// alongside results.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModelInfo {
+    /// Precession model selected for the ICRS ↔ ECEF chain
+    pub precession: PrecessionNutationModel,
+    /// Nutation series and its truncation
+    pub nutation: String,
+    /// Whether Earth Orientation Parameters (polar motion, DUT1) are applied
+    pub eop_loaded: bool,
+    /// TAI-UTC in seconds at the queried time
+    pub leap_seconds: f64,
+    /// Source of UT1-UTC
+    pub dut1_source: Dut1Source,
+}
+
+/// Report the models and corrections in use for transforms at `time`.
+///
+/// `eop` is the provider passed to [`icrs_to_ecef_at_with_eop`], or `None`
+/// for the EOP-free transforms such as [`icrs_to_ecef_at`]. `model` is the
+/// precession model passed to [`icrs_to_ecef_at_with_model`], or the default
+/// [`PrecessionNutationModel::Iau2006`] used by all other transforms.
+#[must_use]
+pub fn active_model_info(
+    time: DateTime<Utc>,
+    eop: Option<&dyn EopProvider>,
+    model: PrecessionNutationModel,
+) -> ModelInfo {
+    ModelInfo {
+        precession: model,
+        nutation: "IAU 2000B truncated to its 5 largest of 77 terms".to_string(),
+        eop_loaded: eop.is_some(),
+        leap_seconds: crate::time_scales::leap_seconds_at(time),
+        dut1_source: if eop.is_some() {
+            Dut1Source::Provider
+        } else {
+            Dut1Source::AssumedZero
+        },
+    }
+}
+
+/// Memoized ICRS → ECEF rotations, bucketed by epoch.
+///
+/// Epochs are grouped into buckets of a fixed `resolution`, and each bucket's
+/// rotation is computed once, at the bucket start, then reused. Unlike the
+/// single-slot [`CachedTransform`](crate::CachedTransform), a propagation loop
+/// that revisits earlier epochs keeps its hits. At most `capacity` buckets are
+/// kept; when full, the bucket farthest in time from the new one is evicted,
+/// which for a loop stepping forward is the one it is least likely to revisit.
+///
+/// The Earth turns about 15 arcsec per second, so the resolution is also the
+/// worst-case rotation error: 1 s is up to ~460 m at the equator, 1 ms ~0.5 m.
+#[derive(Debug)]
+pub struct RotationCache {
+    /// Bucket width in microseconds
+    resolution_us: i64,
+    /// Maximum number of buckets held
+    capacity: usize,
+    /// Rotation per bucket index
+    entries: std::sync::RwLock<std::collections::HashMap<i64, nalgebra::UnitQuaternion<f64>>>,
+}
+
+impl RotationCache {
+    /// Number of buckets held by [`new`](Self::new): over an hour of 1 s buckets.
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create an empty cache with the given bucket width (at least 1 µs),
+    /// holding up to [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY) buckets.
+    #[must_use]
+    pub fn new(resolution: chrono::Duration) -> Self {
+        Self::with_capacity(resolution, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create an empty cache holding up to `capacity` buckets. A capacity of
+    /// zero is treated as one.
+    #[must_use]
+    pub fn with_capacity(resolution: chrono::Duration, capacity: usize) -> Self {
+        Self {
+            resolution_us: resolution.num_microseconds().unwrap_or(i64::MAX).max(1),
+            capacity: capacity.max(1),
+            entries: std::sync::RwLock::new(std::collections::HashMap::new()),
+        }
+    }
+
+    /// ICRS → ECEF rotation for the bucket containing `time`, as computed by
+    /// [`icrs_to_ecef_rotation`] at the bucket start.
+    pub fn rotation_at(&self, time: DateTime<Utc>) -> nalgebra::UnitQuaternion<f64> {
+        self.rotation_with(time, icrs_to_ecef_rotation)
+    }
+
+    /// Cached rotation for the bucket containing `time`, calling `compute` with
+    /// the bucket start on a miss.
+    fn rotation_with<F>(&self, time: DateTime<Utc>, compute: F) -> nalgebra::UnitQuaternion<f64>
+    where
+        F: FnOnce(DateTime<Utc>) -> nalgebra::UnitQuaternion<f64>,
+    {
+        let bucket = time.timestamp_micros().div_euclid(self.resolution_us);
+        if let Some(quat) = self.entries.read().unwrap().get(&bucket) {
+            return *quat;
+        }
+
+        let start = DateTime::from_timestamp_micros(bucket * self.resolution_us).unwrap_or(time);
+        let quat = compute(start);
+        let mut entries = self.entries.write().unwrap();
+        if entries.len() >= self.capacity && !entries.contains_key(&bucket) {
+            let farthest = entries.keys().copied().max_by_key(|&other| other.abs_diff(bucket));
+            if let Some(farthest) = farthest {
+                entries.remove(&farthest);
+            }
+        }
+        entries.insert(bucket, quat);
+        quat
+    }
+
+    /// Cached equivalent of [`icrs_to_ecef_at`].
+    pub fn icrs_to_ecef_at(&self, time: DateTime<Utc>) -> RigidBodyTransform<Icrs, Ecef> {
+        let quat = self.rotation_at(time);
+        unsafe {
+            let rotation = rotation_from_quaternion(quat);
+            RigidBodyTransform::new(Vector::zero(), rotation)
+        }
+    }
+
+    /// Number of buckets computed so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    /// Whether no bucket has been computed yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached rotations.
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+}
+
 /// Constructs the transform from ECEF to ICRS at the specified time.
 ///
 /// This is the inverse of [icrs_to_ecef_at].
@@ -62,3 +275,578 @@ pub fn mci_to_icrs() -> RigidBodyTransform<Mci, Icrs> {
 pub fn icrs_to_mci() -> RigidBodyTransform<Icrs, Mci> {
     mci_to_icrs().inverse()
 }
+
+/// Constructs the transform from MCI to ICRS with the lunar pole at the specified time.
+///
+/// Unlike [mci_to_icrs], which uses the frozen J2000 pole, this applies the
+/// IAU 2009 linear pole rates (about 0.005° per century). Libration is not
+/// included.
+#[must_use]
+pub fn mci_to_icrs_at(time: DateTime<Utc>) -> RigidBodyTransform<Mci, Icrs> {
+    let quat = mci_to_icrs_rotation_at(utc_to_julian_date(time));
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the transform from ICRS to MCI with the lunar pole at the specified time.
+///
+/// This is the inverse of [mci_to_icrs_at].
+#[must_use]
+pub fn icrs_to_mci_at(time: DateTime<Utc>) -> RigidBodyTransform<Icrs, Mci> {
+    mci_to_icrs_at(time).inverse()
+}
+
+/// Constructs the transform from ECEF to MCI at the specified time.
+///
+/// Composes ECEF → ICRS (time-dependent Earth rotation) with ICRS → MCI
+/// (approximately time-independent), which is the chain needed to relate a
+/// ground antenna to a lunar target.
+///
+/// # Note
+///
+/// Like [mci_to_icrs], this only re-orients axes; it does not translate
+/// between the geocenter and the selenocenter.
+#[must_use]
+pub fn ecef_to_mci_at(time: DateTime<Utc>) -> RigidBodyTransform<Ecef, Mci> {
+    ecef_to_icrs_at(time).and_then(icrs_to_mci())
+}
+
+/// Constructs the transform from MCI to ECEF at the specified time.
+///
+/// This is the inverse of [ecef_to_mci_at].
+#[must_use]
+pub fn mci_to_ecef_at(time: DateTime<Utc>) -> RigidBodyTransform<Mci, Ecef> {
+    ecef_to_mci_at(time).inverse()
+}
+
+/// Constructs the transform from ECEF to an observer's [`Topocentric`] (ENU) frame.
+///
+/// The origin moves to [`Observer::to_ecef`] and the axes turn to local east,
+/// north and up. The Earth-fixed result does not depend on time.
+#[must_use]
+pub fn ecef_to_topocentric(observer: &Observer) -> RigidBodyTransform<Ecef, Topocentric> {
+    let [east, north, up] = crate::ext::enu_basis(observer.latitude, observer.longitude);
+    let quat = nalgebra::UnitQuaternion::from_rotation_matrix(&nalgebra::Rotation3::from_basis_unchecked(&[
+        east, north, up,
+    ]));
+    let [x, y, z] = observer.to_ecef().to_cartesian();
+    #[allow(deprecated)]
+    let translation = Vector::from_cartesian(x, y, z);
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(translation, rotation)
+    }
+}
+
+/// Constructs the transform from ICRS to an observer's [`Topocentric`] frame
+/// at the specified time.
+///
+/// Chains [`icrs_to_ecef_at`] with [`ecef_to_topocentric`], so it inherits
+/// the accuracy of the former. Geocentric ICRS positions come out relative
+/// to the observer, including topocentric parallax.
+#[must_use]
+pub fn icrs_to_topocentric_at(observer: &Observer, time: DateTime<Utc>) -> RigidBodyTransform<Icrs, Topocentric> {
+    icrs_to_ecef_at(time).and_then(ecef_to_topocentric(observer))
+}
+
+/// Constructs the transform from an observer's [`Topocentric`] frame to ICRS
+/// at the specified time.
+///
+/// This is the inverse of [icrs_to_topocentric_at].
+#[must_use]
+pub fn topocentric_to_icrs_at(observer: &Observer, time: DateTime<Utc>) -> RigidBodyTransform<Topocentric, Icrs> {
+    icrs_to_topocentric_at(observer, time).inverse()
+}
+
+/// Constructs the transform from EME2000 to ICRS.
+///
+/// Applies the IAU 2006 frame bias (about 23 milliarcseconds), so EME2000
+/// positions enter the ICRS graph explicitly rather than being treated as
+/// equal. At geostationary distance the bias amounts to roughly 5 m.
+#[must_use]
+pub fn eme2000_to_icrs() -> RigidBodyTransform<Eme2000, Icrs> {
+    icrs_to_eme2000().inverse()
+}
+
+/// Constructs the transform from ICRS to EME2000.
+///
+/// This is the inverse of [eme2000_to_icrs].
+#[must_use]
+pub fn icrs_to_eme2000() -> RigidBodyTransform<Icrs, Eme2000> {
+    // The bias matrix maps ICRS to EME2000; transforms apply the inverse of
+    // their rotation, matching `mci_to_icrs`.
+    let quat = frame_bias_rotation().inverse();
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the fixed transform from the J2000 mean equator to the J2000
+/// mean ecliptic.
+///
+/// Rotates about the equinox direction by the J2000 mean obliquity
+/// (≈ 23.4393°), with no time dependence, as used to convert heliocentric
+/// orbital elements between equatorial and ecliptic axes. The rotation is
+/// cached; see [`j2000_ecliptic_rotation`].
+#[must_use]
+pub fn j2000_equator_to_ecliptic() -> RigidBodyTransform<Eme2000, Ecliptic> {
+    let quat = *j2000_ecliptic_rotation();
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the transform from ICRS to Galactic coordinates.
+///
+/// Uses the IAU Galactic pole and the longitude of the celestial pole; the
+/// transform is time-independent.
+#[must_use]
+pub fn icrs_to_galactic() -> RigidBodyTransform<Icrs, Galactic> {
+    let quat = *icrs_to_galactic_rotation();
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(Vector::zero(), rotation)
+    }
+}
+
+/// Constructs the transform from Galactic coordinates to ICRS.
+///
+/// This is the inverse of [icrs_to_galactic].
+#[must_use]
+pub fn galactic_to_icrs() -> RigidBodyTransform<Galactic, Icrs> {
+    icrs_to_galactic().inverse()
+}
+
+/// Constructs the transform from ICRS to ecliptic coordinates at the specified time.
+///
+/// The axes are the fixed J2000 mean ecliptic and equinox of [`Ecliptic`]:
+/// the frame bias to EME2000 followed by a rotation about the J2000 equinox
+/// by the IAU 2006 obliquity ε₀ = 84381.406″, so this matches
+/// [`icrs_to_eme2000`] followed by [`j2000_equator_to_ecliptic`]. `time`
+/// only places the Sun: with [`EclipticOrigin::Heliocentric`] the origin is
+/// moved from the geocenter to the Sun's position at `time`.
+#[must_use]
+pub fn icrs_to_ecliptic_at(time: DateTime<Utc>, origin: EclipticOrigin) -> RigidBodyTransform<Icrs, Ecliptic> {
+    let quat = frame_bias_rotation().inverse() * j2000_ecliptic_rotation();
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(ecliptic_origin(time, origin), rotation)
+    }
+}
+
+/// Constructs the transform from ecliptic coordinates to ICRS at the specified time.
+///
+/// This is the inverse of [icrs_to_ecliptic_at].
+#[must_use]
+pub fn ecliptic_to_icrs_at(time: DateTime<Utc>, origin: EclipticOrigin) -> RigidBodyTransform<Ecliptic, Icrs> {
+    icrs_to_ecliptic_at(time, origin).inverse()
+}
+
+/// Constructs the transform from ICRS to the ecliptic and equinox of date.
+///
+/// Applies the frame bias and IAU 2006 precession to reach the mean equator
+/// and equinox of date, with [`Obliquity::True`] also the nutation of
+/// [`nutation_matrix`](crate::constants::nutation_matrix) to reach the true
+/// equator and equinox, then tilts by the matching (mean or true) obliquity
+/// of date. The nutation series is truncated to ~0.1-0.2 arcsecond.
+/// `origin` is handled as in [`icrs_to_ecliptic_at`].
+#[must_use]
+pub fn icrs_to_ecliptic_of_date_at(
+    time: DateTime<Utc>,
+    origin: EclipticOrigin,
+    obliquity: Obliquity,
+) -> RigidBodyTransform<Icrs, EclipticOfDate> {
+    let jd_tt = utc_to_tt(time);
+    let (nutated, epsilon) = match obliquity {
+        Obliquity::Mean => (false, mean_obliquity(jd_tt)),
+        Obliquity::True => (true, mean_obliquity(jd_tt) + nutation_angles(jd_tt).1),
+    };
+    let quat = bias_precession_nutation(jd_tt, nutated, PrecessionNutationModel::Iau2006)
+        * nalgebra::UnitQuaternion::from_axis_angle(&nalgebra::Vector3::x_axis(), epsilon);
+    unsafe {
+        let rotation = rotation_from_quaternion(quat);
+        RigidBodyTransform::new(ecliptic_origin(time, origin), rotation)
+    }
+}
+
+/// Constructs the transform from the ecliptic and equinox of date to ICRS.
+///
+/// This is the inverse of [icrs_to_ecliptic_of_date_at].
+#[must_use]
+pub fn ecliptic_of_date_to_icrs_at(
+    time: DateTime<Utc>,
+    origin: EclipticOrigin,
+    obliquity: Obliquity,
+) -> RigidBodyTransform<EclipticOfDate, Icrs> {
+    icrs_to_ecliptic_of_date_at(time, origin, obliquity).inverse()
+}
+
+/// ICRS position of the ecliptic frames' origin at `time`.
+fn ecliptic_origin(time: DateTime<Utc>, origin: EclipticOrigin) -> Vector<Icrs> {
+    match origin {
+        EclipticOrigin::Geocentric => Vector::zero(),
+        EclipticOrigin::Heliocentric => {
+            let [x, y, z] = sun_position_icrs(time).to_cartesian();
+            #[allow(deprecated)]
+            Vector::from_cartesian(x, y, z)
+        }
+    }
+}
+
+/// Transforms a full position-velocity state between frames.
+///
+/// The position goes through `tf` as usual. The velocity is rotated into the
+/// target axes and, when `omega` is given, corrected with `-ω × r` for a
+/// target frame rotating at `omega` (rad/s, target-frame axes) relative to
+/// the source; see
+/// [`VelocityTransformExt::transform_velocity_rotating`]. Pass `None` for
+/// frames that do not rotate relative to each other. The epoch is kept.
+///
+/// For ICRS → ECEF use `Some([0.0, 0.0, EARTH_ROTATION_RATE])` with
+/// [`icrs_to_ecef_at`] evaluated at the state's epoch.
+#[must_use]
+pub fn transform_state<From, To>(
+    tf: &RigidBodyTransform<From, To>,
+    state: &EphemerisState<From>,
+    omega: Option<[f64; 3]>,
+) -> EphemerisState<To>
+where
+    From: sguaba::CoordinateSystem,
+    To: sguaba::CoordinateSystem,
+{
+    let position = *state.position();
+    let velocity = velocity_components(state.velocity());
+    let velocity = match omega {
+        Some(omega) => tf.transform_velocity_rotating(position, velocity, omega),
+        None => tf.transform_velocity(position, velocity),
+    };
+
+    EphemerisState::new(tf.transform(position), velocity_from_components(velocity), state.epoch())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use sguaba::Coordinate;
+    use uom::si::f64::Length;
+    use uom::si::length::{kilometer, meter};
+
+    #[test]
+    fn galactic_center_maps_to_origin_of_longitude() {
+        use crate::{EquatorialCoords, GalacticCoordinateExt, IcrsCoordinateExt};
+        use uom::si::angle::degree;
+        use uom::si::f64::Angle;
+
+        // Galactic center (l = 0, b = 0) in ICRS: 17h45m37.224s, -28°56'10.23"
+        let center = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(266.405_100),
+            dec: Angle::new::<degree>(-28.936_175),
+            distance: Length::new::<kilometer>(1.0),
+        });
+        let (l, b) = icrs_to_galactic().transform(center).to_galactic_lb();
+        let l = l.get::<degree>();
+        assert!(l.min(360.0 - l) < 1e-4, "l = {l}");
+        assert!(b.get::<degree>().abs() < 1e-4);
+
+        let pole = Coordinate::<Icrs>::from_equatorial(EquatorialCoords {
+            ra: Angle::new::<degree>(192.859_48),
+            dec: Angle::new::<degree>(27.128_25),
+            distance: Length::new::<kilometer>(1.0),
+        });
+        let (_, b) = icrs_to_galactic().transform(pole).to_galactic_lb();
+        assert!((b.get::<degree>() - 90.0).abs() < 1e-9);
+
+        let back = galactic_to_icrs().transform(icrs_to_galactic().transform(center));
+        assert!(back.distance_from(&center).get::<meter>() < 1e-9);
+    }
+
+    #[test]
+    fn icrs_to_ecef_state_matches_ground_track() {
+        use crate::constants::EARTH_ROTATION_RATE;
+        use crate::ext::ecef_to_icrs_velocity;
+
+        let time = Utc.with_ymd_and_hms(2026, 2, 10, 6, 0, 0).unwrap();
+        #[allow(deprecated)]
+        let ground = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<kilometer>(6_378.137),
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(0.0),
+        );
+        let inertial = EphemerisState::new(
+            ecef_to_icrs_at(time).transform(ground),
+            velocity_from_components(ecef_to_icrs_velocity(ground, [0.0, 0.0, 0.0], time)),
+            time,
+        );
+
+        let omega = [0.0, 0.0, EARTH_ROTATION_RATE];
+        let fixed = transform_state(&icrs_to_ecef_at(time), &inertial, Some(omega));
+        assert!(fixed.position().distance_from(&ground).get::<meter>() < 1e-6);
+        assert_eq!(fixed.epoch(), time);
+        let v = nalgebra::Vector3::from(velocity_components(fixed.velocity()));
+        assert!(v.norm() < 1e-6, "ground point should be at rest in ECEF, got {v}");
+
+        // Without the rotating-frame term the ~465 m/s eastward motion remains
+        let rotated_only = transform_state(&icrs_to_ecef_at(time), &inertial, None);
+        let [_, vy, _] = velocity_components(rotated_only.velocity());
+        assert!((vy - EARTH_ROTATION_RATE * 6_378_137.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn model_info_reports_eop_and_leap_seconds() {
+        use crate::eop::{EopRecord, TableEop};
+
+        let time = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let info = active_model_info(time, None, PrecessionNutationModel::default());
+        assert_eq!(info.precession, PrecessionNutationModel::Iau2006);
+        assert!(!info.eop_loaded);
+        assert_eq!(info.dut1_source, Dut1Source::AssumedZero);
+        assert_eq!(info.leap_seconds, 37.0);
+
+        let table = TableEop::new(vec![EopRecord { epoch: time, xp: 0.0, yp: 0.0, dut1: 0.01 }]).unwrap();
+        let info = active_model_info(time, Some(&table), PrecessionNutationModel::Iau1976);
+        assert_eq!(info.precession, PrecessionNutationModel::Iau1976);
+        assert!(info.eop_loaded);
+        assert_eq!(info.dut1_source, Dut1Source::Provider);
+    }
+
+    #[test]
+    fn rotation_cache_computes_once_per_bucket() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let cache = RotationCache::new(chrono::Duration::seconds(1));
+        let computations = AtomicUsize::new(0);
+        let rotation = |time| {
+            cache.rotation_with(time, |t| {
+                computations.fetch_add(1, Ordering::SeqCst);
+                icrs_to_ecef_rotation(t)
+            })
+        };
+
+        for step in 0..1000 {
+            let _ = rotation(start + chrono::Duration::microseconds(step * 999));
+        }
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        assert!(cache.rotation_at(start).angle_to(&icrs_to_ecef_rotation(start)) < 1e-15);
+        let _ = cache.icrs_to_ecef_at(start + chrono::Duration::milliseconds(10));
+        assert_eq!(cache.len(), 1);
+
+        // Revisiting an earlier bucket after moving on is still a hit
+        let _ = rotation(start + chrono::Duration::seconds(5));
+        let _ = rotation(start + chrono::Duration::milliseconds(500));
+        assert_eq!(computations.load(Ordering::SeqCst), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn rotation_cache_evicts_the_farthest_bucket_when_full() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let cache = RotationCache::with_capacity(chrono::Duration::seconds(1), 3);
+        let second = |n| start + chrono::Duration::seconds(n);
+
+        for n in 0..10 {
+            let _ = cache.rotation_at(second(n));
+        }
+        assert_eq!(cache.len(), 3);
+
+        // The three most recent buckets survive a forward-stepping loop
+        let computations = std::cell::Cell::new(0);
+        for n in 7..10 {
+            let _ = cache.rotation_with(second(n), |t| {
+                computations.set(computations.get() + 1);
+                icrs_to_ecef_rotation(t)
+            });
+        }
+        assert_eq!(computations.get(), 0);
+    }
+
+    #[test]
+    fn j2000_ecliptic_rotation_is_obliquity_about_x() {
+        let quat = j2000_ecliptic_rotation();
+        assert!((quat.angle().to_degrees() - 23.439_279).abs() < 1e-6);
+        let axis = quat.axis().unwrap();
+        assert!((axis.x - 1.0).abs() < 1e-12);
+
+        // The equatorial pole lies ε from the ecliptic pole, in the Y-Z plane
+        #[allow(deprecated)]
+        let pole = Coordinate::<Eme2000>::from_cartesian(
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(1.0),
+        );
+        let [x, y, z] = j2000_equator_to_ecliptic().transform(pole).to_cartesian().map(|c| c.get::<kilometer>());
+        let eps = 23.439_279_f64.to_radians();
+        assert!(x.abs() < 1e-12);
+        assert!((y.abs() - eps.sin()).abs() < 1e-8);
+        assert!((z - eps.cos()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn ecef_to_mci_roundtrip() {
+        let time = Utc.with_ymd_and_hms(2030, 6, 15, 8, 30, 0).unwrap();
+
+        #[allow(deprecated)]
+        let station = Coordinate::<Ecef>::from_cartesian(
+            Length::new::<kilometer>(4_000.0),
+            Length::new::<kilometer>(-2_500.0),
+            Length::new::<kilometer>(4_300.0),
+        );
+
+        let mci = ecef_to_mci_at(time).transform(station);
+        let back = mci_to_ecef_at(time).transform(mci);
+
+        assert!(back.distance_from(&station).get::<meter>() < 1e-6);
+    }
+
+    #[test]
+    fn mci_pole_drifts_slowly_from_j2000() {
+        use crate::constants::{lunar, J2000_JD};
+
+        let at_j2000 = mci_to_icrs_rotation_at(J2000_JD);
+        assert!(at_j2000.angle_to(mci_to_icrs_rotation()) < 1e-15);
+
+        // 0.4 centuries: ΔRA = 0.00124°, ΔDec = 0.0052°
+        let time = Utc.with_ymd_and_hms(2040, 1, 1, 12, 0, 0).unwrap();
+        let drift = mci_to_icrs_rotation_at(utc_to_julian_date(time))
+            .angle_to(mci_to_icrs_rotation())
+            .to_degrees();
+        let t = 0.4;
+        let d_ra = lunar::RA_RATE_DEG_PER_CENTURY * t;
+        let d_dec = lunar::DEC_RATE_DEG_PER_CENTURY * t;
+
+        assert!(drift > d_dec - d_ra && drift < d_dec + d_ra);
+
+        #[allow(deprecated)]
+        let x = Coordinate::<Mci>::from_cartesian(
+            Length::new::<kilometer>(1_000.0),
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(0.0),
+        );
+        let moved = mci_to_icrs_at(time).transform(x).distance_from(&mci_to_icrs().transform(x));
+        let bound = (d_dec + d_ra).to_radians() * 1_000e3;
+        assert!(moved.get::<meter>() > 0.0 && moved.get::<meter>() < bound);
+    }
+
+    #[test]
+    fn frame_bias_is_about_23_mas_and_composes_with_ecef() {
+        use crate::constants::ARCSEC_TO_RAD;
+        use nalgebra::Vector3;
+
+        let bias_mas = frame_bias_rotation().angle() / ARCSEC_TO_RAD * 1000.0;
+        assert!(bias_mas > 22.0 && bias_mas < 24.0);
+
+        // First-order bias matrix: the ICRS x axis picks up -dα₀ in y and ξ₀ in z
+        let x_eme = frame_bias_rotation().transform_vector(&Vector3::x());
+        let mas = ARCSEC_TO_RAD / 1000.0;
+        assert!((x_eme.y - 14.6 * mas).abs() < 1e-12);
+        assert!((x_eme.z + 16.617 * mas).abs() < 1e-12);
+
+        #[allow(deprecated)]
+        let geo = Coordinate::<Eme2000>::from_cartesian(
+            Length::new::<kilometer>(42_164.0),
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(0.0),
+        );
+        let icrs = eme2000_to_icrs().transform(geo);
+        assert!(icrs_to_eme2000().transform(icrs).distance_from(&geo).get::<meter>() < 1e-6);
+
+        let time = Utc.with_ymd_and_hms(2030, 6, 15, 8, 30, 0).unwrap();
+        let via_icrs = eme2000_to_icrs().and_then(icrs_to_ecef_at(time)).transform(geo);
+        let [x, y, z] = geo.to_cartesian();
+        #[allow(deprecated)]
+        let unbiased = icrs_to_ecef_at(time).transform(Coordinate::<Icrs>::from_cartesian(x, y, z));
+        let shift = via_icrs.distance_from(&unbiased).get::<meter>();
+        let expected = bias_mas * mas * 42_164e3;
+        assert!(shift > 0.5 * expected && shift < 1.01 * expected);
+    }
+
+    #[test]
+    fn icrs_to_ecliptic_is_the_fixed_j2000_ecliptic() {
+        #[allow(deprecated)]
+        let star = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<kilometer>(0.3),
+            Length::new::<kilometer>(-0.5),
+            Length::new::<kilometer>(0.8),
+        );
+        let via_eme2000 = icrs_to_eme2000().and_then(j2000_equator_to_ecliptic()).transform(star);
+
+        // The obliquity does not drift with the epoch (~47″ per century)
+        for year in [2000, 2050, 2100] {
+            let time = Utc.with_ymd_and_hms(year, 3, 1, 0, 0, 0).unwrap();
+            let ecl = icrs_to_ecliptic_at(time, EclipticOrigin::Geocentric).transform(star);
+            assert!(ecl.distance_from(&via_eme2000).get::<meter>() < 1e-9, "{year}");
+
+            let back = ecliptic_to_icrs_at(time, EclipticOrigin::Geocentric).transform(ecl);
+            assert!(back.distance_from(&star).get::<meter>() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn ecliptic_of_date_follows_precession_and_nutation() {
+        let time = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+        #[allow(deprecated)]
+        let j2000_equinox = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<kilometer>(1.0),
+            Length::new::<kilometer>(0.0),
+            Length::new::<kilometer>(0.0),
+        );
+        let lon_lat = |obliquity| {
+            let [x, y, z] = icrs_to_ecliptic_of_date_at(time, EclipticOrigin::Geocentric, obliquity)
+                .transform(j2000_equinox)
+                .to_cartesian()
+                .map(|c| c.get::<kilometer>());
+            (y.atan2(x), z.asin())
+        };
+
+        // Half a century of general precession in longitude, p_A ≈ 5028.8″·T
+        let (mean_lon, mean_lat) = lon_lat(Obliquity::Mean);
+        let arcsec = crate::constants::ARCSEC_TO_RAD;
+        assert!((mean_lon / arcsec - 2514.7).abs() < 1.0, "{}″", mean_lon / arcsec);
+        assert!(mean_lat.abs() < 5.0 * arcsec);
+
+        // The true equinox is shifted along the ecliptic by Δψ
+        let (true_lon, true_lat) = lon_lat(Obliquity::True);
+        let dpsi = nutation_angles(utc_to_tt(time)).0;
+        assert!((true_lon - mean_lon - dpsi).abs() < 1e-10);
+        assert!((true_lat - mean_lat).abs() < 1e-10);
+
+        let back = ecliptic_of_date_to_icrs_at(time, EclipticOrigin::Geocentric, Obliquity::True)
+            .transform(icrs_to_ecliptic_of_date_at(time, EclipticOrigin::Geocentric, Obliquity::True).transform(j2000_equinox));
+        assert!(back.distance_from(&j2000_equinox).get::<meter>() < 1e-9);
+    }
+
+    #[test]
+    fn ecliptic_origins_differ_by_earth_sun_vector() {
+        use crate::astrodynamics::sun_position_icrs;
+
+        let time = Utc.with_ymd_and_hms(2030, 6, 15, 8, 30, 0).unwrap();
+        #[allow(deprecated)]
+        let mars_ish = Coordinate::<Icrs>::from_cartesian(
+            Length::new::<kilometer>(-1.2e8),
+            Length::new::<kilometer>(1.9e8),
+            Length::new::<kilometer>(0.8e8),
+        );
+
+        let geocentric = icrs_to_ecliptic_at(time, EclipticOrigin::Geocentric);
+        let heliocentric = icrs_to_ecliptic_at(time, EclipticOrigin::Heliocentric);
+
+        // The Sun sits at the heliocentric origin
+        let sun = sun_position_icrs(time);
+        assert!(heliocentric.transform(sun).distance_from_origin().get::<meter>() < 1e-3);
+
+        // Geocentric minus heliocentric is the geocentric Sun in ecliptic axes
+        let geo = geocentric.transform(mars_ish).to_cartesian();
+        let helio = heliocentric.transform(mars_ish).to_cartesian();
+        let sun_ecl = geocentric.transform(sun).to_cartesian();
+        for k in 0..3 {
+            assert!((geo[k] - helio[k] - sun_ecl[k]).get::<meter>().abs() < 1e-3);
+        }
+    }
+}